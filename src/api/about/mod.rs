@@ -1,4 +1,6 @@
 //! # User Overview Responses
+use std::time::SystemTime;
+
 use serde::Deserialize;
 
 use crate::api::response::BasicThing;
@@ -133,5 +135,74 @@ pub struct AboutData {
     pub accept_pms: Option<bool>,
 }
 
+impl AboutData {
+    /// When the account was created.
+    pub fn created_at(&self) -> Option<SystemTime> {
+        crate::util::utc_timestamp(self.created_utc?)
+    }
+
+    /// The karma shown on the profile, which also includes awarder and awardee karma.
+    ///
+    /// Falls back to the sum of the individual karma counts if Reddit didn't provide it.
+    pub fn total_karma(&self) -> i32 {
+        self.total_karma.unwrap_or_else(|| {
+            [
+                self.link_karma,
+                self.comment_karma,
+                self.awarder_karma,
+                self.awardee_karma,
+            ]
+            .into_iter()
+            .flatten()
+            .sum()
+        })
+    }
+
+    /// Karma from giving awards, or `0` if Reddit didn't provide it.
+    pub fn awarder_karma(&self) -> i32 {
+        self.awarder_karma.unwrap_or(0)
+    }
+
+    /// Karma from receiving awards, or `0` if Reddit didn't provide it.
+    pub fn awardee_karma(&self) -> i32 {
+        self.awardee_karma.unwrap_or(0)
+    }
+
+    /// Whether the account has a verified email address, or `false` if Reddit didn't say.
+    pub fn has_verified_email(&self) -> bool {
+        self.has_verified_email.unwrap_or(false)
+    }
+}
+
 /// Overview
 pub type About = BasicThing<AboutData>;
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::AboutData;
+
+    #[test]
+    fn test_about_accessors() {
+        let about: AboutData = serde_json::from_str(
+            r#"{
+                "name": "someone",
+                "created_utc": 1600000000.0,
+                "link_karma": 10,
+                "comment_karma": 20,
+                "awardee_karma": 5,
+                "has_verified_email": true
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            about.created_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_600_000_000))
+        );
+        assert_eq!(about.total_karma(), 35);
+        assert_eq!(about.awarder_karma(), 0);
+        assert!(about.has_verified_email());
+    }
+}
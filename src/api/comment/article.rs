@@ -78,21 +78,38 @@ impl<'de> Deserialize<'de> for ArticleCommentsResponse {
     }
 }
 
+/// A submission and its comments, parsed separately so that the submission is kept even if the
+/// comments fail to parse.
 #[derive(Debug)]
-pub struct ArticleCommentsResponseWithoutComments {
+pub struct PartialArticleCommentsResponse {
     pub submission: SubmissionData,
+    pub comments: Result<OuterBasicListing<ArticleCommentOrMoreComments>, serde_json::Error>,
 }
 
-type EncodedNoComments = (BasicListing<SubmissionData>, serde::de::IgnoredAny);
-
-impl<'de> Deserialize<'de> for ArticleCommentsResponseWithoutComments {
+impl<'de> Deserialize<'de> for PartialArticleCommentsResponse {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let (submission, _) = EncodedNoComments::deserialize(deserializer)?;
-        Ok(ArticleCommentsResponseWithoutComments {
-            submission: submission.data.children.into_iter().next().unwrap().data,
+        type Encoded = (BasicListing<SubmissionData>, serde_json::Value);
+
+        let (submission, comments) = Encoded::deserialize(deserializer)?;
+
+        let submission = submission
+            .data
+            .children
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                serde::de::Error::custom(
+                    "expected article comments submission array to be non-empty",
+                )
+            })?
+            .data;
+
+        Ok(PartialArticleCommentsResponse {
+            submission,
+            comments: serde_json::from_value(comments),
         })
     }
 }
@@ -104,6 +121,16 @@ pub struct MoreCommentData {
     pub parent_id: ThingFullname,
     pub count: i32,
     pub depth: i32,
+    /// The IDs of the comments which can be loaded through `api/morechildren`.
+    /// This is empty for "continue this thread" markers.
+    #[serde(default)]
+    pub children: Vec<String>,
+}
+
+/// The `data` of a response from `api/morechildren`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct MoreChildrenData {
+    pub things: Vec<ArticleCommentOrMoreComments>,
 }
 
 /// Represents an article comment, or a more comments marker
@@ -117,3 +144,43 @@ pub enum ArticleCommentOrMoreComments {
     #[serde(rename = "more")]
     More(MoreCommentData),
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{ArticleCommentOrMoreComments, MoreChildrenData, PartialArticleCommentsResponse};
+    use crate::api::response::PostResponse;
+    use crate::api::submission::test_submission;
+
+    #[test]
+    fn deserialize_partial_article_comments() {
+        let submission = test_submission(json!({}));
+        let json = json!([
+            {"kind": "Listing", "data": {"children": [{"kind": "t3", "data": submission}]}},
+            {"kind": "Listing", "data": {"children": [{"kind": "t1", "data": {"depth": "deep"}}]}}
+        ]);
+
+        let response: PartialArticleCommentsResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.submission.title, "Hello");
+        assert!(response.comments.is_err());
+    }
+
+    #[test]
+    fn deserialize_more_children() {
+        let json = r#"{"json": {"errors": [], "data": {"things": [
+            {"kind": "more", "data": {
+                "id": "def456", "name": "t1_def456", "parent_id": "t1_abc123",
+                "count": 2, "depth": 1, "children": ["def456", "ghi789"]
+            }}
+        ]}}}"#;
+
+        let response: PostResponse<MoreChildrenData> = serde_json::from_str(json).unwrap();
+        let things = response.json.data.unwrap().things;
+
+        let ArticleCommentOrMoreComments::More(more) = &things[0] else {
+            panic!("expected a more marker");
+        };
+        assert_eq!(more.children, vec!["def456", "ghi789"]);
+    }
+}
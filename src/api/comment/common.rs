@@ -32,7 +32,6 @@ pub struct CommonCommentData {
     pub banned_at_utc: Option<f64>,
     #[serde(default, with = "what_a_terrible_api")]
     pub banned_by: Option<String>,
-    #[serde(deserialize_with = "crate::util::serde::unescape_html")]
     pub body: String,
     pub body_html: String,
     pub can_gild: bool,
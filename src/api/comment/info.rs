@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::CommonCommentData;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InfoCommentData {
+    #[serde(flatten)]
+    pub common: CommonCommentData,
+}
@@ -3,6 +3,7 @@
 pub mod article;
 pub mod common;
 pub mod created;
+pub mod info;
 pub mod latest;
 pub mod replies;
 
@@ -10,7 +11,7 @@ use super::response::BasicListing;
 
 pub use article::{
     ArticleAndCommentsResponse, ArticleCommentData, ArticleCommentOrMoreComments,
-    ArticleCommentsResponse, ArticleCommentsResponseWithoutComments,
+    ArticleCommentsResponse, PartialArticleCommentsResponse,
 };
 /// list of latest comment data
 pub type APILatestComments = BasicListing<latest::LatestCommentData>;
@@ -18,3 +19,14 @@ pub type APILatestComments = BasicListing<latest::LatestCommentData>;
 pub type APIArticleComments = BasicListing<article::ArticleCommentData>;
 /// list of created comment data
 pub type APICreatedComments = BasicListing<created::CreatedCommentWithLinkInfoData>;
+
+/// A minimal comment as Reddit returns it, with `fields` added or replaced, for tests.
+#[cfg(test)]
+pub(crate) fn test_comment(fields: serde_json::Value) -> serde_json::Value {
+    let mut comment: serde_json::Value =
+        serde_json::from_str(include_str!("../../../tests/fixtures/comment.json")).unwrap();
+    if let serde_json::Value::Object(fields) = fields {
+        comment.as_object_mut().unwrap().extend(fields);
+    }
+    comment
+}
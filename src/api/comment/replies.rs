@@ -6,7 +6,11 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::api::{comment::article::ArticleCommentOrMoreComments, response::OuterBasicListing};
+use crate::api::{
+    comment::article::{ArticleCommentData, ArticleCommentOrMoreComments},
+    response::OuterBasicListing,
+    ThingFullname,
+};
 
 /// The article an article has, or empty if it has none.
 #[derive(Debug, PartialEq)]
@@ -17,6 +21,72 @@ pub enum ArticleReplies {
     Empty,
 }
 
+impl ArticleReplies {
+    /// The direct replies, including "more comments" markers.
+    pub fn children(&self) -> &[ArticleCommentOrMoreComments] {
+        match self {
+            ArticleReplies::Replies(listing) => &listing.data.children,
+            ArticleReplies::Empty => &[],
+        }
+    }
+
+    /// Walks every reply beneath this one depth-first, in the order they appear on the site.
+    /// "More comments" markers are skipped.
+    pub fn walk(&self) -> impl Iterator<Item = &ArticleCommentData> {
+        CommentWalk::new(self.children())
+    }
+
+    /// Collects every reply beneath this one, depth-first.
+    pub fn flatten(&self) -> Vec<&ArticleCommentData> {
+        self.walk().collect()
+    }
+
+    /// Finds the reply with the fullname anywhere beneath this one.
+    pub fn find(&self, name: &ThingFullname) -> Option<&ArticleCommentData> {
+        self.walk().find(|comment| &comment.common.name == name)
+    }
+}
+
+/// A depth-first walk over a comment tree.
+pub(crate) struct CommentWalk<'a> {
+    stack: Vec<&'a ArticleCommentData>,
+}
+
+impl<'a> CommentWalk<'a> {
+    pub(crate) fn new(top_level: &'a [ArticleCommentOrMoreComments]) -> Self {
+        let mut walk = Self { stack: Vec::new() };
+        walk.push_children(top_level);
+        walk
+    }
+
+    pub(crate) fn from_comments(
+        top_level: impl DoubleEndedIterator<Item = &'a ArticleCommentData>,
+    ) -> Self {
+        Self {
+            stack: top_level.rev().collect(),
+        }
+    }
+
+    fn push_children(&mut self, children: &'a [ArticleCommentOrMoreComments]) {
+        // Pushed in reverse, so the first child is popped first.
+        self.stack
+            .extend(children.iter().rev().filter_map(|child| match child {
+                ArticleCommentOrMoreComments::Comment(comment) => Some(comment),
+                ArticleCommentOrMoreComments::More(_) => None,
+            }));
+    }
+}
+
+impl<'a> Iterator for CommentWalk<'a> {
+    type Item = &'a ArticleCommentData;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let comment = self.stack.pop()?;
+        self.push_children(comment.replies.children());
+        Some(comment)
+    }
+}
+
 impl Serialize for ArticleReplies {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -91,6 +161,7 @@ mod tests {
         comment::{
             article::{ArticleCommentOrMoreComments, MoreCommentData},
             replies::ArticleReplies,
+            test_comment,
         },
         response::{BasicThing, Listing},
         ThingFullname,
@@ -146,10 +217,68 @@ mod tests {
                         name: ThingFullname::try_from("t1_abc123").unwrap(),
                         parent_id: ThingFullname::try_from("t3_xyz123").unwrap(),
                         count: 123,
-                        depth: 0
+                        depth: 0,
+                        children: Vec::new(),
                     })]
                 }
             })
         );
     }
+
+    fn comment(
+        id: &str,
+        parent: &str,
+        depth: i32,
+        replies: &[serde_json::Value],
+    ) -> serde_json::Value {
+        let replies = if replies.is_empty() {
+            serde_json::json!("")
+        } else {
+            serde_json::json!({ "kind": "Listing", "data": { "children": replies } })
+        };
+
+        let data = test_comment(serde_json::json!({
+            "id": id,
+            "name": format!("t1_{id}"),
+            "parent_id": parent,
+            "depth": depth,
+            "replies": replies,
+        }));
+
+        serde_json::json!({ "kind": "t1", "data": data })
+    }
+
+    #[test]
+    fn walk_is_depth_first() {
+        let more = serde_json::json!({ "kind": "more", "data": {
+            "id": "zzz", "name": "t1_zzz", "parent_id": "t1_aaa", "count": 5, "depth": 1
+        }});
+        let tree = serde_json::json!({ "replies": { "kind": "Listing", "data": { "children": [
+            comment("aaa", "t3_xyz123", 0, &[
+                comment("bbb", "t1_aaa", 1, &[comment("ccc", "t1_bbb", 2, &[])]),
+                more,
+                comment("ddd", "t1_aaa", 1, &[]),
+            ]),
+            comment("eee", "t3_xyz123", 0, &[]),
+        ]}}});
+
+        let response: Response = serde_json::from_value(tree).unwrap();
+        let ids: Vec<_> = response
+            .replies
+            .walk()
+            .map(|comment| comment.common.id.as_str())
+            .collect();
+        assert_eq!(ids, ["aaa", "bbb", "ccc", "ddd", "eee"]);
+        assert_eq!(response.replies.flatten().len(), 5);
+
+        let found = response
+            .replies
+            .find(&ThingFullname::try_from("t1_ccc").unwrap())
+            .unwrap();
+        assert_eq!(found.depth, 2);
+        assert!(response
+            .replies
+            .find(&ThingFullname::try_from("t1_zzz").unwrap())
+            .is_none());
+    }
 }
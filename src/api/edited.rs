@@ -0,0 +1,23 @@
+//! # Edited
+//! Responses from `/api/editusertext`, which edits the text of a comment or self post.
+
+use serde::Deserialize;
+
+use crate::api::{comment::created::CreatedCommentData, submission::SubmissionData};
+
+/// A thing returned by `/api/editusertext`, with its text updated.
+#[derive(Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum EditedItem {
+    /// A comment
+    #[serde(rename = "t1")]
+    Comment(CreatedCommentData),
+    /// A self post
+    #[serde(rename = "t3")]
+    Submission(SubmissionData),
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EditedItems {
+    pub things: Vec<EditedItem>,
+}
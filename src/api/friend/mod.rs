@@ -1,8 +1,58 @@
-//! # Inbox Responses
+//! # Friend Responses
+use serde::de::IgnoredAny;
 use serde::Deserialize;
-/// The response from an add friend request
+
+use crate::api::response::PostResponseInner;
+use crate::util::RouxError;
+
+/// The response from an add or remove friend request.
+///
+/// Depending on the request, Reddit either responds with `{"success": bool}` or with the
+/// usual `{"json": {"errors": [...]}}` envelope.
 #[derive(Debug, Deserialize)]
 pub struct Friend {
     /// Was the friend request a success
+    #[serde(default)]
     pub success: bool,
+    #[serde(default)]
+    json: Option<PostResponseInner<IgnoredAny>>,
+}
+
+impl Friend {
+    /// Converts the response into an error if Reddit reported one.
+    pub(crate) fn into_result(self) -> Result<(), RouxError> {
+        match self.json {
+            Some(json) if !json.errors.is_empty() => Err(RouxError::reddit_error(json.errors)),
+            Some(_) => Ok(()),
+            None if self.success => Ok(()),
+            None => Err(RouxError::reddit_error(Vec::new())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Friend;
+    use crate::api::response::ApiErrorCode;
+    use crate::util::error::RouxErrorKind;
+
+    #[test]
+    fn test_friend_errors() {
+        let ok: Friend = serde_json::from_str(r#"{"json": {"errors": []}}"#).unwrap();
+        assert!(ok.into_result().is_ok());
+
+        let ok: Friend = serde_json::from_str(r#"{"success": true}"#).unwrap();
+        assert!(ok.into_result().is_ok());
+
+        let failed: Friend = serde_json::from_str(
+            r#"{"json": {"errors": [["USER_DOESNT_EXIST", "that user doesn't exist", "name"]]}}"#,
+        )
+        .unwrap();
+
+        let RouxErrorKind::RedditError(errors) = failed.into_result().unwrap_err().kind else {
+            panic!("expected a reddit error");
+        };
+        assert_eq!(errors[0].code(), ApiErrorCode::UserDoesntExist);
+        assert_eq!(errors[0].field(), Some("name"));
+    }
 }
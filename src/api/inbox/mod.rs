@@ -1,10 +1,10 @@
 //! # Inbox Responses
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{api::response::BasicListing, api::ThingFullname};
 
 /// InboxItem
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InboxData {
     /// ID
     pub id: String,
@@ -20,8 +20,8 @@ pub struct InboxData {
     pub subreddit_name_prefixed: Option<String>,
     /// New
     pub new: bool,
-    /// ???
-    pub r#type: String,
+    /// What the message is, e.g. a reply to a comment.
+    pub r#type: MessageType,
     /// Body
     pub body: String,
     /// Dest
@@ -41,3 +41,53 @@ pub struct InboxData {
 
 /// Inbox
 pub type APIInbox = BasicListing<InboxData>;
+
+/// What a message in the inbox is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageType {
+    /// A reply to one of the account's comments.
+    CommentReply,
+    /// A reply to one of the account's posts.
+    PostReply,
+    /// A comment mentioning the account's username.
+    UsernameMention,
+    /// A private message.
+    PrivateMessage,
+    /// Anything else, including messages Reddit reports as `unknown` (e.g. some private
+    /// messages and moderator mail).
+    #[serde(other)]
+    Unknown,
+}
+
+impl MessageType {
+    /// The `type` Reddit uses for this kind of message.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageType::CommentReply => "comment_reply",
+            MessageType::PostReply => "post_reply",
+            MessageType::UsernameMention => "username_mention",
+            MessageType::PrivateMessage => "private_message",
+            MessageType::Unknown => "unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageType;
+
+    #[test]
+    fn test_message_type() {
+        let parse = |ty: &str| serde_json::from_str::<MessageType>(&format!("{ty:?}")).unwrap();
+
+        assert_eq!(parse("username_mention"), MessageType::UsernameMention);
+        assert_eq!(parse("comment_reply"), MessageType::CommentReply);
+        assert_eq!(parse("unknown"), MessageType::Unknown);
+        assert_eq!(parse("something_new"), MessageType::Unknown);
+        assert_eq!(
+            serde_json::to_string(&MessageType::PostReply).unwrap(),
+            r#""post_reply""#
+        );
+    }
+}
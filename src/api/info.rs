@@ -0,0 +1,53 @@
+//! # Info
+//! Responses from `/api/info`, which looks up things of different kinds by fullname.
+
+use serde::Deserialize;
+
+use crate::api::{
+    comment::info::InfoCommentData, response::OuterBasicListing, submission::SubmissionData,
+    subreddit::SubredditData,
+};
+
+/// The raw response for `/api/info`
+pub type InfoItems = OuterBasicListing<InfoItem>;
+
+/// A thing returned by `/api/info`, which only looks up comments, submissions and subreddits
+#[derive(Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum InfoItem {
+    /// A comment
+    #[serde(rename = "t1")]
+    Comment(InfoCommentData),
+    /// A submission
+    #[serde(rename = "t3")]
+    Submission(SubmissionData),
+    /// A subreddit
+    #[serde(rename = "t5")]
+    Subreddit(SubredditData),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{InfoItem, InfoItems};
+    use crate::api::comment::test_comment;
+    use crate::api::submission::test_submission;
+
+    #[test]
+    fn test_mixed_kinds() {
+        let json = json!({"kind": "Listing", "data": {"children": [
+            {"kind": "t3", "data": test_submission(json!({}))},
+            {"kind": "t1", "data": test_comment(json!({}))},
+            {"kind": "t5", "data": {"display_name": "rust", "id": "2s7lj", "name": "t5_2s7lj"}},
+        ]}});
+
+        let items: InfoItems = serde_json::from_value(json).unwrap();
+        let [submission, comment, subreddit] = items.data.children.as_slice() else {
+            panic!("expected three things");
+        };
+        assert!(matches!(submission, InfoItem::Submission(data) if data.id == "abc123"));
+        assert!(matches!(comment, InfoItem::Comment(data) if data.common.id == "abc123"));
+        assert!(matches!(subreddit, InfoItem::Subreddit(data) if data.display_name == "rust"));
+    }
+}
@@ -6,6 +6,8 @@
 
 use serde::Deserialize;
 
+use crate::api::response::{BasicThing, ListingNotFullname};
+
 /// An update within a live thread.
 #[derive(Debug, Deserialize)]
 pub struct LiveUpdateData {
@@ -27,6 +29,10 @@ pub struct LiveUpdateData {
     pub id: String,
 }
 
+/// A page of updates from a live thread. Updates are not named like other things, so the listing
+/// cannot be paged through like other listings.
+pub(crate) type LiveUpdates = BasicThing<ListingNotFullname<BasicThing<LiveUpdateData>>>;
+
 /// A live-updating thread.
 #[derive(Debug, Deserialize)]
 pub struct LiveThreadData {
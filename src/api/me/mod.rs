@@ -1,11 +1,19 @@
 //! # Me data
+use std::collections::HashMap;
+
 use serde::Deserialize;
+use serde_json::Value;
+
+mod prefs;
+pub use prefs::PrefsData;
 
 /// MeData
 #[derive(Debug, Deserialize)]
 pub struct MeData {
     /// ID
     pub id: String,
+    /// Username
+    pub name: String,
     /// Is employee
     pub is_employee: bool,
     /// Verified
@@ -18,6 +26,9 @@ pub struct MeData {
     pub is_suspended: bool,
     /// Has mail
     pub has_mail: bool,
+    /// Has mod mail
+    #[serde(default)]
+    pub has_mod_mail: bool,
     /// Inbox count
     pub inbox_count: f64,
     /// Created
@@ -30,10 +41,39 @@ pub struct MeData {
     pub comment_karma: i32,
     /// Link karma
     pub link_karma: i32,
+    /// Total karma, including awarder and awardee karma
+    #[serde(default)]
+    pub total_karma: i32,
     /// Is mod
     pub is_mod: bool,
     /// Is gold
     pub is_gold: bool,
     /// Icon img
     pub icon_img: String,
+    /// Feature flags enabled for the account.
+    /// Most are booleans, but some are experiment variants given as objects.
+    #[serde(default)]
+    pub features: HashMap<String, Value>,
+}
+
+/// The counts shown on the inbox badges, as returned by
+/// [`AuthedClient::unread_counts`](crate::client::AuthedClient::unread_counts).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnreadCounts {
+    /// The number of unread messages in the inbox.
+    pub inbox: u32,
+    /// Whether there is unread mail.
+    pub has_mail: bool,
+    /// Whether there is unread mod mail.
+    pub has_mod_mail: bool,
+}
+
+impl From<&MeData> for UnreadCounts {
+    fn from(me: &MeData) -> Self {
+        Self {
+            inbox: me.inbox_count as u32,
+            has_mail: me.has_mail,
+            has_mod_mail: me.has_mod_mail,
+        }
+    }
 }
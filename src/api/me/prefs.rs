@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+/// The logged in account's preferences, from `/api/v1/me/prefs`.
+///
+/// Reddit returns many more preferences than are listed here; only the commonly useful ones are kept.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PrefsData {
+    /// The interface language, e.g. `en`.
+    pub lang: Option<String>,
+    /// Whether the account has confirmed it is over 18.
+    pub over_18: Option<bool>,
+    /// Whether NSFW results are included in searches.
+    pub search_include_over_18: Option<bool>,
+    /// Whether NSFW content is labelled.
+    pub label_nsfw: Option<bool>,
+    /// The default sort for comments, e.g. `confidence` or `new`.
+    pub default_comment_sort: Option<String>,
+    /// Comments scoring below this are collapsed.
+    pub min_comment_score: Option<i32>,
+    /// Links scoring below this are hidden.
+    pub min_link_score: Option<i32>,
+    /// The number of comments shown by default.
+    pub num_comments: Option<u32>,
+    /// The number of links shown per page.
+    pub numsites: Option<u32>,
+    /// Whether link flair is shown.
+    pub show_link_flair: Option<bool>,
+    /// Whether user flair is shown.
+    pub show_flair: Option<bool>,
+    /// Whether messages are shown as threads.
+    pub threaded_messages: Option<bool>,
+    /// Whether read messages are collapsed.
+    pub collapse_read_messages: Option<bool>,
+    /// Whether messages are marked read when the inbox is opened.
+    pub mark_messages_read: Option<bool>,
+    /// Who may send private messages, e.g. `everyone` or `whitelisted`.
+    pub accept_pms: Option<String>,
+    /// Whether other users can follow this account.
+    pub enable_followers: Option<bool>,
+    /// Whether votes are shown publicly.
+    pub public_votes: Option<bool>,
+    /// Whether the profile is hidden from search engines.
+    pub hide_from_robots: Option<bool>,
+    /// Whether night mode is enabled.
+    pub nightmode: Option<bool>,
+}
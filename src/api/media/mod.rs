@@ -0,0 +1,64 @@
+//! # Media upload responses
+use serde::Deserialize;
+
+/// The lease returned by `api/media/asset.json`, granting permission to upload a file.
+#[derive(Debug, Deserialize)]
+pub struct MediaAssetLease {
+    /// Where and how to upload the file.
+    pub args: MediaUploadArgs,
+    /// The asset the file will become.
+    pub asset: MediaAsset,
+}
+
+/// Where and how to upload a file.
+#[derive(Debug, Deserialize)]
+pub struct MediaUploadArgs {
+    /// The URL to upload to, without a scheme (e.g. `//reddit-uploaded-media.s3-accelerate.amazonaws.com`).
+    pub action: String,
+    /// Form fields which must be sent alongside the file.
+    pub fields: Vec<MediaUploadField>,
+}
+
+/// A form field to send when uploading a file.
+#[derive(Debug, Deserialize)]
+pub struct MediaUploadField {
+    /// The field name
+    pub name: String,
+    /// The field value
+    pub value: String,
+}
+
+/// An uploaded media asset.
+#[derive(Debug, Deserialize)]
+pub struct MediaAsset {
+    /// The ID of the asset, as used in gallery posts.
+    pub asset_id: String,
+    /// Whether Reddit has finished processing the asset.
+    pub processing_state: Option<String>,
+    /// A websocket which reports when processing has finished.
+    pub websocket_url: Option<String>,
+}
+
+/// A file which has been uploaded, as returned by
+/// [`AuthedClient::upload_media`](crate::client::AuthedClient::upload_media).
+#[derive(Debug, Clone)]
+pub struct UploadedMedia {
+    /// The ID of the asset, as used in gallery posts.
+    pub asset_id: String,
+    /// The URL of the uploaded file, as used when submitting image and video posts.
+    pub url: String,
+    /// A websocket which reports when processing has finished.
+    pub websocket_url: Option<String>,
+}
+
+/// The response from submitting an image or video post.
+///
+/// Reddit does not return the new post, as it is only created once the media has been processed.
+/// It is then listed on the account's [submissions page](Self::user_submitted_page).
+#[derive(Debug, Deserialize)]
+pub struct MediaSubmitted {
+    /// The page listing the account's submissions.
+    pub user_submitted_page: Option<String>,
+    /// A websocket which reports the new post's URL once processing has finished.
+    pub websocket_url: Option<String>,
+}
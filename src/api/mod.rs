@@ -1,11 +1,16 @@
 pub mod about;
 pub(crate) mod comment;
+pub mod edited;
 mod flair_id;
 pub mod friend;
 pub(crate) mod inbox;
+pub mod info;
 pub mod live;
 pub mod me;
+pub mod media;
 pub mod moderator;
+pub mod modmail;
+pub mod multi;
 pub mod overview;
 pub mod response;
 pub mod saved;
@@ -23,9 +28,9 @@ pub use comment::{
 pub use distinguished::*;
 pub use flair_id::*;
 pub use friend::Friend;
-pub use inbox::APIInbox;
+pub use inbox::{APIInbox, MessageType};
 pub use me::MeData;
-pub use moderator::Moderators;
+pub use moderator::{ModPermission, Moderators};
 pub use overview::Overview;
 pub use saved::APISaved;
 pub use submission::APISubmissions;
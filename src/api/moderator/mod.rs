@@ -2,6 +2,7 @@
 use serde::Deserialize;
 
 use crate::api::response::{BasicThing, Listing};
+use crate::builders::form::join_comma;
 
 /// ModeratorsData
 #[derive(Debug, Deserialize)]
@@ -13,8 +14,123 @@ pub struct ModeratorData {
     /// Author flair text
     pub author_flair_text: Option<String>,
     /// Mod permissions
-    pub mod_permissions: Option<Vec<String>>,
+    pub mod_permissions: Option<Vec<ModPermission>>,
+}
+
+impl ModeratorData {
+    /// Whether the moderator has the permission, either directly or through [`ModPermission::All`].
+    pub fn has_permission(&self, permission: &ModPermission) -> bool {
+        self.mod_permissions.as_ref().is_some_and(|permissions| {
+            permissions.contains(&ModPermission::All) || permissions.contains(permission)
+        })
+    }
 }
 
 /// Moderators
 pub type Moderators = BasicThing<Listing<ModeratorData>>;
+
+/// A permission a moderator of a subreddit can have.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModPermission {
+    /// Every permission, including those added by Reddit in future.
+    All,
+    /// Manage approved users, bans and mutes.
+    Access,
+    /// Manage the subreddit's settings, rules and removal reasons.
+    Config,
+    /// Manage flair.
+    Flair,
+    /// Read and reply to modmail.
+    Mail,
+    /// Approve, remove and lock posts and comments.
+    Posts,
+    /// Manage the wiki.
+    Wiki,
+    /// Manage chat settings.
+    ChatConfig,
+    /// Moderate chat.
+    ChatOperator,
+    /// Manage chat channels.
+    Channels,
+    /// A permission roux does not know about.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl ModPermission {
+    /// The name Reddit uses for this permission.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModPermission::All => "all",
+            ModPermission::Access => "access",
+            ModPermission::Config => "config",
+            ModPermission::Flair => "flair",
+            ModPermission::Mail => "mail",
+            ModPermission::Posts => "posts",
+            ModPermission::Wiki => "wiki",
+            ModPermission::ChatConfig => "chat_config",
+            ModPermission::ChatOperator => "chat_operator",
+            ModPermission::Channels => "channels",
+            ModPermission::Other(name) => name,
+        }
+    }
+
+    /// The `permissions` form value granting exactly these permissions.
+    ///
+    /// Permissions not listed are removed, so an empty list leaves the moderator with none.
+    pub(crate) fn to_param(permissions: &[ModPermission]) -> String {
+        if permissions.contains(&ModPermission::All) {
+            return "+all".to_owned();
+        }
+
+        join_comma(
+            std::iter::once("-all".to_owned())
+                .chain(permissions.iter().map(|p| format!("+{}", p.as_str()))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModPermission, ModeratorData};
+
+    #[test]
+    fn test_mod_permissions() {
+        let moderator: ModeratorData = serde_json::from_str(
+            r#"{
+                "id": "t2_abc",
+                "name": "someone",
+                "author_flair_text": null,
+                "mod_permissions": ["posts", "chat_config", "something_new"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            moderator.mod_permissions.as_deref(),
+            Some(
+                &[
+                    ModPermission::Posts,
+                    ModPermission::ChatConfig,
+                    ModPermission::Other("something_new".to_owned()),
+                ][..]
+            )
+        );
+        assert!(moderator.has_permission(&ModPermission::Posts));
+        assert!(!moderator.has_permission(&ModPermission::Mail));
+    }
+
+    #[test]
+    fn test_permissions_param() {
+        assert_eq!(
+            ModPermission::to_param(&[ModPermission::Posts, ModPermission::Mail]),
+            "-all,+posts,+mail"
+        );
+        assert_eq!(
+            ModPermission::to_param(&[ModPermission::All, ModPermission::Wiki]),
+            "+all"
+        );
+        assert_eq!(ModPermission::to_param(&[]), "-all");
+    }
+}
@@ -0,0 +1,129 @@
+//! # Modmail
+//!
+//! API data models for [new modmail], from `/api/mod/conversations`.
+//!
+//! [new modmail]: https://mod.reddit.com/mail
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A modmail conversation, without its messages.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversationData {
+    /// The identifier of this conversation.
+    pub id: String,
+    /// The subject of this conversation.
+    pub subject: String,
+    /// The state of this conversation: `0` for new, `1` for in progress and `2` for archived.
+    pub state: i32,
+    /// Whether this conversation was created automatically, e.g. a ban notification.
+    pub is_auto: bool,
+    /// Whether this conversation is only visible to moderators.
+    pub is_internal: bool,
+    /// Whether this conversation has been highlighted.
+    pub is_highlighted: bool,
+    /// Whether this conversation can be replied to.
+    #[serde(default)]
+    pub is_repliable: bool,
+    /// The number of messages in this conversation.
+    pub num_messages: u32,
+    /// When this conversation was last updated.
+    pub last_updated: Option<String>,
+    /// When a user (non-moderator) last replied.
+    pub last_user_update: Option<String>,
+    /// When a moderator last replied.
+    pub last_mod_update: Option<String>,
+    /// When the oldest unread message was sent, if any are unread.
+    pub last_unread: Option<String>,
+    /// The subreddit this conversation belongs to.
+    pub owner: ModmailOwner,
+    /// The user the moderators are talking to.
+    ///
+    /// This is empty (i.e. has an empty name) for internal moderator discussions.
+    #[serde(default)]
+    pub participant: ModmailAuthor,
+    /// Everyone who has sent a message in this conversation.
+    pub authors: Vec<ModmailAuthor>,
+    /// The messages and moderator actions in this conversation, oldest first.
+    pub obj_ids: Vec<ModmailObjectId>,
+}
+
+/// The subreddit a modmail conversation belongs to.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailOwner {
+    /// The fullname of the subreddit.
+    pub id: String,
+    /// The name of the subreddit.
+    pub display_name: String,
+    /// The kind of owner, always `subreddit`.
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// Someone taking part in a modmail conversation.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ModmailAuthor {
+    /// The name of the user (without the `/u/`).
+    pub name: String,
+    /// Whether they are a moderator of the subreddit.
+    pub is_mod: bool,
+    /// Whether they are a Reddit admin.
+    pub is_admin: bool,
+    /// Whether they started the conversation.
+    pub is_op: bool,
+    /// Whether they are the user the moderators are talking to.
+    pub is_participant: bool,
+    /// Whether they replied as the subreddit rather than as themselves.
+    pub is_hidden: bool,
+    /// Whether their account has been deleted.
+    pub is_deleted: bool,
+}
+
+/// A reference to an object within a modmail conversation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ModmailObjectId {
+    /// The identifier of the object.
+    pub id: String,
+    /// The kind of object, e.g. `messages` or `modActions`.
+    pub key: String,
+}
+
+/// A message within a modmail conversation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailMessage {
+    /// The identifier of this message.
+    pub id: String,
+    /// The body of the message as HTML.
+    pub body: String,
+    /// The body of the message in markdown.
+    pub body_markdown: String,
+    /// Who sent the message.
+    pub author: ModmailAuthor,
+    /// Whether this message is only visible to moderators.
+    pub is_internal: bool,
+    /// When the message was sent.
+    pub date: String,
+}
+
+/// The response from listing conversations.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModmailConversations {
+    pub conversations: HashMap<String, ModmailConversationData>,
+    pub conversation_ids: Vec<String>,
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+}
+
+/// The response from fetching or replying to a single conversation.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ModmailConversationResponse {
+    pub conversation: ModmailConversationData,
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+}
@@ -0,0 +1,71 @@
+//! # Multireddit Responses
+//! Responses from `/api/multi`, about multireddits (custom feeds) which combine several subreddits.
+
+use serde::Deserialize;
+
+use crate::api::response::BasicThing;
+
+/// A subreddit in a multireddit.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MultiSubreddit {
+    /// The name of the subreddit, without the `/r/` prefix.
+    pub name: String,
+}
+
+/// A multireddit, also called a custom feed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MultiData {
+    /// The name used in the multireddit's URL.
+    pub name: String,
+    /// The name shown for the multireddit.
+    pub display_name: String,
+    /// The path to the multireddit, e.g. `/user/someone/m/name/`.
+    pub path: String,
+    /// The name of the user who owns the multireddit.
+    pub owner: String,
+    /// The subreddits combined in the multireddit.
+    #[serde(default)]
+    pub subreddits: Vec<MultiSubreddit>,
+    /// The description, in markdown.
+    #[serde(default)]
+    pub description_md: String,
+    /// Whether the multireddit is `public`, `private` or `hidden`.
+    pub visibility: String,
+    /// This is `true` if the multireddit contains NSFW subreddits.
+    #[serde(default)]
+    pub over_18: bool,
+    /// The number of users following the multireddit.
+    #[serde(default)]
+    pub num_subscribers: u64,
+    /// The URL of the multireddit's icon.
+    pub icon_url: Option<String>,
+    /// The path of the multireddit this was copied from, if any.
+    pub copied_from: Option<String>,
+    /// A timestamp of the time when the multireddit was created, in **UTC**.
+    pub created_utc: f64,
+}
+
+/// The response from `/api/multi/mine` and `/api/multi/user/{username}`.
+pub(crate) type MultiList = Vec<BasicThing<MultiData>>;
+
+#[cfg(test)]
+mod tests {
+    use super::MultiList;
+
+    #[test]
+    fn test_multi_list() {
+        let multis: MultiList = serde_json::from_str(
+            r#"[{"kind": "LabeledMulti", "data": {
+                "name": "langs", "display_name": "Languages", "path": "/user/someone/m/langs/",
+                "owner": "someone", "subreddits": [{"name": "rust"}, {"name": "golang"}],
+                "description_md": "", "visibility": "public", "over_18": false,
+                "num_subscribers": 3, "icon_url": null, "copied_from": null,
+                "created_utc": 1700000000.0, "can_edit": false
+            }}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(multis.len(), 1);
+        assert_eq!(multis[0].data.subreddits[1].name, "golang");
+    }
+}
@@ -1,6 +1,8 @@
 //! # Responses
 //! Base responses
 
+use std::time::Duration;
+
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::api::ThingFullname;
@@ -74,12 +76,116 @@ pub(crate) struct PostResponseInner<T> {
 #[derive(Debug, Deserialize)]
 pub struct ApiError(pub [String; 3]);
 
-/// A response for something that has been created, but without its actual data.
-#[derive(Deserialize, Debug)]
-pub(crate) struct LazyThingCreatedData {
-    #[allow(unused)]
-    pub id: String,
-    pub name: ThingFullname,
+impl ApiError {
+    /// The error code, e.g. `RATELIMIT`.
+    pub fn code(&self) -> ApiErrorCode {
+        ApiErrorCode::from(self.0[0].as_str())
+    }
+
+    /// The human readable description of the error.
+    pub fn message(&self) -> &str {
+        &self.0[1]
+    }
+
+    /// The form field the error relates to, if any.
+    pub fn field(&self) -> Option<&str> {
+        Some(self.0[2].as_str()).filter(|f| !f.is_empty())
+    }
+
+    /// For ratelimit errors, how long Reddit says to wait before trying again.
+    ///
+    /// This is parsed from the message (e.g. "try again in 34 minutes"), so is only as precise as it is.
+    pub fn retry_after(&self) -> Option<Duration> {
+        if !matches!(
+            self.code(),
+            ApiErrorCode::Ratelimit | ApiErrorCode::SubredditRatelimit
+        ) {
+            return None;
+        }
+
+        let message = self.message().to_lowercase();
+        let rest = ["try again in ", "take a break for "]
+            .into_iter()
+            .find_map(|marker| message.split_once(marker))?
+            .1;
+        let mut words = rest.split_whitespace();
+        let amount: u64 = words.next()?.parse().ok()?;
+        let unit = words.next()?.trim_end_matches(['.', ',']);
+
+        match unit.trim_end_matches('s') {
+            "millisecond" => Some(Duration::from_millis(amount)),
+            "second" => Some(Duration::from_secs(amount)),
+            "minute" => Some(Duration::from_secs(amount * 60)),
+            "hour" => Some(Duration::from_secs(amount * 60 * 60)),
+            _ => None,
+        }
+    }
+}
+
+/// The code of an [`ApiError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiErrorCode {
+    /// The account is doing that action too often.
+    Ratelimit,
+    /// The account is doing that action too often in this subreddit.
+    SubredditRatelimit,
+    /// The named user does not exist.
+    UserDoesntExist,
+    /// No user was specified.
+    NoUser,
+    /// The subreddit does not exist.
+    SubredditNoExist,
+    /// The user is already a moderator of the subreddit.
+    AlreadyModerator,
+    /// The user has not been invited to moderate the subreddit.
+    NoInviteFound,
+    /// The user is not allowed to do that.
+    NotAllowed,
+    /// The user is not allowed to post or comment in the subreddit, e.g. because it is
+    /// restricted or they do not meet its karma or account age requirements.
+    SubredditNotAllowed,
+    /// The recipient only accepts private messages from users they have whitelisted.
+    NotWhitelistedByUserMessage,
+    /// The post is archived, so can no longer be commented on or voted on.
+    TooOld,
+    /// The post or comment is locked, so can no longer be replied to.
+    ThreadLocked,
+    /// Any other error code.
+    Other(String),
+}
+
+impl From<&str> for ApiErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "RATELIMIT" => ApiErrorCode::Ratelimit,
+            "SUBREDDIT_RATELIMIT" => ApiErrorCode::SubredditRatelimit,
+            "USER_DOESNT_EXIST" => ApiErrorCode::UserDoesntExist,
+            "NO_USER" => ApiErrorCode::NoUser,
+            "SUBREDDIT_NOEXIST" => ApiErrorCode::SubredditNoExist,
+            "ALREADY_MODERATOR" => ApiErrorCode::AlreadyModerator,
+            "NO_INVITE_FOUND" => ApiErrorCode::NoInviteFound,
+            "NOT_ALLOWED" => ApiErrorCode::NotAllowed,
+            "SUBREDDIT_NOTALLOWED" => ApiErrorCode::SubredditNotAllowed,
+            "NOT_WHITELISTED_BY_USER_MESSAGE" => ApiErrorCode::NotWhitelistedByUserMessage,
+            "TOO_OLD" => ApiErrorCode::TooOld,
+            "THREAD_LOCKED" => ApiErrorCode::ThreadLocked,
+            other => ApiErrorCode::Other(other.to_owned()),
+        }
+    }
+}
+
+impl ApiErrorCode {
+    /// Whether retrying the same request later could succeed.
+    ///
+    /// Ratelimits pass with time, see [`ApiError::retry_after`]. Errors such as
+    /// [`ApiErrorCode::ThreadLocked`] or [`ApiErrorCode::SubredditNotAllowed`] will not, so bots
+    /// should skip the post or subreddit instead. Unknown codes are assumed not to pass.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ApiErrorCode::Ratelimit | ApiErrorCode::SubredditRatelimit
+        )
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -98,3 +204,72 @@ pub type OuterBasicListing<T> = BasicThing<Listing<T>>;
 
 /// Often times a basic thing will have this structure.
 pub type BasicListing<T> = BasicThing<Listing<BasicThing<T>>>;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde::de::IgnoredAny;
+
+    use super::{ApiError, ApiErrorCode, PostResponse};
+    use crate::util::RouxError;
+
+    fn error(code: &str, message: &str) -> ApiError {
+        ApiError([code.to_owned(), message.to_owned(), "ratelimit".to_owned()])
+    }
+
+    #[test]
+    fn test_post_response_errors() {
+        let response: PostResponse<IgnoredAny> = serde_json::from_str(
+            r#"{"json": {"errors": [["NO_INVITE_FOUND", "there is no pending invite", ""]]}}"#,
+        )
+        .unwrap();
+        let error = RouxError::reddit_error(response.json.errors);
+        assert_eq!(error.api_error_code(), Some(ApiErrorCode::NoInviteFound));
+    }
+
+    #[test]
+    fn test_posting_error_codes() {
+        let response: PostResponse<IgnoredAny> = serde_json::from_str(
+            r#"{"json": {"errors": [
+                ["THREAD_LOCKED", "Comments are locked.", "parent"],
+                ["TOO_OLD", "that's a piece of history now", "parent"]
+            ]}}"#,
+        )
+        .unwrap();
+        let codes: Vec<_> = response.json.errors.iter().map(ApiError::code).collect();
+        assert_eq!(codes, [ApiErrorCode::ThreadLocked, ApiErrorCode::TooOld]);
+        assert!(codes.iter().all(|code| !code.is_retryable()));
+
+        assert_eq!(
+            ApiErrorCode::from("SUBREDDIT_NOTALLOWED"),
+            ApiErrorCode::SubredditNotAllowed
+        );
+        assert_eq!(
+            ApiErrorCode::from("NOT_WHITELISTED_BY_USER_MESSAGE"),
+            ApiErrorCode::NotWhitelistedByUserMessage
+        );
+        assert!(ApiErrorCode::Ratelimit.is_retryable());
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let minutes = error(
+            "RATELIMIT",
+            "Looks like you've been doing that a lot. Take a break for 3 minutes before trying again.",
+        );
+        assert_eq!(minutes.retry_after(), Some(Duration::from_secs(3 * 60)));
+
+        let minutes = error(
+            "RATELIMIT",
+            "you are doing that too much. try again in 34 minutes.",
+        );
+        assert_eq!(minutes.retry_after(), Some(Duration::from_secs(34 * 60)));
+
+        let second = error("SUBREDDIT_RATELIMIT", "try again in 1 second.");
+        assert_eq!(second.retry_after(), Some(Duration::from_secs(1)));
+
+        let other = error("NOT_ALLOWED", "try again in 1 second.");
+        assert_eq!(other.retry_after(), None);
+    }
+}
@@ -18,3 +18,17 @@ pub enum SavedData {
 
 /// Saved listing
 pub type APISaved = BasicListing<SavedData>;
+
+/// A category that saved items can be sorted into, a Reddit Premium feature.
+#[derive(Debug, Deserialize)]
+pub struct SavedCategory {
+    /// The name of the category.
+    pub category: String,
+}
+
+/// The response from `api/saved_categories`
+#[derive(Debug, Deserialize)]
+pub(crate) struct SavedCategories {
+    #[serde(default)]
+    pub categories: Vec<SavedCategory>,
+}
@@ -20,6 +20,9 @@ pub struct SubmissionData {
     // pub media_embed: MediaEmbed,
     /// The subreddit that this submission was posted in (not including `/r/`)
     pub subreddit: String,
+    /// The type of the subreddit, e.g. `public`, `restricted` or `archived`.
+    #[serde(default)]
+    pub subreddit_type: Option<String>,
     /// If this is a self post, it contains the HTML of the post body. Otherwise, it is `None`.
     pub selftext_html: Option<String>,
     /// The self text in **Markdown** format, if this is a self post. Unlike `selftext_html`, this
@@ -149,6 +152,27 @@ pub struct SubmissionData {
     pub gallery_data: Option<SubmissionDataGalleryData>,
     /// The media metadata, used by the gallery if it is present.
     pub media_metadata: Option<HashMap<String, SubmissionDataMediaMetadata>>,
+    /// The media attached to the post, e.g. a video hosted by Reddit.
+    #[serde(default)]
+    pub media: Option<SubmissionDataMedia>,
+    /// How the comments are shown, if not as usual.
+    #[serde(default)]
+    pub discussion_type: Option<DiscussionType>,
+    /// The poll, if this is a poll or prediction post.
+    #[serde(default)]
+    pub poll_data: Option<SubmissionDataPoll>,
+    /// The broadcast, if this is a Reddit Public Access Network (RPAN) stream.
+    #[serde(default)]
+    pub rpan_video: Option<SubmissionDataRpanVideo>,
+    /// A timestamp of when the event starts, in **UTC**, if this is an event post.
+    #[serde(default)]
+    pub event_start: Option<f64>,
+    /// A timestamp of when the event ends, in **UTC**, if this is an event post.
+    #[serde(default)]
+    pub event_end: Option<f64>,
+    /// This is `true` if this is an event post and the event is happening now.
+    #[serde(default)]
+    pub event_is_live: bool,
     /// Moderation related data for this post.
     ///
     /// This is present only if you are a moderator and can moderate this post.
@@ -188,6 +212,82 @@ pub struct SubmissionDataPreviewImageSource {
     pub height: u64,
 }
 
+/// Submission media
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionDataMedia {
+    /// Present if the media is a video hosted by Reddit.
+    #[serde(default)]
+    pub reddit_video: Option<SubmissionDataRedditVideo>,
+}
+
+/// A video hosted by Reddit
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionDataRedditVideo {
+    /// A direct link to the video, without audio.
+    pub fallback_url: String,
+    /// A HLS playlist for the video, including audio.
+    pub hls_url: Option<String>,
+    /// A DASH playlist for the video, including audio.
+    pub dash_url: Option<String>,
+    /// The duration, in seconds.
+    pub duration: Option<u64>,
+    /// Width
+    pub width: Option<u64>,
+    /// Height
+    pub height: Option<u64>,
+    /// Whether the video is a gif converted to a video.
+    #[serde(default)]
+    pub is_gif: bool,
+}
+
+/// How a submission's comments are shown
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiscussionType {
+    /// The comments are shown as a live chat.
+    #[serde(rename = "CHAT")]
+    Chat,
+    /// A kind of discussion not known to this crate.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A poll attached to a submission
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionDataPoll {
+    /// The options which can be voted for.
+    #[serde(default)]
+    pub options: Vec<SubmissionDataPollOption>,
+    /// The total number of votes, if they are visible.
+    pub total_vote_count: Option<u64>,
+    /// When voting ends, in milliseconds since the Unix epoch.
+    pub voting_end_timestamp: Option<f64>,
+    /// The ID of the option the logged-in user voted for.
+    pub user_selection: Option<String>,
+    /// Whether this poll is a prediction.
+    #[serde(default)]
+    pub is_prediction: bool,
+}
+
+/// An option of a poll
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionDataPollOption {
+    /// Option ID
+    pub id: String,
+    /// Option text
+    pub text: String,
+    /// The number of votes, if they are visible.
+    pub vote_count: Option<u64>,
+}
+
+/// A Reddit Public Access Network (RPAN) broadcast
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionDataRpanVideo {
+    /// A HLS playlist for the broadcast.
+    pub hls_url: Option<String>,
+    /// A link to an image strip of the broadcast.
+    pub scrubber_media_url: Option<String>,
+}
+
 /// Submission gallery data
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SubmissionDataGalleryData {
@@ -362,3 +462,104 @@ pub struct SubmissionMetadataImage {
 
 /// Submissions
 pub type APISubmissions = BasicListing<SubmissionData>;
+
+/// A post which has just been submitted, from the response to `api/submit`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmittedPost {
+    /// The ID of the post, e.g. `abc123`.
+    pub id: String,
+    /// The fullname of the post, e.g. `t3_abc123`.
+    pub name: ThingFullname,
+    /// The URL of the post's comments page.
+    pub url: String,
+}
+
+/// A gallery post which has just been submitted, from the response to `api/submit_gallery_post`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmittedGallery {
+    /// The URL of the post's comments page.
+    pub url: String,
+}
+
+/// The response from `duplicates/{article}`, a listing with the submission followed by a listing
+/// of its duplicates.
+#[derive(Debug)]
+pub(crate) struct DuplicatesResponse {
+    pub duplicates: APISubmissions,
+}
+
+impl<'de> Deserialize<'de> for DuplicatesResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        type Encoded = (serde::de::IgnoredAny, APISubmissions);
+
+        let (_, duplicates) = Encoded::deserialize(deserializer)?;
+        Ok(DuplicatesResponse { duplicates })
+    }
+}
+
+/// A minimal submission as Reddit returns it, with `fields` added or replaced, for tests.
+#[cfg(test)]
+pub(crate) fn test_submission(fields: serde_json::Value) -> serde_json::Value {
+    let mut submission: serde_json::Value =
+        serde_json::from_str(include_str!("../../../tests/fixtures/submission.json")).unwrap();
+    if let serde_json::Value::Object(fields) = fields {
+        submission.as_object_mut().unwrap().extend(fields);
+    }
+    submission
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{test_submission, DuplicatesResponse, SubmittedPost};
+    use crate::api::response::PostResponse;
+
+    #[test]
+    fn test_duplicates_response() {
+        let listing = |ids: &[&str]| {
+            let children: Vec<_> = ids
+                .iter()
+                .map(|id| {
+                    let data = test_submission(json!({"id": id, "name": format!("t3_{id}")}));
+                    json!({"kind": "t3", "data": data})
+                })
+                .collect();
+            json!({"kind": "Listing", "data": {"children": children}})
+        };
+
+        let json = json!([listing(&["abc123"]), listing(&["def456", "ghi789"])]);
+        let response: DuplicatesResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.duplicates.data.children.len(), 2);
+        assert_eq!(response.duplicates.data.children[1].data.id, "ghi789");
+
+        let none = json!([listing(&["abc123"]), listing(&[])]);
+        let response: DuplicatesResponse = serde_json::from_value(none).unwrap();
+        assert!(response.duplicates.data.children.is_empty());
+    }
+
+    #[test]
+    fn test_submitted_post() {
+        let response: PostResponse<SubmittedPost> = serde_json::from_str(
+            r#"{"json": {"errors": [], "data": {
+                "url": "https://www.reddit.com/r/rust/comments/abc123/hello/",
+                "drafts_count": 0,
+                "id": "abc123",
+                "name": "t3_abc123"
+            }}}"#,
+        )
+        .unwrap();
+
+        let post = response.json.data.unwrap();
+        assert_eq!(post.id, "abc123");
+        assert_eq!(post.name.full(), "t3_abc123");
+        assert_eq!(
+            post.url,
+            "https://www.reddit.com/r/rust/comments/abc123/hello/"
+        );
+    }
+}
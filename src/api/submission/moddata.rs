@@ -39,14 +39,30 @@ pub struct SubmissionModerationData {
     pub removed: bool,
     /// Who removed the post
     pub removed_by: Option<String>,
-    /// Which type of user removed it (presumably an enum of moderator and admin)
-    pub removed_by_category: Option<String>,
+    /// Who removed the post, if it has been removed
+    pub removed_by_category: Option<RemovedByCategory>,
     /// Whether it was removed for spam
     pub spam: bool,
     /// Reports made by users.
     pub user_reports: Vec<SubmissionUserReport>,
 }
 
+impl SubmissionModerationData {
+    /// Updates the data to match the submission having just been approved at `at`.
+    ///
+    /// `approved_by` is left as it was, since the approving moderator's name is not known.
+    pub(crate) fn mark_approved(&mut self, at: f64) {
+        self.approved = true;
+        self.approved_at_utc = Some(at);
+        self.removed = false;
+        self.spam = false;
+        self.removed_by = None;
+        self.removed_by_category = None;
+        self.banned_by = None;
+        self.banned_at_utc = None;
+    }
+}
+
 pub(super) fn deserialize<'de, D>(
     deserializer: D,
 ) -> Result<Option<SubmissionModerationData>, D::Error>
@@ -90,6 +106,26 @@ where
     }
 }
 
+/// Who removed a submission, from its `removed_by_category`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemovedByCategory {
+    /// One of the subreddit's moderators.
+    Moderator,
+    /// AutoModerator, or the subreddit's filters, which hold it for review.
+    #[serde(rename = "automod_filtered")]
+    AutoModerator,
+    /// Reddit's spam filter or admins.
+    Reddit,
+    /// The author deleted it.
+    Deleted,
+    /// Reddit removed it after a copyright notice.
+    CopyrightTakedown,
+    /// A category roux does not know about, such as `anti_evil_ops`.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 /// Reports by one of the subreddit's moderators.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct SubmissionModeratorReport([String; 2]);
@@ -211,7 +247,7 @@ mod tests {
                 removal_reason: None,
                 removed: true,
                 removed_by: Some(String::from("exampleUsername")),
-                removed_by_category: Some(String::from("moderator")),
+                removed_by_category: Some(RemovedByCategory::Moderator),
                 spam: false,
                 user_reports: Vec::new()
             })
@@ -233,6 +269,48 @@ mod tests {
         assert_eq!(container.mod_data, None);
     }
 
+    #[test]
+    pub fn test_mark_approved() {
+        const DATA: &str = include_str!("is_mod.json");
+
+        #[derive(Deserialize)]
+        struct Container {
+            #[serde(flatten, deserialize_with = "deserialize")]
+            pub mod_data: Option<SubmissionModerationData>,
+        }
+
+        let mut data = serde_json::from_str::<Container>(DATA)
+            .unwrap()
+            .mod_data
+            .unwrap();
+        data.mark_approved(1725100000.0);
+
+        assert!(data.approved && !data.removed && !data.spam);
+        assert_eq!(data.approved_at_utc, Some(1725100000.0));
+        assert_eq!(data.banned_by, None);
+        assert_eq!(data.removed_by_category, None);
+    }
+
+    #[test]
+    pub fn test_removed_by_category_serde() {
+        let categories: Vec<RemovedByCategory> =
+            serde_json::from_str(r#"["automod_filtered", "copyright_takedown", "anti_evil_ops"]"#)
+                .unwrap();
+
+        assert_eq!(
+            categories,
+            [
+                RemovedByCategory::AutoModerator,
+                RemovedByCategory::CopyrightTakedown,
+                RemovedByCategory::Unknown(String::from("anti_evil_ops"))
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&categories).unwrap(),
+            r#"["automod_filtered","copyright_takedown","anti_evil_ops"]"#
+        );
+    }
+
     #[test]
     pub fn test_user_reports_serde() {
         const DATA: &str = r#"["A rule",5,false,true]"#;
@@ -0,0 +1,72 @@
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use crate::api::ThingFullname;
+
+/// A user banned from a subreddit, from `/r/{subreddit}/about/banned`.
+#[derive(Debug, Deserialize)]
+pub struct BannedUserData {
+    /// The name of the user (without the `/u/`).
+    pub name: String,
+    /// The fullname of the user.
+    pub id: ThingFullname,
+    /// When the user was banned, in seconds since the Unix epoch.
+    pub date: f64,
+    /// The note left by the moderator who banned the user.
+    #[serde(default)]
+    pub note: Option<String>,
+    /// How many days are left until the ban expires, or `None` if it is permanent.
+    #[serde(default)]
+    pub days_left: Option<u32>,
+    /// The ID of the ban.
+    #[serde(default)]
+    pub rel_id: Option<String>,
+}
+
+impl BannedUserData {
+    /// When the user was banned.
+    pub fn banned_at(&self) -> Option<SystemTime> {
+        crate::util::utc_timestamp(self.date)
+    }
+
+    /// Whether the ban never expires.
+    pub fn is_permanent(&self) -> bool {
+        self.days_left.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::BannedUserData;
+    use crate::api::response::OuterBasicListing;
+
+    #[test]
+    fn test_banned_listing() {
+        let listing: OuterBasicListing<BannedUserData> = serde_json::from_str(
+            r#"{
+                "kind": "Listing",
+                "data": {
+                    "after": "t2_def",
+                    "before": null,
+                    "children": [
+                        { "date": 1700000000.0, "note": "spam", "days_left": 3, "rel_id": "rb_abc", "name": "spammer", "id": "t2_abc" },
+                        { "date": 1600000000.0, "note": "", "days_left": null, "rel_id": "rb_def", "name": "troll", "id": "t2_def" }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let bans = &listing.data.children;
+        assert_eq!(bans[0].name, "spammer");
+        assert_eq!(
+            bans[0].banned_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+        assert!(!bans[0].is_permanent());
+        assert!(bans[1].is_permanent());
+    }
+}
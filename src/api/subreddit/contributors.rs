@@ -0,0 +1,52 @@
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use crate::api::ThingFullname;
+
+/// An approved submitter of a subreddit, from `/r/{subreddit}/about/contributors`.
+#[derive(Debug, Deserialize)]
+pub struct ContributorData {
+    /// The name of the user (without the `/u/`).
+    pub name: String,
+    /// The fullname of the user.
+    pub id: ThingFullname,
+    /// When the user was approved, in seconds since the Unix epoch.
+    pub date: f64,
+    /// The ID of the approval.
+    #[serde(default)]
+    pub rel_id: Option<String>,
+}
+
+impl ContributorData {
+    /// When the user was approved.
+    pub fn approved_at(&self) -> Option<SystemTime> {
+        crate::util::utc_timestamp(self.date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContributorData;
+    use crate::api::response::OuterBasicListing;
+
+    #[test]
+    fn test_contributors_listing() {
+        let listing: OuterBasicListing<ContributorData> = serde_json::from_str(
+            r#"{
+                "kind": "Listing",
+                "data": {
+                    "after": null,
+                    "before": null,
+                    "children": [{ "date": 1700000000.0, "rel_id": "rb_abc", "name": "helper", "id": "t2_abc" }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let contributor = &listing.data.children[0];
+        assert_eq!(contributor.name, "helper");
+        assert_eq!(contributor.id.full(), "t2_abc");
+        assert!(contributor.approved_at().is_some());
+    }
+}
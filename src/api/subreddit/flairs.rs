@@ -11,6 +11,35 @@ pub struct FlairSelection {
     pub current: FlairCurrentChoice,
 }
 
+impl FlairSelection {
+    /// The choices whose text contains `text`, ignoring case.
+    pub fn choices_matching<'a>(&'a self, text: &str) -> impl Iterator<Item = &'a FlairChoice> {
+        let text = text.to_lowercase();
+        self.choices
+            .iter()
+            .filter(move |choice| choice.flair_text.to_lowercase().contains(&text))
+    }
+
+    /// The choice whose text is exactly `text`, ignoring case.
+    pub fn choice_by_text(&self, text: &str) -> Option<&FlairChoice> {
+        self.choices
+            .iter()
+            .find(|choice| choice.flair_text.eq_ignore_ascii_case(text))
+    }
+
+    /// The choice with the template ID.
+    pub fn choice_by_id(&self, template_id: &FlairId) -> Option<&FlairChoice> {
+        self.choices
+            .iter()
+            .find(|choice| &choice.flair_template_id == template_id)
+    }
+
+    /// The choice which is currently selected, if any.
+    pub fn current_choice(&self) -> Option<&FlairChoice> {
+        self.choice_by_id(self.current.flair_template_id.as_ref()?)
+    }
+}
+
 /// The current flair choice.
 ///
 /// template_id and text may be None if no flair is currently selected.
@@ -39,6 +68,93 @@ pub struct FlairChoice {
     pub flair_text: String,
     /// Whether the text can be edited
     pub flair_text_editable: bool,
+    /// The background color as a hex code, if Reddit provided one.
+    #[serde(default)]
+    pub flair_background_color: Option<String>,
+    /// Whether the text is `dark` or `light`, if Reddit provided it.
+    #[serde(default)]
+    pub flair_text_color: Option<String>,
+}
+
+/// Whether a flair template is for posts or users.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlairType {
+    /// Flair for posts.
+    Link,
+    /// Flair for users.
+    User,
+}
+
+impl FlairType {
+    /// The `flair_type` Reddit uses for this kind of flair.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlairType::Link => "LINK_FLAIR",
+            FlairType::User => "USER_FLAIR",
+        }
+    }
+
+    /// The endpoint listing the subreddit's templates of this kind.
+    pub(crate) fn templates_endpoint(&self) -> &'static str {
+        match self {
+            FlairType::Link => "api/link_flair_v2",
+            FlairType::User => "api/user_flair_v2",
+        }
+    }
+}
+
+/// What a flair's text may contain.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FlairAllowableContent {
+    /// Text and emojis.
+    All,
+    /// Only emojis.
+    Emoji,
+    /// Only text.
+    Text,
+    /// A value roux does not know about.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl FlairAllowableContent {
+    /// The value Reddit uses for this setting.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FlairAllowableContent::All => "all",
+            FlairAllowableContent::Emoji => "emoji",
+            FlairAllowableContent::Text => "text",
+            FlairAllowableContent::Other(value) => value,
+        }
+    }
+}
+
+/// A flair template in a subreddit, which users or posts can select.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FlairTemplate {
+    /// The ID of the template.
+    pub id: FlairId,
+    /// The text of the flair.
+    pub text: String,
+    /// Whether users can edit the text when selecting the flair.
+    pub text_editable: bool,
+    /// Whether only moderators can select the flair.
+    #[serde(default)]
+    pub mod_only: bool,
+    /// The background color as a hex code, or empty/`transparent` for none.
+    #[serde(default)]
+    pub background_color: Option<String>,
+    /// Whether the text is `dark` or `light`.
+    #[serde(default)]
+    pub text_color: Option<String>,
+    /// The CSS class used by old Reddit.
+    #[serde(default)]
+    pub css_class: Option<String>,
+    /// What the text may contain.
+    pub allowable_content: FlairAllowableContent,
+    /// The most emojis the text may contain.
+    pub max_emojis: usize,
 }
 
 /// An entry in a subreddit's list of user flairs
@@ -60,3 +176,56 @@ pub struct FlairList {
     /// The next page to fetch
     pub next: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{FlairAllowableContent, FlairSelection, FlairTemplate};
+
+    #[test]
+    fn test_choices_matching() {
+        let selection: FlairSelection = serde_json::from_str(
+            r##"{
+                "choices": [
+                    {"flair_css_class": "", "flair_position": "right", "flair_template_id": "a1",
+                     "flair_text": "Question", "flair_text_editable": false},
+                    {"flair_css_class": "meta", "flair_position": "right", "flair_template_id": "b2",
+                     "flair_text": "Meta question", "flair_text_editable": true,
+                     "flair_background_color": "#ff4500", "flair_text_color": "light"}
+                ],
+                "current": {"flair_css_class": "", "flair_position": "right",
+                            "flair_template_id": "b2", "flair_text": "Meta question"}
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(selection.choices_matching("QUESTION").count(), 2);
+        assert_eq!(
+            selection
+                .choice_by_text("question")
+                .map(|c| c.flair_template_id.as_str()),
+            Some("a1")
+        );
+        let current = selection.current_choice().unwrap();
+        assert!(current.flair_text_editable);
+        assert_eq!(current.flair_background_color.as_deref(), Some("#ff4500"));
+    }
+
+    #[test]
+    fn test_flair_templates() {
+        let templates: Vec<FlairTemplate> = serde_json::from_str(
+            r##"[{
+                "type": "richtext", "text_editable": false, "allowable_content": "emoji",
+                "text": ":ferris: Rustacean", "max_emojis": 3, "text_color": "light",
+                "mod_only": true, "css_class": "", "richtext": [], "background_color": "#dea584",
+                "id": "a1b2c3d4-0000-0000-0000-000000000000", "override_css": false
+            }]"##,
+        )
+        .unwrap();
+
+        let template = &templates[0];
+        assert_eq!(template.id.as_str(), "a1b2c3d4-0000-0000-0000-000000000000");
+        assert_eq!(template.allowable_content, FlairAllowableContent::Emoji);
+        assert!(template.mod_only);
+        assert_eq!(template.max_emojis, 3);
+    }
+}
@@ -3,15 +3,35 @@ use serde::Deserialize;
 
 use crate::api::{response::BasicListing, FlairId, ThingFullname};
 
+mod banned;
+mod contributors;
 mod flairs;
 mod modlog;
 mod modqueue;
+mod muted;
+mod post_requirements;
 mod removal_reasons;
+mod settings;
+mod styles;
+mod stylesheet;
+mod trending;
+mod widgets;
+mod wiki;
 
+pub use banned::*;
+pub use contributors::*;
 pub use flairs::*;
 pub use modlog::*;
 pub use modqueue::*;
+pub use muted::*;
+pub use post_requirements::*;
 pub use removal_reasons::*;
+pub use settings::*;
+pub use styles::*;
+pub use stylesheet::*;
+pub use trending::*;
+pub use widgets::*;
+pub use wiki::*;
 
 /// SubredditResponse
 #[derive(Debug, Deserialize)]
@@ -33,6 +53,22 @@ pub enum AccountsActive {
     Vector(Vec<u8>),
 }
 
+/// The types of submission that can be made in a subreddit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionType {
+    /// Both link and self posts are allowed.
+    Any,
+    /// Only link posts are allowed.
+    Link,
+    /// Only self (text) posts are allowed.
+    #[serde(rename = "self")]
+    SelfPost,
+    /// Some banned and employee-only subreddits have an empty string, or Reddit may add new values.
+    #[serde(other)]
+    Unknown,
+}
+
 /// If the API user has user flair in this subreddit, and its user_flair_type is richtext,
 /// this will be an array containing two string elements which define the user's flair.
 #[allow(dead_code)]
@@ -221,7 +257,7 @@ pub struct SubredditData {
     pub show_media_preview: Option<bool>,
     /// The type of links that can be submitted in this subreddit. This will typically be one of any, link, or self;
     /// some banned and employee-only subreddits have this value set to an empty string.
-    pub submission_type: Option<String>,
+    pub submission_type: Option<SubmissionType>,
     /// Whether or not the API user has subscribed to this subreddit.
     pub user_is_subscriber: Option<bool>,
     /// Whether or not this subreddit has the "Accepting new requests to post" option turned off.
@@ -234,6 +270,12 @@ pub struct SubredditData {
     pub user_flair_type: Option<String>,
     /// Unknown
     pub allow_polls: Option<bool>,
+    /// Whether or not this subreddit allows talk (live audio) posts.
+    pub allow_talks: Option<bool>,
+    /// Whether or not this subreddit allows chat posts to be created.
+    pub allow_chat_post_creation: Option<bool>,
+    /// The types of media which can be included in comments, e.g. `giphy`, `static`, `animated` or `expression`.
+    pub allowed_media_in_comments: Option<Vec<String>>,
     /// Whether or not this subreddit has the "collapse deleted and removed comments" option enabled.
     pub collapse_deleted_comments: Option<bool>,
     /// If "Custom sized emojis" has been configured for this subreddit,
@@ -0,0 +1,47 @@
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use crate::api::ThingFullname;
+
+/// A user muted in a subreddit's modmail, from `/r/{subreddit}/about/muted`.
+#[derive(Debug, Deserialize)]
+pub struct MutedUserData {
+    /// The name of the user (without the `/u/`).
+    pub name: String,
+    /// The fullname of the user.
+    pub id: ThingFullname,
+    /// When the user was muted, in seconds since the Unix epoch.
+    pub date: f64,
+    /// The ID of the mute.
+    #[serde(default)]
+    pub rel_id: Option<String>,
+}
+
+impl MutedUserData {
+    /// When the user was muted.
+    pub fn muted_at(&self) -> Option<SystemTime> {
+        crate::util::utc_timestamp(self.date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::MutedUserData;
+
+    #[test]
+    fn test_muted_user() {
+        let muted: MutedUserData = serde_json::from_str(
+            r#"{ "date": 1700000000.0, "rel_id": "Mute_abc", "name": "shouty", "id": "t2_abc" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(muted.name, "shouty");
+        assert_eq!(
+            muted.muted_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use serde::Deserialize;
+
+/// The requirements a subreddit places on new posts, from `/api/v1/{subreddit}/post_requirements`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PostRequirements {
+    /// Regexes that the title must match one of.
+    pub title_regexes: Vec<String>,
+    /// Strings that the title must contain one of.
+    pub title_required_strings: Vec<String>,
+    /// Strings that the title must not contain.
+    pub title_blacklisted_strings: Vec<String>,
+    /// The minimum length of the title.
+    pub title_text_min_length: Option<u32>,
+    /// The maximum length of the title.
+    pub title_text_max_length: Option<u32>,
+    /// Regexes that the body must match one of.
+    pub body_regexes: Vec<String>,
+    /// Strings that the body must contain one of.
+    pub body_required_strings: Vec<String>,
+    /// Strings that the body must not contain.
+    pub body_blacklisted_strings: Vec<String>,
+    /// The minimum length of the body.
+    pub body_text_min_length: Option<u32>,
+    /// The maximum length of the body.
+    pub body_text_max_length: Option<u32>,
+    /// Whether a body is `required`, `notAllowed` or `none` (optional).
+    pub body_restriction_policy: Option<String>,
+    /// Whether links are restricted to the `whitelist`, away from the `blacklist`, or `none`.
+    pub link_restriction_policy: Option<String>,
+    /// Domains which links can be to, if the link restriction policy is `whitelist`.
+    pub domain_whitelist: Vec<String>,
+    /// Domains which links cannot be to, if the link restriction policy is `blacklist`.
+    pub domain_blacklist: Vec<String>,
+    /// How many days must pass before a link can be reposted.
+    pub link_repost_age: Option<u32>,
+    /// Whether a flair must be selected.
+    pub is_flair_required: bool,
+    /// The minimum number of items in a gallery.
+    pub gallery_min_items: Option<u32>,
+    /// The maximum number of items in a gallery.
+    pub gallery_max_items: Option<u32>,
+    /// Whether gallery items must have captions.
+    pub gallery_captions_requirement: Option<String>,
+    /// Whether gallery items must have urls.
+    pub gallery_urls_requirement: Option<String>,
+    /// Posting guidelines shown to users.
+    pub guidelines_text: Option<String>,
+    /// When the guidelines are shown.
+    pub guidelines_display_policy: Option<String>,
+}
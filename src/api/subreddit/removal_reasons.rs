@@ -21,3 +21,38 @@ pub struct RemovalReason {
     /// A title shown to moderators
     pub title: String,
 }
+
+impl SubredditRemovalReasons {
+    /// The removal reasons, in the order they appear.
+    pub fn ordered(&self) -> impl Iterator<Item = &RemovalReason> {
+        self.order.iter().filter_map(|id| self.data.get(id))
+    }
+}
+
+/// The response from creating a removal reason.
+#[derive(Debug, Deserialize)]
+pub(crate) struct CreatedRemovalReason {
+    pub id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubredditRemovalReasons;
+
+    #[test]
+    fn test_ordered_removal_reasons() {
+        let reasons: SubredditRemovalReasons = serde_json::from_str(
+            r#"{
+                "data": {
+                    "a": { "id": "a", "title": "Spam", "message": "No spam." },
+                    "b": { "id": "b", "title": "Off topic", "message": "Stay on topic." }
+                },
+                "order": ["b", "a"]
+            }"#,
+        )
+        .unwrap();
+
+        let titles: Vec<_> = reasons.ordered().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, ["Off topic", "Spam"]);
+    }
+}
@@ -0,0 +1,407 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::builders::form::FormBuilder;
+
+/// A subreddit's settings, from `/r/{subreddit}/about/edit`.
+///
+/// Only the settings roux understands are typed; the rest are kept in `other` so that they can
+/// be sent back unchanged. Typed settings are `None` if Reddit did not return them.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubredditSettings {
+    /// The title shown in the browser tab.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// The short description shown in search results and the sidebar, in markdown.
+    #[serde(default)]
+    pub public_description: Option<String>,
+    /// The old Reddit sidebar, in markdown.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Who can view and post, e.g. `public`, `restricted` or `private`.
+    #[serde(default)]
+    pub subreddit_type: Option<String>,
+    /// Whether the subreddit is marked NSFW.
+    #[serde(default)]
+    pub over_18: Option<bool>,
+    /// The kinds of submissions allowed.
+    #[serde(default, rename = "content_options")]
+    pub submission_types: Option<SubmissionTypes>,
+    /// Whether image posts are allowed.
+    #[serde(default)]
+    pub allow_images: Option<bool>,
+    /// Whether video posts are allowed.
+    #[serde(default)]
+    pub allow_videos: Option<bool>,
+    /// Whether poll posts are allowed.
+    #[serde(default)]
+    pub allow_polls: Option<bool>,
+    /// The spam filter for link posts.
+    #[serde(default)]
+    pub spam_links: Option<SpamFilter>,
+    /// The spam filter for self posts.
+    #[serde(default)]
+    pub spam_selfposts: Option<SpamFilter>,
+    /// The spam filter for comments.
+    #[serde(default)]
+    pub spam_comments: Option<SpamFilter>,
+    /// Whether crowd control is enabled, collapsing comments from users who are new to the
+    /// subreddit.
+    #[serde(default)]
+    pub crowd_control_mode: Option<bool>,
+    /// How strict crowd control is, from `0` (lenient) to `3` (strict).
+    #[serde(default)]
+    pub crowd_control_level: Option<u8>,
+    /// Who can edit the wiki.
+    #[serde(default)]
+    pub wikimode: Option<WikiMode>,
+    /// How old an account must be, in days, to edit the wiki.
+    #[serde(default)]
+    pub wiki_edit_age: Option<i64>,
+    /// How much karma in the subreddit a user needs to edit the wiki.
+    #[serde(default)]
+    pub wiki_edit_karma: Option<i64>,
+    /// The order comments are sorted in by default, or `None` for Reddit's default.
+    #[serde(default)]
+    pub suggested_comment_sort: Option<CommentSort>,
+    /// Whether new members are sent a welcome message when they join.
+    #[serde(default)]
+    pub welcome_message_enabled: bool,
+    /// The welcome message sent to new members, in markdown.
+    #[serde(default)]
+    pub welcome_message_text: Option<String>,
+    /// The other settings, as Reddit returned them.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl SubredditSettings {
+    /// The welcome message sent to new members.
+    pub fn welcome_message(&self) -> WelcomeMessage {
+        WelcomeMessage {
+            enabled: self.welcome_message_enabled,
+            text: self.welcome_message_text.clone().unwrap_or_default(),
+        }
+    }
+
+    /// The form for `api/site_admin`, which replaces every setting of the subreddit.
+    ///
+    /// `about/edit` and `api/site_admin` name some settings differently, so those are renamed.
+    /// Settings which are not strings, numbers or booleans are left out.
+    pub(crate) fn site_admin_form(&self) -> FormBuilder<'_> {
+        let mut form = FormBuilder::new()
+            .with_bool("welcome_message_enabled", self.welcome_message_enabled)
+            .with(
+                "welcome_message_text",
+                self.welcome_message_text.as_deref().unwrap_or_default(),
+            )
+            .with_opt("title", self.title.as_deref())
+            .with_opt("public_description", self.public_description.as_deref())
+            .with_opt("description", self.description.as_deref())
+            .with_opt("type", self.subreddit_type.as_deref())
+            .with_opt(
+                "link_type",
+                self.submission_types.as_ref().map(|t| t.as_str()),
+            )
+            .with_opt("spam_links", self.spam_links.as_ref().map(|f| f.as_str()))
+            .with_opt(
+                "spam_selfposts",
+                self.spam_selfposts.as_ref().map(|f| f.as_str()),
+            )
+            .with_opt(
+                "spam_comments",
+                self.spam_comments.as_ref().map(|f| f.as_str()),
+            )
+            .with_opt("wikimode", self.wikimode.as_ref().map(|m| m.as_str()))
+            .with(
+                "suggested_comment_sort",
+                self.suggested_comment_sort
+                    .as_ref()
+                    .map_or("", |sort| sort.as_str()),
+            )
+            .with_opt(
+                "crowd_control_level",
+                self.crowd_control_level.map(|l| l.to_string()),
+            )
+            .with_opt(
+                "wiki_edit_age",
+                self.wiki_edit_age.map(|age| age.to_string()),
+            )
+            .with_opt(
+                "wiki_edit_karma",
+                self.wiki_edit_karma.map(|karma| karma.to_string()),
+            );
+
+        for (key, value) in [
+            ("over_18", self.over_18),
+            ("allow_images", self.allow_images),
+            ("allow_videos", self.allow_videos),
+            ("allow_polls", self.allow_polls),
+            ("crowd_control_mode", self.crowd_control_mode),
+        ] {
+            if let Some(value) = value {
+                form.add(key, if value { "true" } else { "false" });
+            }
+        }
+
+        for (key, value) in &self.other {
+            let key = match key.as_str() {
+                "default_set" => "allow_top",
+                "language" => "lang",
+                "subreddit_id" => "sr",
+                other => other,
+            };
+            match value {
+                Value::String(value) => form.add(key, value.as_str()),
+                Value::Bool(value) => form.add(key, if *value { "true" } else { "false" }),
+                Value::Number(value) => form.add(key, value.to_string()),
+                _ => {}
+            }
+        }
+
+        form
+    }
+}
+
+/// The kinds of submissions a subreddit allows.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionTypes {
+    /// Both link and self posts.
+    Any,
+    /// Only link posts.
+    Link,
+    /// Only self (text) posts.
+    #[serde(rename = "self")]
+    SelfPost,
+    /// A value roux does not know about.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl SubmissionTypes {
+    /// The value Reddit uses for this setting.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SubmissionTypes::Any => "any",
+            SubmissionTypes::Link => "link",
+            SubmissionTypes::SelfPost => "self",
+            SubmissionTypes::Other(value) => value,
+        }
+    }
+}
+
+/// How strictly the spam filter treats a kind of submission.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpamFilter {
+    /// Only likely spam is filtered.
+    Low,
+    /// Anything suspicious is filtered.
+    High,
+    /// Everything is filtered until approved.
+    All,
+    /// A value roux does not know about.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl SpamFilter {
+    /// The value Reddit uses for this setting.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SpamFilter::Low => "low",
+            SpamFilter::High => "high",
+            SpamFilter::All => "all",
+            SpamFilter::Other(value) => value,
+        }
+    }
+}
+
+/// An order comments can be sorted in.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum CommentSort {
+    /// "Best", which balances score against how many have voted.
+    Confidence,
+    /// Highest score first.
+    Top,
+    /// Newest first.
+    New,
+    /// Most evenly split votes first.
+    Controversial,
+    /// Oldest first.
+    Old,
+    /// A random order.
+    Random,
+    /// Questions and answers, showing threads the submitter replied to first.
+    QuestionsAndAnswers,
+    /// Newest first, updating live.
+    Live,
+    /// A value roux does not know about.
+    Other(String),
+}
+
+impl CommentSort {
+    /// The value Reddit uses for this sort.
+    pub fn as_str(&self) -> &str {
+        match self {
+            CommentSort::Confidence => "confidence",
+            CommentSort::Top => "top",
+            CommentSort::New => "new",
+            CommentSort::Controversial => "controversial",
+            CommentSort::Old => "old",
+            CommentSort::Random => "random",
+            CommentSort::QuestionsAndAnswers => "qa",
+            CommentSort::Live => "live",
+            CommentSort::Other(value) => value,
+        }
+    }
+}
+
+impl From<String> for CommentSort {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "confidence" => CommentSort::Confidence,
+            "top" => CommentSort::Top,
+            "new" => CommentSort::New,
+            "controversial" => CommentSort::Controversial,
+            "old" => CommentSort::Old,
+            "random" => CommentSort::Random,
+            "qa" => CommentSort::QuestionsAndAnswers,
+            "live" => CommentSort::Live,
+            _ => CommentSort::Other(value),
+        }
+    }
+}
+
+/// Who can edit a subreddit's wiki.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WikiMode {
+    /// The wiki is disabled.
+    Disabled,
+    /// Only moderators and approved wiki contributors.
+    ModOnly,
+    /// Anyone who meets the age and karma requirements.
+    Anyone,
+    /// A value roux does not know about.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl WikiMode {
+    /// The value Reddit uses for this setting.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WikiMode::Disabled => "disabled",
+            WikiMode::ModOnly => "modonly",
+            WikiMode::Anyone => "anyone",
+            WikiMode::Other(value) => value,
+        }
+    }
+}
+
+/// The message sent to new members of a subreddit, from its [`SubredditSettings`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WelcomeMessage {
+    /// Whether the message is sent.
+    pub enabled: bool,
+    /// The message, in markdown.
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SubredditSettingsResponse {
+    pub data: SubredditSettings,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CommentSort, SpamFilter, SubmissionTypes, SubredditSettingsResponse, WelcomeMessage,
+        WikiMode,
+    };
+
+    #[test]
+    fn test_site_admin_form() {
+        let mut settings = serde_json::from_str::<SubredditSettingsResponse>(
+            r#"{
+                "kind": "subreddit_settings",
+                "data": {
+                    "subreddit_id": "t5_2s7lj",
+                    "subreddit_type": "public",
+                    "title": "Rust",
+                    "language": "en",
+                    "over_18": false,
+                    "wiki_edit_age": 0,
+                    "content_options": "self",
+                    "spam_links": "high",
+                    "spam_comments": "low",
+                    "spam_selfposts": "everything",
+                    "wikimode": "modonly",
+                    "crowd_control_mode": true,
+                    "crowd_control_level": 2,
+                    "suggested_comment_sort": "qa",
+                    "welcome_message_enabled": false,
+                    "welcome_message_text": null,
+                    "comment_score_hide_mins": null,
+                    "domain": null
+                }
+            }"#,
+        )
+        .unwrap()
+        .data;
+
+        assert_eq!(settings.subreddit_type.as_deref(), Some("public"));
+        assert_eq!(settings.submission_types, Some(SubmissionTypes::SelfPost));
+        assert_eq!(settings.spam_links, Some(SpamFilter::High));
+        assert_eq!(
+            settings.spam_selfposts,
+            Some(SpamFilter::Other("everything".to_owned()))
+        );
+        assert_eq!(settings.wikimode, Some(WikiMode::ModOnly));
+        assert_eq!(settings.crowd_control_level, Some(2));
+        assert_eq!(settings.allow_polls, None);
+        assert_eq!(
+            settings.suggested_comment_sort,
+            Some(CommentSort::QuestionsAndAnswers)
+        );
+
+        assert_eq!(
+            settings.welcome_message(),
+            WelcomeMessage {
+                enabled: false,
+                text: String::new()
+            }
+        );
+
+        settings.welcome_message_enabled = true;
+        settings.welcome_message_text = Some("Hello!".to_owned());
+
+        let form = format!("{:?}", settings.site_admin_form());
+        for field in [
+            r#"sr: "t5_2s7lj""#,
+            r#"type: "public""#,
+            r#"lang: "en""#,
+            r#"over_18: "false""#,
+            r#"wiki_edit_age: "0""#,
+            r#"welcome_message_enabled: "true""#,
+            r#"welcome_message_text: "Hello!""#,
+            r#"link_type: "self""#,
+            r#"spam_selfposts: "everything""#,
+            r#"wikimode: "modonly""#,
+            r#"crowd_control_mode: "true""#,
+            r#"crowd_control_level: "2""#,
+            r#"suggested_comment_sort: "qa""#,
+        ] {
+            assert!(form.contains(field), "{field} is not in {form}");
+        }
+        assert!(!form.contains("domain"));
+        assert!(!form.contains("allow_polls"));
+
+        settings.suggested_comment_sort = None;
+        let form = format!("{:?}", settings.site_admin_form());
+        assert!(form.contains(r#"suggested_comment_sort: """#), "{form}");
+    }
+}
@@ -0,0 +1,142 @@
+use serde::Deserialize;
+
+use super::{MenuLink, StructuredWidgets};
+
+/// A subreddit's redesign theme and widgets, from `/api/v1/structured_styles/{subreddit}`.
+#[derive(Debug, Deserialize)]
+pub struct StructuredStyles {
+    /// The theme's colors and images.
+    pub style: SubredditStyle,
+    /// The subreddit's widgets.
+    #[serde(default)]
+    pub content: StructuredStylesContent,
+}
+
+impl StructuredStyles {
+    /// The links in the subreddit's menu bar, in the order they are shown.
+    ///
+    /// Menu widgets which cannot be parsed are skipped.
+    pub fn menu_links(&self) -> Vec<MenuLink> {
+        self.content
+            .widgets
+            .menus()
+            .into_iter()
+            .flat_map(|menu| menu.data)
+            .collect()
+    }
+}
+
+/// The colors and images of a subreddit's theme.
+///
+/// Colors are hex strings such as `#0079D3`. Fields which the subreddit has not set are `None`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SubredditStyle {
+    /// The base color, used for the header and buttons.
+    pub primary_color: Option<String>,
+    /// The color used to highlight links and active elements.
+    pub highlight_color: Option<String>,
+    /// The background color of the page.
+    pub background_color: Option<String>,
+    /// The URL of the background image of the page.
+    pub background_image: Option<String>,
+    /// The background color of the banner.
+    pub banner_background_color: Option<String>,
+    /// The URL of the banner image.
+    pub banner_background_image: Option<String>,
+    /// How the banner image is positioned, e.g. `cover` or `tiled`.
+    pub banner_background_image_position: Option<String>,
+    /// The height of the banner, e.g. `small`, `medium` or `large`.
+    pub banner_height: Option<String>,
+    /// The URL of an image positioned over the banner.
+    pub banner_positioned_image: Option<String>,
+    /// The URL of the subreddit's icon.
+    pub community_icon: Option<String>,
+    /// The URL of the banner image on mobile.
+    pub mobile_banner_image: Option<String>,
+    /// The accent color on mobile.
+    pub mobile_key_color: Option<String>,
+    /// The background color of the menu bar.
+    pub menu_background_color: Option<String>,
+    /// The color of the selected menu link.
+    pub menu_link_color_active: Option<String>,
+    /// The color of menu links when hovered.
+    pub menu_link_color_hover: Option<String>,
+    /// The color of other menu links.
+    pub menu_link_color_inactive: Option<String>,
+    /// The background color of submenus.
+    pub submenu_background_color: Option<String>,
+    /// The background color of sidebar widgets.
+    pub sidebar_widget_background_color: Option<String>,
+    /// The color of sidebar widget headers.
+    pub sidebar_widget_header_color: Option<String>,
+    /// The color of post titles.
+    pub post_title_color: Option<String>,
+    /// The primary color from old reddit's theme.
+    pub legacy_primary_color: Option<String>,
+    /// The URL of the banner image from old reddit's theme.
+    pub legacy_banner_background_image: Option<String>,
+}
+
+/// The widgets of a subreddit, as part of its [`StructuredStyles`].
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct StructuredStylesContent {
+    /// The widgets and where they appear.
+    pub widgets: StructuredWidgets,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StructuredStylesResponse {
+    pub data: StructuredStyles,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MenuLink, StructuredStylesResponse};
+
+    #[test]
+    fn test_menu_links() {
+        let json = r##"{
+            "data": {
+                "style": { "primaryColor": "#0079D3", "bannerHeight": "small", "communityIcon": null },
+                "content": {
+                    "widgets": {
+                        "items": {
+                            "widget_b": {
+                                "id": "widget_b",
+                                "kind": "menu",
+                                "data": [
+                                    { "text": "Wiki", "url": "https://www.reddit.com/r/rust/wiki" },
+                                    { "text": "More", "children": [{ "text": "Discord", "url": "https://discord.gg/rust" }] }
+                                ]
+                            },
+                            "widget_a": { "kind": "textarea", "text": "Hello" },
+                            "widget_c": { "id": "widget_c", "kind": "menu", "data": [{ "text": "FAQ", "url": "https://example.com/faq" }] }
+                        },
+                        "layout": { "topbar": { "order": ["widget_c", "widget_b"] }, "sidebar": { "order": ["widget_a"] } }
+                    }
+                }
+            }
+        }"##;
+
+        let styles = serde_json::from_str::<StructuredStylesResponse>(json)
+            .unwrap()
+            .data;
+        assert_eq!(styles.style.primary_color.as_deref(), Some("#0079D3"));
+        assert_eq!(styles.style.community_icon, None);
+
+        let links = styles.menu_links();
+        let texts: Vec<_> = links.iter().map(|link| link.text.as_str()).collect();
+        assert_eq!(texts, ["FAQ", "Wiki", "More"]);
+        assert_eq!(links[2].url, None);
+        assert_eq!(
+            links[2].children,
+            [MenuLink {
+                text: "Discord".to_owned(),
+                url: Some("https://discord.gg/rust".to_owned()),
+                children: Vec::new(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use serde::Deserialize;
+
+use crate::api::ThingFullname;
+
+/// A subreddit's old Reddit stylesheet, from `/r/{subreddit}/about/stylesheet`.
+#[derive(Debug, Deserialize)]
+pub struct Stylesheet {
+    /// The CSS, as written by the moderators.
+    pub stylesheet: String,
+    /// The images uploaded for the stylesheet to use.
+    #[serde(default)]
+    pub images: Vec<StylesheetImage>,
+    /// The fullname of the subreddit.
+    pub subreddit_id: ThingFullname,
+}
+
+impl Stylesheet {
+    /// The uploaded image with the name, if there is one.
+    pub fn image(&self, name: &str) -> Option<&StylesheetImage> {
+        self.images.iter().find(|image| image.name == name)
+    }
+}
+
+/// An image uploaded for a subreddit's stylesheet.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StylesheetImage {
+    /// The name of the image, which the stylesheet refers to it by.
+    pub name: String,
+    /// The URL the image is served from.
+    pub url: String,
+    /// How the stylesheet refers to the image, e.g. `url(%%banner%%)`.
+    pub link: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stylesheet;
+    use crate::api::response::BasicThing;
+
+    #[test]
+    fn test_stylesheet() {
+        let stylesheet: BasicThing<Stylesheet> = serde_json::from_str(
+            r#"{
+                "kind": "stylesheet",
+                "data": {
+                    "images": [{
+                        "url": "https://b.thumbs.redditmedia.com/abc.png",
+                        "link": "url(%%banner%%)",
+                        "name": "banner"
+                    }],
+                    "subreddit_id": "t5_2s7lj",
+                    "stylesheet": ".side { background: url(%%banner%%); }"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let image = stylesheet.data.image("banner").unwrap();
+        assert_eq!(image.link, "url(%%banner%%)");
+        assert!(stylesheet.data.stylesheet.contains(&image.link));
+        assert!(stylesheet.data.image("missing").is_none());
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+/// The day's trending subreddits, from `/api/trending_subreddits`.
+#[derive(Debug, Deserialize)]
+pub struct TrendingSubreddits {
+    /// The names of the trending subreddits, without the `/r/` prefix.
+    pub subreddit_names: Vec<String>,
+    /// The number of comments on the thread discussing them.
+    #[serde(default)]
+    pub comment_count: u64,
+    /// The permalink to the thread discussing them, e.g. `/r/trendingsubreddits/comments/...`.
+    pub comment_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrendingSubreddits;
+
+    #[test]
+    fn test_deserialize() {
+        let trending: TrendingSubreddits = serde_json::from_str(
+            r#"{
+                "subreddit_names": ["rust", "learnrust"],
+                "comment_count": 42,
+                "comment_url": "/r/trendingsubreddits/comments/abc123/trending_subreddits/"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(trending.subreddit_names, ["rust", "learnrust"]);
+        assert_eq!(trending.comment_count, 42);
+    }
+}
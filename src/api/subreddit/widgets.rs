@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A subreddit's widgets, from `/r/{subreddit}/api/widgets`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct StructuredWidgets {
+    /// The raw widgets. Key is the widget's ID.
+    pub items: HashMap<String, Value>,
+    /// Where the widgets appear.
+    pub layout: WidgetLayout,
+}
+
+impl StructuredWidgets {
+    /// The widget with the ID.
+    pub fn widget(&self, id: &str) -> Option<Widget> {
+        self.items
+            .get(id)
+            .and_then(|widget| Widget::deserialize(widget).ok())
+    }
+
+    /// The widgets in the sidebar, in the order they are shown.
+    ///
+    /// Widgets which cannot be parsed are skipped.
+    pub fn sidebar(&self) -> Vec<Widget> {
+        self.layout
+            .sidebar
+            .order
+            .iter()
+            .filter_map(|id| self.widget(id))
+            .collect()
+    }
+
+    /// The menu widgets in the menu bar, in the order they are shown.
+    ///
+    /// Menu widgets which cannot be parsed are skipped.
+    pub fn menus(&self) -> Vec<MenuWidget> {
+        self.layout
+            .topbar
+            .order
+            .iter()
+            .filter_map(|id| self.items.get(id))
+            .filter(|widget| widget.get("kind").and_then(Value::as_str) == Some("menu"))
+            .filter_map(|widget| MenuWidget::deserialize(widget).ok())
+            .collect()
+    }
+}
+
+/// Where a subreddit's widgets appear.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct WidgetLayout {
+    /// The widgets in the menu bar.
+    pub topbar: WidgetOrder,
+    /// The widgets in the sidebar.
+    pub sidebar: WidgetOrder,
+}
+
+/// An ordered list of widgets.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct WidgetOrder {
+    /// The IDs of the widgets, in the order they are shown.
+    pub order: Vec<String>,
+}
+
+/// A widget in a subreddit's sidebar or menu bar.
+///
+/// Widgets are created with [`Subreddit::add_widget`](crate::client::Subreddit::add_widget), in which
+/// case their `id` is ignored and can be left empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Widget {
+    /// A block of markdown.
+    #[serde(rename = "textarea")]
+    TextArea(TextAreaWidget),
+    /// A list of buttons linking elsewhere.
+    #[serde(rename = "button")]
+    ButtonList(ButtonListWidget),
+    /// Upcoming events from a Google Calendar.
+    #[serde(rename = "calendar")]
+    Calendar(CalendarWidget),
+    /// Links in the menu bar.
+    #[serde(rename = "menu")]
+    Menu(MenuWidget),
+    /// A kind of widget roux does not type, such as the rules or moderators widgets.
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl Widget {
+    /// The ID of the widget.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Widget::TextArea(widget) => Some(&widget.id),
+            Widget::ButtonList(widget) => Some(&widget.id),
+            Widget::Calendar(widget) => Some(&widget.id),
+            Widget::Menu(widget) => Some(&widget.id),
+            Widget::Other(widget) => widget.get("id").and_then(Value::as_str),
+        }
+    }
+}
+
+/// The colors of a widget. Colors are hex strings such as `#0079D3`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WidgetStyles {
+    /// The background color.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    /// The color of the header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_color: Option<String>,
+}
+
+/// A widget showing a block of markdown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextAreaWidget {
+    /// The ID of the widget.
+    #[serde(default, skip_serializing)]
+    pub id: String,
+    /// The title of the widget.
+    pub short_name: String,
+    /// The markdown shown.
+    pub text: String,
+    /// The colors of the widget.
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// A widget showing a list of buttons.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ButtonListWidget {
+    /// The ID of the widget.
+    #[serde(default, skip_serializing)]
+    pub id: String,
+    /// The title of the widget.
+    pub short_name: String,
+    /// The markdown shown above the buttons.
+    #[serde(default)]
+    pub description: String,
+    /// The buttons.
+    pub buttons: Vec<WidgetButton>,
+    /// The colors of the widget.
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// A button in a [`ButtonListWidget`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetButton {
+    /// The kind of button, `text` or `image`.
+    pub kind: String,
+    /// The text of the button.
+    pub text: String,
+    /// Where the button links to.
+    pub url: String,
+    /// The color of the button's border.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The color of the button's text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+    /// The color the button is filled with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<String>,
+}
+
+impl WidgetButton {
+    /// Creates a text button linking to the URL.
+    pub fn link(text: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            kind: "text".to_owned(),
+            text: text.into(),
+            url: url.into(),
+            color: None,
+            text_color: None,
+            fill_color: None,
+        }
+    }
+}
+
+/// A widget showing upcoming events from a public Google Calendar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarWidget {
+    /// The ID of the widget.
+    #[serde(default, skip_serializing)]
+    pub id: String,
+    /// The title of the widget.
+    pub short_name: String,
+    /// The ID of the Google Calendar, usually an email address.
+    pub google_calendar_id: String,
+    /// Whether the events are kept in sync with the calendar.
+    #[serde(default)]
+    pub requires_sync: bool,
+    /// What is shown for each event.
+    pub configuration: CalendarConfiguration,
+    /// The colors of the widget.
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// What a [`CalendarWidget`] shows for each event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarConfiguration {
+    /// The number of events shown, from 1 to 50.
+    pub num_events: u8,
+    /// Whether the date is shown.
+    pub show_date: bool,
+    /// Whether the description is shown.
+    pub show_description: bool,
+    /// Whether the location is shown.
+    pub show_location: bool,
+    /// Whether the time is shown.
+    pub show_time: bool,
+    /// Whether the title is shown.
+    pub show_title: bool,
+}
+
+/// A widget of links in a subreddit's menu bar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuWidget {
+    /// The ID of the widget.
+    #[serde(default, skip_serializing)]
+    pub id: String,
+    /// Whether a link to the subreddit's wiki is shown before the links.
+    #[serde(default)]
+    pub show_wiki: bool,
+    /// The links.
+    pub data: Vec<MenuLink>,
+}
+
+/// A link in a subreddit's menu bar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MenuLink {
+    /// The text of the link.
+    pub text: String,
+    /// Where the link goes, or `None` if it only opens a submenu.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The links in this link's submenu.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<MenuLink>,
+}
+
+impl MenuLink {
+    /// Creates a link to the URL.
+    pub fn new(text: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            url: Some(url.into()),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a submenu of links.
+    pub fn submenu(text: impl Into<String>, children: Vec<MenuLink>) -> Self {
+        Self {
+            text: text.into(),
+            url: None,
+            children,
+        }
+    }
+}
+
+/// The body sent to create or update a menu widget.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MenuWidgetRequest<'a> {
+    pub kind: &'static str,
+    pub show_wiki: bool,
+    pub data: &'a [MenuLink],
+}
+
+impl<'a> MenuWidgetRequest<'a> {
+    pub fn new(links: &'a [MenuLink], show_wiki: bool) -> Self {
+        Self {
+            kind: "menu",
+            show_wiki,
+            data: links,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MenuLink, MenuWidgetRequest, StructuredWidgets, Widget, WidgetButton};
+
+    #[test]
+    fn test_menu_widget_request() {
+        let links = [
+            MenuLink::new("Wiki", "https://www.reddit.com/r/rust/wiki"),
+            MenuLink::submenu(
+                "More",
+                vec![MenuLink::new("Discord", "https://discord.gg/rust")],
+            ),
+        ];
+
+        let body = serde_json::to_string(&MenuWidgetRequest::new(&links, false)).unwrap();
+        assert_eq!(
+            body,
+            r#"{"kind":"menu","showWiki":false,"data":[{"text":"Wiki","url":"https://www.reddit.com/r/rust/wiki"},{"text":"More","children":[{"text":"Discord","url":"https://discord.gg/rust"}]}]}"#
+        );
+    }
+
+    #[test]
+    fn test_sidebar_widgets() {
+        let widgets: StructuredWidgets = serde_json::from_str(
+            r##"{
+                "items": {
+                    "widget_1": {
+                        "kind": "button", "id": "widget_1", "shortName": "Links",
+                        "description": "",
+                        "buttons": [{"kind": "text", "text": "Discord", "url": "https://discord.gg/rust", "color": "#000000"}],
+                        "styles": {"backgroundColor": "", "headerColor": ""}
+                    },
+                    "widget_2": {"kind": "id-card", "id": "widget_2", "shortName": "About"},
+                    "widget_3": {
+                        "kind": "textarea", "id": "widget_3", "shortName": "Hidden",
+                        "text": "Not in the layout", "textHtml": ""
+                    }
+                },
+                "layout": {"sidebar": {"order": ["widget_2", "widget_1"]}, "topbar": {"order": []}}
+            }"##,
+        )
+        .unwrap();
+
+        let sidebar = widgets.sidebar();
+        assert_eq!(sidebar.len(), 2);
+        assert!(matches!(&sidebar[0], Widget::Other(_)));
+        assert_eq!(sidebar[0].id(), Some("widget_2"));
+        let Widget::ButtonList(buttons) = &sidebar[1] else {
+            panic!("expected a button widget, got {:?}", sidebar[1]);
+        };
+        assert_eq!(buttons.id, "widget_1");
+        assert_eq!(buttons.buttons[0].text, "Discord");
+        assert_eq!(buttons.buttons[0].color.as_deref(), Some("#000000"));
+
+        let mut widget = sidebar[1].clone();
+        if let Widget::ButtonList(buttons) = &mut widget {
+            buttons.buttons = vec![WidgetButton::link(
+                "Wiki",
+                "https://www.reddit.com/r/rust/wiki",
+            )];
+        }
+        assert_eq!(
+            serde_json::to_string(&widget).unwrap(),
+            r#"{"kind":"button","shortName":"Links","description":"","buttons":[{"kind":"text","text":"Wiki","url":"https://www.reddit.com/r/rust/wiki"}],"styles":{"backgroundColor":"","headerColor":""}}"#
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+use crate::api::response::BasicThing;
+
+/// Who can edit a wiki page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(from = "u8")]
+pub enum WikiPermLevel {
+    /// Whoever the subreddit's wiki settings allow.
+    Inherit,
+    /// Only approved wiki contributors of this page.
+    ApprovedEditors,
+    /// Only moderators.
+    ModsOnly,
+}
+
+impl WikiPermLevel {
+    /// The `permlevel` Reddit uses for this level.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WikiPermLevel::Inherit => "0",
+            WikiPermLevel::ApprovedEditors => "1",
+            WikiPermLevel::ModsOnly => "2",
+        }
+    }
+}
+
+impl From<u8> for WikiPermLevel {
+    fn from(level: u8) -> Self {
+        match level {
+            1 => WikiPermLevel::ApprovedEditors,
+            2 => WikiPermLevel::ModsOnly,
+            _ => WikiPermLevel::Inherit,
+        }
+    }
+}
+
+/// The settings of a wiki page, from `/r/{subreddit}/wiki/settings/{page}`.
+#[derive(Debug, Deserialize)]
+pub struct WikiPageSettings {
+    /// Who can edit the page.
+    pub permlevel: WikiPermLevel,
+    /// Whether the page is shown in the list of the subreddit's wiki pages.
+    pub listed: bool,
+    /// The users approved to edit the page.
+    #[serde(default)]
+    pub editors: Vec<BasicThing<WikiEditor>>,
+}
+
+impl WikiPageSettings {
+    /// The names of the users approved to edit the page.
+    pub fn editor_names(&self) -> impl Iterator<Item = &str> {
+        self.editors.iter().map(|editor| editor.data.name.as_str())
+    }
+}
+
+/// A user approved to edit a wiki page.
+#[derive(Debug, Deserialize)]
+pub struct WikiEditor {
+    /// The name of the user (without the `/u/`).
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WikiPageSettings, WikiPermLevel};
+    use crate::api::response::BasicThing;
+
+    #[test]
+    fn test_wiki_page_settings() {
+        let settings: BasicThing<WikiPageSettings> = serde_json::from_str(
+            r#"{
+                "kind": "wikipagesettings",
+                "data": {
+                    "permlevel": 1,
+                    "listed": false,
+                    "editors": [{ "kind": "t2", "data": { "name": "someone", "id": "abc" } }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.data.permlevel, WikiPermLevel::ApprovedEditors);
+        assert!(!settings.data.listed);
+        assert_eq!(
+            settings.data.editor_names().collect::<Vec<_>>(),
+            ["someone"]
+        );
+    }
+}
@@ -1,6 +1,9 @@
 //! # Thing Fullname
 //! A thing fullname represents a complete identifier to a particular object, encoding both its kind and a base-36 identifier.
 
+use std::cmp::Ordering;
+use std::str::FromStr;
+
 use serde::{de::Error, Deserialize, Serialize};
 
 /// A wrapper for a thing's fullname, which is a kind and base-36 identifier. The possible kinds include:
@@ -11,7 +14,11 @@ use serde::{de::Error, Deserialize, Serialize};
 /// - t5_ - Subreddit
 /// - t6_ - Award
 /// - t8_ - PromoCampaign
+///
+/// Fullnames are ordered by kind, then by id as a number, so sorting things of one kind orders
+/// them from oldest to newest.
 #[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(transparent)]
 pub struct ThingFullname(String);
 
 const SPLIT_INDEX: usize = "t1".len();
@@ -37,6 +44,26 @@ impl TryFrom<String> for ThingFullname {
     }
 }
 
+impl FromStr for ThingFullname {
+    type Err = InvalidThingFullname;
+
+    fn from_str(thing_id: &str) -> Result<Self, Self::Err> {
+        Self::try_from(thing_id).map_err(|()| InvalidThingFullname(thing_id.to_owned()))
+    }
+}
+
+/// The error when parsing a string which is not a valid [`ThingFullname`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidThingFullname(pub String);
+
+impl std::fmt::Display for InvalidThingFullname {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid thing id '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidThingFullname {}
+
 impl ThingFullname {
     fn validate(thing_id: &str) -> Result<(), ()> {
         let (kind, _) = thing_id.split_once('_').ok_or(())?;
@@ -71,6 +98,28 @@ impl ThingFullname {
         self.split().1
     }
 
+    /// Decodes the base-36 id as a number, e.g. for storing compactly.
+    ///
+    /// Returns `None` if the id is not base-36 as Reddit writes it (lowercase, without leading
+    /// zeros) or is too large for a `u64`, so [`ThingFullname::from_u64`] always gives back the
+    /// same fullname.
+    pub fn id_u64(&self) -> Option<u64> {
+        let id = self.id();
+        let is_digit = |b: u8| b.is_ascii_digit() || b.is_ascii_lowercase();
+        if id.is_empty() || !id.bytes().all(is_digit) || (id.len() > 1 && id.starts_with('0')) {
+            return None;
+        }
+
+        u64::from_str_radix(id, 36).ok()
+    }
+
+    /// Creates a fullname from a kind, e.g. `t3`, and a numeric id, such as from [`ThingFullname::id_u64`].
+    ///
+    /// Returns `None` if the kind is not valid.
+    pub fn from_u64(kind: &str, id: u64) -> Option<Self> {
+        Self::try_from(format!("{kind}_{}", to_base36(id))).ok()
+    }
+
     /// Returns the full thing id
     #[inline(always)]
     pub fn full(&self) -> &str {
@@ -115,6 +164,21 @@ impl ThingFullname {
     }
 }
 
+/// Encodes the number in lowercase base-36, as Reddit formats ids.
+fn to_base36(mut value: u64) -> String {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    let mut digits = Vec::new();
+    loop {
+        digits.push(DIGITS[(value % 36) as usize]);
+        value /= 36;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().map(|&digit| digit as char).collect()
+}
+
 impl<'de> Deserialize<'de> for ThingFullname {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -132,6 +196,28 @@ impl std::fmt::Display for ThingFullname {
     }
 }
 
+impl AsRef<str> for ThingFullname {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Ord for ThingFullname {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Ids have no leading zeros, so a shorter id is a smaller number.
+        self.kind()
+            .cmp(other.kind())
+            .then_with(|| self.id().len().cmp(&other.id().len()))
+            .then_with(|| self.id().cmp(other.id()))
+    }
+}
+
+impl PartialOrd for ThingFullname {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +230,84 @@ mod tests {
         assert_eq!(thing.id(), "abcdef");
     }
 
+    #[test]
+    pub fn test_id_u64() {
+        let thing = ThingFullname::try_from("t3_1f155ot").unwrap();
+        assert_eq!(thing.id_u64(), Some(3_085_695_245));
+        assert_eq!(ThingFullname::from_u64("t3", 3_085_695_245), Some(thing));
+
+        assert_eq!(ThingFullname::from_u64("t1", 0).unwrap().full(), "t1_0");
+        assert_eq!(ThingFullname::from_u64("x1", 0), None);
+
+        let invalid = ThingFullname::try_from("t1_+abc").unwrap();
+        assert_eq!(invalid.id_u64(), None);
+        let overflow = ThingFullname::try_from("t1_zzzzzzzzzzzzzz").unwrap();
+        assert_eq!(overflow.id_u64(), None);
+        let uppercase = ThingFullname::try_from("t3_ABC").unwrap();
+        assert_eq!(uppercase.id_u64(), None);
+        let leading_zero = ThingFullname::try_from("t3_0abc").unwrap();
+        assert_eq!(leading_zero.id_u64(), None);
+        let zero = ThingFullname::try_from("t3_0").unwrap();
+        assert_eq!(zero.id_u64(), Some(0));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_u64_round_trip(id: u64, kind in 1u8..=8) {
+            let kind = format!("t{kind}");
+            let thing = ThingFullname::from_u64(&kind, id).unwrap();
+
+            proptest::prop_assert_eq!(thing.kind(), kind.as_str());
+            proptest::prop_assert_eq!(thing.id_u64(), Some(id));
+        }
+
+        #[test]
+        fn prop_id_round_trip(id in "[1-9a-z][0-9a-z]{0,11}") {
+            let thing = ThingFullname::try_from(format!("t3_{id}")).unwrap();
+            let decoded = thing.id_u64().unwrap();
+
+            proptest::prop_assert_eq!(ThingFullname::from_u64("t3", decoded), Some(thing));
+        }
+
+        #[test]
+        fn prop_id_decodes_losslessly(id in "[0-9A-Za-z]{1,14}") {
+            let thing = ThingFullname::try_from(format!("t3_{id}")).unwrap();
+
+            if let Some(decoded) = thing.id_u64() {
+                proptest::prop_assert_eq!(ThingFullname::from_u64("t3", decoded), Some(thing));
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_parse_and_display() {
+        let thing: ThingFullname = "t3_1f155ot".parse().unwrap();
+        assert_eq!(thing.to_string(), "t3_1f155ot");
+        assert_eq!(thing.as_ref(), "t3_1f155ot");
+        assert_eq!(
+            "1f155ot".parse::<ThingFullname>(),
+            Err(InvalidThingFullname(String::from("1f155ot")))
+        );
+
+        assert_eq!(serde_json::to_string(&thing).unwrap(), r#""t3_1f155ot""#);
+        assert_eq!(
+            serde_json::from_str::<ThingFullname>(r#""t3_1f155ot""#).unwrap(),
+            thing
+        );
+    }
+
+    #[test]
+    pub fn test_ordering() {
+        let mut things: Vec<ThingFullname> = ["t3_10", "t3_z", "t1_zz", "t3_a"]
+            .into_iter()
+            .map(|id| id.parse().unwrap())
+            .collect();
+        things.sort();
+
+        let sorted: Vec<&str> = things.iter().map(ThingFullname::full).collect();
+        assert_eq!(sorted, ["t1_zz", "t3_a", "t3_z", "t3_10"]);
+    }
+
     #[test]
     pub fn test_url_parse() {
         assert_eq!(
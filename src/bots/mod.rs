@@ -0,0 +1,147 @@
+//! # Bots
+//! Small frameworks for the most common kinds of bot, built on the [`streams`](crate::streams).
+//!
+//! Requires the `bots` feature, and is not available with `blocking`.
+//!
+//! # Replying to mentions
+//! [`MentionBot`] watches the inbox for comments mentioning the bot's username, asks a handler
+//! what to reply, replies, and marks the mention as read.
+//!
+//! ```no_run
+//! use roux::bots::MentionBot;
+//! use roux::client::OAuthClient;
+//! use roux::Config;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), roux::util::RouxError> {
+//! let client = OAuthClient::new(Config::from_env().unwrap())?.login().await?;
+//!
+//! MentionBot::new(client, |mention| {
+//!     let author = mention.author()?;
+//!     Some(format!("Hello, /u/{author}!"))
+//! })
+//! .run()
+//! .await;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Moderating a subreddit
+//! [`ModerationBot`] checks new submissions, comments and modqueue items against
+//! [`ModerationRule`]s, and removes, reports, flairs or replies to them as the rules decide.
+
+use futures_util::stream::{self, Stream, StreamExt};
+
+use crate::client::{AuthedClient, CommentBatchResult, CommentSpacing};
+use crate::models::Message;
+use crate::streams::StreamOptions;
+use crate::util::RouxError;
+
+mod moderation;
+pub use moderation::{
+    AppliedAction, ModeratedThing, ModerationAction, ModerationBot, ModerationRule,
+    ModerationSource,
+};
+
+/// Replies to comments which mention the logged in user, see the [module docs](self).
+pub struct MentionBot<F> {
+    client: AuthedClient,
+    handler: F,
+    stream_options: StreamOptions,
+    spacing: CommentSpacing,
+}
+
+/// A mention that [`MentionBot`] has handled.
+pub struct HandledMention {
+    /// The comment mentioning the bot.
+    pub mention: Message<AuthedClient>,
+    /// The reply, if the handler gave one.
+    ///
+    /// If replying failed, the mention is left unread.
+    pub reply: Option<CommentBatchResult<AuthedClient>>,
+}
+
+impl<F> MentionBot<F>
+where
+    F: FnMut(&Message<AuthedClient>) -> Option<String>,
+{
+    /// Creates a bot which replies to each mention with what `handler` returns, or not at all
+    /// if it returns `None`.
+    pub fn new(client: AuthedClient, handler: F) -> Self {
+        Self {
+            client,
+            handler,
+            stream_options: StreamOptions::new(),
+            spacing: CommentSpacing::default(),
+        }
+    }
+
+    /// Sets how often the inbox is polled.
+    pub fn stream_options(mut self, options: StreamOptions) -> Self {
+        self.stream_options = options;
+        self
+    }
+
+    /// Sets how ratelimited replies are retried.
+    ///
+    /// Only one reply is posted at a time, so [`CommentSpacing::interval`] is not used.
+    pub fn comment_spacing(mut self, spacing: CommentSpacing) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Handles mentions as they arrive, yielding each one once it has been handled.
+    ///
+    /// Other unread messages are left as they are. The stream never ends; errors are yielded as
+    /// they occur and polling continues after backing off.
+    pub fn stream(self) -> impl Stream<Item = Result<HandledMention, RouxError>> {
+        let messages = Box::pin(
+            self.client
+                .stream_unread(self.stream_options.clone(), false)
+                .filter(|message| {
+                    let is_mention = match message {
+                        Ok(message) => message.is_mention(),
+                        Err(_) => true,
+                    };
+                    async move { is_mention }
+                }),
+        );
+
+        stream::unfold(
+            (messages, self.handler, self.client, self.spacing),
+            |(mut messages, mut handler, client, spacing)| async move {
+                let mention = match messages.next().await? {
+                    Ok(mention) => mention,
+                    Err(error) => return Some((Err(error), (messages, handler, client, spacing))),
+                };
+
+                let reply = match handler(&mention) {
+                    Some(text) => client
+                        .comment_many([(mention.name(), text.as_str())], &spacing)
+                        .await
+                        .pop(),
+                    None => None,
+                };
+
+                let replied = reply.as_ref().is_none_or(|reply| reply.result.is_ok());
+                let result = if replied {
+                    mention.mark_read().await
+                } else {
+                    Ok(())
+                };
+
+                let handled = result.map(|()| HandledMention { mention, reply });
+                Some((handled, (messages, handler, client, spacing)))
+            },
+        )
+    }
+
+    /// Handles mentions forever, see [`MentionBot::stream`].
+    ///
+    /// Errors are retried by polling again; use [`MentionBot::stream`] to see them.
+    pub async fn run(self) {
+        let stream = self.stream();
+        futures_util::pin_mut!(stream);
+        while stream.next().await.is_some() {}
+    }
+}
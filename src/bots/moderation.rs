@@ -0,0 +1,297 @@
+use futures_util::stream::{self, LocalBoxStream, Stream, StreamExt};
+
+use crate::client::{AuthedClient, SelectFlairData, SelectFlairTarget, Subreddit};
+use crate::models::modqueue::QueueThing;
+use crate::streams::StreamOptions;
+use crate::util::RouxError;
+
+/// Where a [`ModerationBot`] found an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModerationSource {
+    /// A new submission.
+    Submissions,
+    /// A new comment.
+    Comments,
+    /// An item added to the modqueue, e.g. because it was reported or filtered.
+    Modqueue,
+}
+
+/// Something a [`ModerationRule`] wants done to an item.
+#[derive(Debug, Clone)]
+pub enum ModerationAction {
+    /// Removes the item, optionally as spam.
+    Remove {
+        /// Whether to remove it as spam.
+        spam: bool,
+    },
+    /// Reports the item with a custom reason.
+    Report(String),
+    /// Sets the flair of a submission, or the user flair of a comment's author.
+    Flair(SelectFlairData),
+    /// Replies to the item.
+    Comment(String),
+}
+
+/// A rule checked against every item a [`ModerationBot`] sees.
+///
+/// Closures taking the item and its source implement this trait.
+pub trait ModerationRule {
+    /// The name of the rule, used in [`AppliedAction::rule`].
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Decides what to do with an item, returning no actions to leave it alone.
+    fn check(
+        &mut self,
+        item: &QueueThing<AuthedClient>,
+        source: ModerationSource,
+    ) -> Vec<ModerationAction>;
+}
+
+impl<F> ModerationRule for F
+where
+    F: FnMut(&QueueThing<AuthedClient>, ModerationSource) -> Vec<ModerationAction>,
+{
+    fn check(
+        &mut self,
+        item: &QueueThing<AuthedClient>,
+        source: ModerationSource,
+    ) -> Vec<ModerationAction> {
+        self(item, source)
+    }
+}
+
+/// An action returned by a rule, and whether it was applied.
+#[derive(Debug)]
+pub struct AppliedAction {
+    /// The [name](ModerationRule::name) of the rule which returned the action.
+    pub rule: String,
+    /// The action.
+    pub action: ModerationAction,
+    /// The result of applying the action, or `None` in dry-run mode.
+    pub result: Option<Result<(), RouxError>>,
+}
+
+/// An item which a [`ModerationBot`] has checked against its rules.
+pub struct ModeratedThing {
+    /// Where the item was found.
+    pub source: ModerationSource,
+    /// The submission or comment.
+    pub item: QueueThing<AuthedClient>,
+    /// The actions returned by the rules, in the order the rules were added.
+    pub actions: Vec<AppliedAction>,
+}
+
+/// Checks new submissions, comments and modqueue items in a subreddit against a set of rules,
+/// applying the actions they return.
+///
+/// Requires the logged in user to moderate the subreddit. Each source is polled separately, so an
+/// item may be checked twice, e.g. when a new submission is later reported.
+///
+/// ```no_run
+/// use roux::bots::{ModerationAction, ModerationBot};
+/// use roux::models::modqueue::QueueThing;
+/// # use roux::client::{OAuthClient, RedditClient};
+/// # use roux::Config;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), roux::util::RouxError> {
+/// # let client = OAuthClient::new(Config::from_env().unwrap())?.login().await?;
+///
+/// ModerationBot::new(client.subreddit("rust"))
+///     .rule(|item: &QueueThing<_>, _| match item {
+///         QueueThing::Submission(submission) if submission.title().contains("crypto") => {
+///             vec![ModerationAction::Remove { spam: false }]
+///         }
+///         _ => Vec::new(),
+///     })
+///     .dry_run(true)
+///     .run()
+///     .await;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ModerationBot {
+    subreddit: Subreddit<AuthedClient>,
+    rules: Vec<Box<dyn ModerationRule>>,
+    stream_options: StreamOptions,
+    submissions: bool,
+    comments: bool,
+    modqueue: bool,
+    dry_run: bool,
+}
+
+impl ModerationBot {
+    /// Creates a bot with no rules, watching all sources of the subreddit.
+    pub fn new(subreddit: Subreddit<AuthedClient>) -> Self {
+        Self {
+            subreddit,
+            rules: Vec::new(),
+            stream_options: StreamOptions::new(),
+            submissions: true,
+            comments: true,
+            modqueue: true,
+            dry_run: false,
+        }
+    }
+
+    /// Adds a rule, checked after the rules already added.
+    pub fn rule(mut self, rule: impl ModerationRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Sets how often each source is polled.
+    pub fn stream_options(mut self, options: StreamOptions) -> Self {
+        self.stream_options = options;
+        self
+    }
+
+    /// Sets whether new submissions are checked.
+    pub fn submissions(mut self, enabled: bool) -> Self {
+        self.submissions = enabled;
+        self
+    }
+
+    /// Sets whether new comments are checked.
+    pub fn comments(mut self, enabled: bool) -> Self {
+        self.comments = enabled;
+        self
+    }
+
+    /// Sets whether items added to the modqueue are checked.
+    pub fn modqueue(mut self, enabled: bool) -> Self {
+        self.modqueue = enabled;
+        self
+    }
+
+    /// Sets whether actions are only returned rather than applied, to try out rules safely.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Checks items as they arrive, yielding each one once its actions have been applied.
+    ///
+    /// A failed action does not stop the rest from being applied, see [`AppliedAction::result`].
+    /// The stream never ends unless every source is disabled; errors are yielded as they occur
+    /// and polling continues after backing off.
+    pub fn stream(self) -> impl Stream<Item = Result<ModeratedThing, RouxError>> {
+        let items = stream::select_all(self.sources());
+
+        stream::unfold(
+            (items, self.rules, self.subreddit, self.dry_run),
+            |(mut items, mut rules, subreddit, dry_run)| async move {
+                let (source, item) = match items.next().await? {
+                    (source, Ok(item)) => (source, item),
+                    (_, Err(error)) => {
+                        return Some((Err(error), (items, rules, subreddit, dry_run)))
+                    }
+                };
+
+                let mut actions = Vec::new();
+                for rule in &mut rules {
+                    for action in rule.check(&item, source) {
+                        let result = if dry_run {
+                            None
+                        } else {
+                            Some(apply(&subreddit, &item, &action).await)
+                        };
+                        actions.push(AppliedAction {
+                            rule: rule.name().to_owned(),
+                            action,
+                            result,
+                        });
+                    }
+                }
+
+                let moderated = ModeratedThing {
+                    source,
+                    item,
+                    actions,
+                };
+                Some((Ok(moderated), (items, rules, subreddit, dry_run)))
+            },
+        )
+    }
+
+    /// Checks items forever, see [`ModerationBot::stream`].
+    ///
+    /// Errors are retried by polling again; use [`ModerationBot::stream`] to see them.
+    pub async fn run(self) {
+        let stream = self.stream();
+        futures_util::pin_mut!(stream);
+        while stream.next().await.is_some() {}
+    }
+
+    /// The streams of each enabled source.
+    fn sources(&self) -> Vec<SourceStream> {
+        let options = &self.stream_options;
+        let mut sources = Vec::new();
+
+        if self.submissions {
+            let submissions = self.subreddit.stream_new(options.clone()).map(|item| {
+                let item = item.map(QueueThing::Submission);
+                (ModerationSource::Submissions, item)
+            });
+            sources.push(submissions.boxed_local());
+        }
+        if self.comments {
+            let comments = self.subreddit.stream_comments(options.clone()).map(|item| {
+                let item = item.map(QueueThing::Comment);
+                (ModerationSource::Comments, item)
+            });
+            sources.push(comments.boxed_local());
+        }
+        if self.modqueue {
+            let modqueue = self
+                .subreddit
+                .stream_modqueue(options.clone())
+                .map(|item| (ModerationSource::Modqueue, item));
+            sources.push(modqueue.boxed_local());
+        }
+
+        sources
+    }
+}
+
+/// The items from one source, tagged with the source.
+type SourceStream = LocalBoxStream<
+    'static,
+    (
+        ModerationSource,
+        Result<QueueThing<AuthedClient>, RouxError>,
+    ),
+>;
+
+/// Applies an action to an item.
+async fn apply(
+    subreddit: &Subreddit<AuthedClient>,
+    item: &QueueThing<AuthedClient>,
+    action: &ModerationAction,
+) -> Result<(), RouxError> {
+    let client = &subreddit.client;
+
+    match action {
+        ModerationAction::Remove { spam } => client.remove(item.name(), *spam).await,
+        ModerationAction::Report(reason) => match item {
+            QueueThing::Submission(submission) => submission.report(reason).await,
+            QueueThing::Comment(comment) => comment.report(reason).await,
+        },
+        ModerationAction::Flair(flair) => {
+            let target = match item {
+                QueueThing::Submission(submission) => {
+                    SelectFlairTarget::Link(submission.name().clone())
+                }
+                QueueThing::Comment(comment) => {
+                    SelectFlairTarget::User(comment.author().to_owned())
+                }
+            };
+            client.select_flair(subreddit.name(), target, flair).await
+        }
+        ModerationAction::Comment(text) => {
+            client.comment(text, item.name()).await?;
+            Ok(())
+        }
+    }
+}
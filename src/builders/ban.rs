@@ -0,0 +1,90 @@
+use crate::builders::form::FormBuilder;
+
+/// The longest ban a subreddit can give, in days. Longer bans must be permanent.
+pub const MAX_BAN_DURATION_DAYS: u32 = 999;
+
+/// Options for banning a user from a subreddit.
+///
+/// By default, bans are permanent and have no reason, note or message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BanOptions {
+    /// How many days the ban lasts, or `None` for a permanent ban.
+    pub duration: Option<u32>,
+    /// The reason for the ban, shown to moderators. This is usually one of the subreddit's rules.
+    pub ban_reason: Option<String>,
+    /// A note about the ban, shown to moderators.
+    pub mod_note: Option<String>,
+    /// A message sent to the user with the ban notification.
+    pub ban_message: Option<String>,
+}
+
+impl BanOptions {
+    /// Creates options for a permanent ban.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many days the ban lasts, up to [`MAX_BAN_DURATION_DAYS`].
+    pub fn duration(mut self, days: u32) -> Self {
+        self.duration = Some(days.clamp(1, MAX_BAN_DURATION_DAYS));
+        self
+    }
+
+    /// Makes the ban permanent.
+    pub fn permanent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    /// Sets the reason for the ban, shown to moderators.
+    pub fn ban_reason(mut self, reason: impl Into<String>) -> Self {
+        self.ban_reason = Some(reason.into());
+        self
+    }
+
+    /// Sets the note about the ban, shown to moderators.
+    pub fn mod_note(mut self, note: impl Into<String>) -> Self {
+        self.mod_note = Some(note.into());
+        self
+    }
+
+    /// Sets the message sent to the user.
+    pub fn ban_message(mut self, message: impl Into<String>) -> Self {
+        self.ban_message = Some(message.into());
+        self
+    }
+
+    /// Adds these options to a `friend` request.
+    pub(crate) fn add_to<'a>(&'a self, form: FormBuilder<'a>) -> FormBuilder<'a> {
+        form.with_opt("duration", self.duration.map(|days| days.to_string()))
+            .with_opt("ban_reason", self.ban_reason.as_deref())
+            .with_opt("note", self.mod_note.as_deref())
+            .with_opt("ban_message", self.ban_message.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BanOptions, MAX_BAN_DURATION_DAYS};
+    use crate::builders::form::FormBuilder;
+
+    #[test]
+    fn test_ban_options_form() {
+        let options = BanOptions::new()
+            .duration(7)
+            .ban_reason("Spam")
+            .ban_message("Please read the rules.");
+
+        let form = options.add_to(FormBuilder::new());
+        assert_eq!(
+            serde_urlencoded::to_string(&form).unwrap(),
+            "api_type=json&duration=7&ban_reason=Spam&ban_message=Please+read+the+rules."
+        );
+
+        assert_eq!(
+            BanOptions::new().duration(5000).duration,
+            Some(MAX_BAN_DURATION_DAYS)
+        );
+        assert_eq!(BanOptions::new().duration(3).permanent().duration, None);
+    }
+}
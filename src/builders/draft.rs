@@ -0,0 +1,425 @@
+//! # Drafts
+//! Checks posts and comments locally before they are sent, and shows what would be sent.
+//!
+//! This lets bots check their content templates (e.g. in CI) without posting anything:
+//!
+//! ```
+//! use roux::builders::draft::DryRun;
+//! use roux::builders::submission::SubmissionSubmitBuilder;
+//! use roux::api::ThingFullname;
+//!
+//! let post = SubmissionSubmitBuilder::text("Weekly thread", "Ask your questions here!");
+//! let dry_run = post.dry_run("rust", None);
+//! assert!(dry_run.is_valid(), "{:?}", dry_run.problems);
+//!
+//! let parent = ThingFullname::from_submission_id("abc123");
+//! let dry_run = DryRun::comment("[docs](https://docs.rs/roux", &parent);
+//! assert!(!dry_run.is_valid());
+//! ```
+
+use serde::Serialize;
+
+use crate::api::subreddit::PostRequirements;
+use crate::api::ThingFullname;
+use crate::builders::form::FormBuilder;
+use crate::builders::submission::{
+    PayloadImage, PayloadLink, PayloadRichText, PayloadSelfText, SubmissionSubmitBuilder,
+    SubmitRequest,
+};
+use crate::util::markdown::{self, MarkdownIssue};
+
+/// The longest post title Reddit accepts, in characters.
+pub const MAX_TITLE_LENGTH: usize = 300;
+
+/// The longest post body Reddit accepts, in characters.
+pub const MAX_SELFTEXT_LENGTH: usize = 40_000;
+
+/// The longest comment Reddit accepts, in characters.
+pub const MAX_COMMENT_LENGTH: usize = 10_000;
+
+/// What would be sent for a post or comment, and any problems found with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DryRun {
+    /// The endpoint the request would be sent to, e.g. `api/submit`.
+    pub endpoint: &'static str,
+    /// The form that would be sent, URL-encoded.
+    pub body: String,
+    /// The problems found, which are empty if none were.
+    pub problems: Vec<DraftProblem>,
+}
+
+impl DryRun {
+    /// Checks a comment, without posting it, see
+    /// [`AuthedClient::comment`](crate::client::AuthedClient::comment).
+    pub fn comment(text: &str, parent: &ThingFullname) -> Self {
+        let form = FormBuilder::new()
+            .with("text", text)
+            .with("parent", parent.full());
+
+        let mut problems = Vec::new();
+        let length = text.chars().count();
+        if text.trim().is_empty() {
+            problems.push(DraftProblem::BodyRequired);
+        } else if length > MAX_COMMENT_LENGTH {
+            problems.push(DraftProblem::BodyTooLong {
+                length,
+                max: MAX_COMMENT_LENGTH,
+            });
+        }
+        problems.extend(markdown::lint(text).into_iter().map(DraftProblem::Markdown));
+
+        Self {
+            endpoint: "api/comment",
+            body: serde_urlencoded::to_string(&form).unwrap_or_default(),
+            problems,
+        }
+    }
+
+    /// Whether no problems were found.
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// The content of a post which can be checked, implemented for the submission payloads.
+pub trait DraftContent {
+    /// The markdown body, if the post has one which can be checked.
+    fn body(&self) -> Option<&str>;
+
+    /// The URL the post links to, if it is a link post.
+    fn url(&self) -> Option<&str> {
+        None
+    }
+
+    /// Whether the post has a body, whether or not it can be checked.
+    fn has_body(&self) -> bool {
+        self.body().is_some_and(|body| !body.trim().is_empty())
+    }
+}
+
+impl DraftContent for PayloadSelfText {
+    fn body(&self) -> Option<&str> {
+        Some(&self.text)
+    }
+}
+
+impl DraftContent for PayloadRichText {
+    fn body(&self) -> Option<&str> {
+        None
+    }
+
+    fn has_body(&self) -> bool {
+        true
+    }
+}
+
+impl DraftContent for PayloadLink {
+    fn body(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    fn url(&self) -> Option<&str> {
+        Some(&self.url)
+    }
+}
+
+impl DraftContent for PayloadImage {
+    fn body(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl<Kind: Serialize + DraftContent> SubmissionSubmitBuilder<Kind> {
+    /// Checks the post, without submitting it, against Reddit's limits and the subreddit's
+    /// requirements (see [`Subreddit::post_requirements`](crate::client::Subreddit::post_requirements)),
+    /// and lints its markdown.
+    ///
+    /// Title and body regexes are not checked. Images are not uploaded, so their URL is not
+    /// part of the body.
+    pub fn dry_run(&self, subreddit: &str, requirements: Option<&PostRequirements>) -> DryRun {
+        let request = SubmitRequest {
+            sr: subreddit,
+            data: self,
+        };
+
+        let mut problems = Vec::new();
+        let default_requirements = PostRequirements::default();
+        let requirements = requirements.unwrap_or(&default_requirements);
+        check_title(&self.title, requirements, &mut problems);
+        check_body(&self.kind, requirements, &mut problems);
+
+        if let Some(domain) = self.kind.url().and_then(url_domain) {
+            if !domain_allowed(&domain, requirements) {
+                problems.push(DraftProblem::DomainNotAllowed(domain));
+            }
+        }
+
+        if requirements.is_flair_required && self.flair_id.is_none() && self.flair_text.is_none() {
+            problems.push(DraftProblem::FlairRequired);
+        }
+
+        DryRun {
+            endpoint: "api/submit",
+            body: serde_urlencoded::to_string(&request).unwrap_or_default(),
+            problems,
+        }
+    }
+}
+
+fn check_title(title: &str, requirements: &PostRequirements, problems: &mut Vec<DraftProblem>) {
+    let length = title.chars().count();
+    let min = requirements.title_text_min_length.unwrap_or(1) as usize;
+    let max = requirements
+        .title_text_max_length
+        .map_or(MAX_TITLE_LENGTH, |max| (max as usize).min(MAX_TITLE_LENGTH));
+    if length < min {
+        problems.push(DraftProblem::TitleTooShort { length, min });
+    } else if length > max {
+        problems.push(DraftProblem::TitleTooLong { length, max });
+    }
+
+    if !contains_any(title, &requirements.title_required_strings) {
+        problems.push(DraftProblem::TitleMissingRequiredString);
+    }
+    if let Some(blacklisted) = find_any(title, &requirements.title_blacklisted_strings) {
+        problems.push(DraftProblem::TitleBlacklistedString(blacklisted));
+    }
+}
+
+fn check_body(
+    content: &impl DraftContent,
+    requirements: &PostRequirements,
+    problems: &mut Vec<DraftProblem>,
+) {
+    match requirements.body_restriction_policy.as_deref() {
+        Some("required") if !content.has_body() => problems.push(DraftProblem::BodyRequired),
+        Some("notAllowed") if content.has_body() => problems.push(DraftProblem::BodyNotAllowed),
+        _ => {}
+    }
+
+    let Some(body) = content.body().filter(|body| !body.is_empty()) else {
+        return;
+    };
+
+    let length = body.chars().count();
+    let min = requirements.body_text_min_length.unwrap_or(0) as usize;
+    let max = requirements
+        .body_text_max_length
+        .map_or(MAX_SELFTEXT_LENGTH, |max| {
+            (max as usize).min(MAX_SELFTEXT_LENGTH)
+        });
+    if length < min {
+        problems.push(DraftProblem::BodyTooShort { length, min });
+    } else if length > max {
+        problems.push(DraftProblem::BodyTooLong { length, max });
+    }
+
+    if !contains_any(body, &requirements.body_required_strings) {
+        problems.push(DraftProblem::BodyMissingRequiredString);
+    }
+    if let Some(blacklisted) = find_any(body, &requirements.body_blacklisted_strings) {
+        problems.push(DraftProblem::BodyBlacklistedString(blacklisted));
+    }
+
+    problems.extend(markdown::lint(body).into_iter().map(DraftProblem::Markdown));
+}
+
+/// Whether the text contains one of the strings, ignoring case, or there are none.
+fn contains_any(text: &str, strings: &[String]) -> bool {
+    strings.is_empty() || find_any(text, strings).is_some()
+}
+
+/// The first of the strings the text contains, ignoring case.
+fn find_any(text: &str, strings: &[String]) -> Option<String> {
+    let text = text.to_lowercase();
+    strings
+        .iter()
+        .find(|string| !string.is_empty() && text.contains(&string.to_lowercase()))
+        .cloned()
+}
+
+fn url_domain(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+    let host = url.host_str()?;
+    Some(host.strip_prefix("www.").unwrap_or(host).to_lowercase())
+}
+
+fn domain_allowed(domain: &str, requirements: &PostRequirements) -> bool {
+    let matches = |listed: &String| {
+        let listed = listed.to_lowercase();
+        domain == listed || domain.ends_with(&format!(".{listed}"))
+    };
+
+    match requirements.link_restriction_policy.as_deref() {
+        Some("whitelist") => requirements.domain_whitelist.iter().any(matches),
+        Some("blacklist") => !requirements.domain_blacklist.iter().any(matches),
+        _ => true,
+    }
+}
+
+/// A problem with a post or comment found by a [`DryRun`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DraftProblem {
+    /// The title is shorter than the subreddit allows.
+    TitleTooShort {
+        /// The length of the title, in characters.
+        length: usize,
+        /// The shortest title allowed.
+        min: usize,
+    },
+    /// The title is longer than Reddit or the subreddit allows.
+    TitleTooLong {
+        /// The length of the title, in characters.
+        length: usize,
+        /// The longest title allowed.
+        max: usize,
+    },
+    /// The title does not contain any of the strings the subreddit requires.
+    TitleMissingRequiredString,
+    /// The title contains a string the subreddit does not allow.
+    TitleBlacklistedString(String),
+    /// The post or comment needs a body.
+    BodyRequired,
+    /// The subreddit does not allow posts with a body.
+    BodyNotAllowed,
+    /// The body is shorter than the subreddit allows.
+    BodyTooShort {
+        /// The length of the body, in characters.
+        length: usize,
+        /// The shortest body allowed.
+        min: usize,
+    },
+    /// The body is longer than Reddit or the subreddit allows.
+    BodyTooLong {
+        /// The length of the body, in characters.
+        length: usize,
+        /// The longest body allowed.
+        max: usize,
+    },
+    /// The body does not contain any of the strings the subreddit requires.
+    BodyMissingRequiredString,
+    /// The body contains a string the subreddit does not allow.
+    BodyBlacklistedString(String),
+    /// The post links to a domain the subreddit does not allow.
+    DomainNotAllowed(String),
+    /// The subreddit requires posts to have a flair.
+    FlairRequired,
+    /// The markdown of the body has a mistake.
+    Markdown(MarkdownIssue),
+}
+
+impl std::fmt::Display for DraftProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DraftProblem::TitleTooShort { length, min } => {
+                write!(f, "title is {length} characters, the minimum is {min}")
+            }
+            DraftProblem::TitleTooLong { length, max } => {
+                write!(f, "title is {length} characters, the limit is {max}")
+            }
+            DraftProblem::TitleMissingRequiredString => {
+                write!(f, "title does not contain any of the required strings")
+            }
+            DraftProblem::TitleBlacklistedString(string) => {
+                write!(f, "title contains '{string}', which is not allowed")
+            }
+            DraftProblem::BodyRequired => write!(f, "a body is required"),
+            DraftProblem::BodyNotAllowed => write!(f, "a body is not allowed"),
+            DraftProblem::BodyTooShort { length, min } => {
+                write!(f, "body is {length} characters, the minimum is {min}")
+            }
+            DraftProblem::BodyTooLong { length, max } => {
+                write!(f, "body is {length} characters, the limit is {max}")
+            }
+            DraftProblem::BodyMissingRequiredString => {
+                write!(f, "body does not contain any of the required strings")
+            }
+            DraftProblem::BodyBlacklistedString(string) => {
+                write!(f, "body contains '{string}', which is not allowed")
+            }
+            DraftProblem::DomainNotAllowed(domain) => {
+                write!(f, "links to {domain} are not allowed")
+            }
+            DraftProblem::FlairRequired => write!(f, "a flair is required"),
+            DraftProblem::Markdown(issue) => issue.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for DraftProblem {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DraftProblem, DryRun, MAX_COMMENT_LENGTH};
+    use crate::api::subreddit::PostRequirements;
+    use crate::api::ThingFullname;
+    use crate::builders::submission::SubmissionSubmitBuilder;
+    use crate::util::markdown::MarkdownIssue;
+
+    #[test]
+    fn test_submission_dry_run() {
+        let post = SubmissionSubmitBuilder::text("Weekly thread", "Ask away").with_live_chat();
+        let dry_run = post.dry_run("rust", None);
+
+        assert!(dry_run.is_valid());
+        assert_eq!(dry_run.endpoint, "api/submit");
+        assert_eq!(
+            dry_run.body,
+            "sr=rust&title=Weekly+thread&sendreplies=true&nsfw=false&spoiler=false&discussion_type=CHAT&kind=self&text=Ask+away&api_type=json&validate_on_submit=false"
+        );
+    }
+
+    #[test]
+    fn test_submission_requirements() {
+        let requirements = PostRequirements {
+            title_required_strings: vec![String::from("[Help]")],
+            title_blacklisted_strings: vec![String::from("urgent")],
+            link_restriction_policy: Some(String::from("whitelist")),
+            domain_whitelist: vec![String::from("github.com")],
+            is_flair_required: true,
+            ..PostRequirements::default()
+        };
+
+        let post = SubmissionSubmitBuilder::link("URGENT question", "https://www.example.com/a")
+            .with_text("[broken](https://example.com");
+        assert_eq!(
+            post.dry_run("rust", Some(&requirements)).problems,
+            [
+                DraftProblem::TitleMissingRequiredString,
+                DraftProblem::TitleBlacklistedString(String::from("urgent")),
+                DraftProblem::Markdown(MarkdownIssue::UnclosedLink { line: 1 }),
+                DraftProblem::DomainNotAllowed(String::from("example.com")),
+                DraftProblem::FlairRequired,
+            ]
+        );
+
+        let post = SubmissionSubmitBuilder::link("[help] CI fails", "https://gist.github.com/a")
+            .with_flair_text("Question");
+        assert!(post.dry_run("rust", Some(&requirements)).is_valid());
+    }
+
+    #[test]
+    fn test_comment_dry_run() {
+        let parent = ThingFullname::from_submission_id("abc123");
+
+        let dry_run = DryRun::comment("Nice & tidy", &parent);
+        assert!(dry_run.is_valid());
+        assert_eq!(
+            dry_run.body,
+            "api_type=json&text=Nice+%26+tidy&parent=t3_abc123"
+        );
+
+        let text = "a".repeat(MAX_COMMENT_LENGTH + 1);
+        assert_eq!(
+            DryRun::comment(&text, &parent).problems,
+            [DraftProblem::BodyTooLong {
+                length: MAX_COMMENT_LENGTH + 1,
+                max: MAX_COMMENT_LENGTH
+            }]
+        );
+        assert_eq!(
+            DryRun::comment(" ", &parent).problems,
+            [DraftProblem::BodyRequired]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use crate::api::subreddit::{FlairAllowableContent, FlairTemplate};
+use crate::builders::form::FormBuilder;
+
+/// The longest flair text Reddit accepts, in characters.
+pub const MAX_FLAIR_TEXT_LENGTH: usize = 64;
+
+/// The number of emojis Reddit allows in flair text unless a template sets its own limit.
+pub const DEFAULT_MAX_FLAIR_EMOJIS: usize = 10;
+
+/// The rules flair text must follow in a subreddit.
+///
+/// Reddit's errors for invalid flair text are vague, so text can be checked against these before
+/// selecting a flair. Use [`SubredditAbout::flair_text_constraints`](crate::models::SubredditAbout::flair_text_constraints)
+/// to get the constraints for a subreddit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlairTextConstraints {
+    /// Whether emojis can be used.
+    pub emojis_enabled: bool,
+    /// The most emojis the text can contain.
+    pub max_emojis: usize,
+}
+
+impl Default for FlairTextConstraints {
+    fn default() -> Self {
+        Self {
+            emojis_enabled: true,
+            max_emojis: DEFAULT_MAX_FLAIR_EMOJIS,
+        }
+    }
+}
+
+impl FlairTextConstraints {
+    /// Sets the most emojis the text can contain, e.g. from a flair template's `max_emojis`.
+    pub fn with_max_emojis(mut self, max_emojis: usize) -> Self {
+        self.max_emojis = max_emojis;
+        self
+    }
+
+    /// Checks that the text follows these constraints.
+    pub fn validate(&self, text: &str) -> Result<(), FlairTextError> {
+        let length = text.chars().count();
+        if length > MAX_FLAIR_TEXT_LENGTH {
+            return Err(FlairTextError::TooLong { length });
+        }
+
+        let emojis = count_emojis(text);
+        if emojis > 0 && !self.emojis_enabled {
+            return Err(FlairTextError::EmojisDisabled);
+        }
+        if emojis > self.max_emojis {
+            return Err(FlairTextError::TooManyEmojis {
+                count: emojis,
+                max: self.max_emojis,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The settings of a flair template, used to create or update it with
+/// [`Subreddit::create_flair_template`](crate::client::Subreddit::create_flair_template) and
+/// [`Subreddit::update_flair_template`](crate::client::Subreddit::update_flair_template).
+///
+/// Settings which are not set are left to Reddit's defaults, so to change only some settings of
+/// an existing template, start from [`FlairTemplateOptions::from_template`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlairTemplateOptions {
+    /// The text of the flair.
+    pub text: String,
+    /// The background color as a hex code such as `#dea584`.
+    pub background_color: Option<String>,
+    /// Whether the text is `dark` or `light`.
+    pub text_color: Option<String>,
+    /// The CSS class used by old Reddit.
+    pub css_class: Option<String>,
+    /// Whether only moderators can select the flair.
+    pub mod_only: Option<bool>,
+    /// Whether users can edit the text when selecting the flair.
+    pub text_editable: Option<bool>,
+    /// What the text may contain.
+    pub allowable_content: Option<FlairAllowableContent>,
+    /// The most emojis the text may contain, up to [`DEFAULT_MAX_FLAIR_EMOJIS`].
+    pub max_emojis: Option<usize>,
+}
+
+impl FlairTemplateOptions {
+    /// Creates options for a template with the text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates options with the settings of an existing template.
+    pub fn from_template(template: &FlairTemplate) -> Self {
+        Self {
+            text: template.text.clone(),
+            background_color: template
+                .background_color
+                .clone()
+                .filter(|color| !color.is_empty()),
+            text_color: template.text_color.clone(),
+            css_class: template.css_class.clone().filter(|class| !class.is_empty()),
+            mod_only: Some(template.mod_only),
+            text_editable: Some(template.text_editable),
+            allowable_content: Some(template.allowable_content.clone()),
+            max_emojis: Some(template.max_emojis),
+        }
+    }
+
+    /// Sets the text of the flair.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Sets the background color, as a hex code such as `#dea584`.
+    pub fn background_color(mut self, color: impl Into<String>) -> Self {
+        self.background_color = Some(color.into());
+        self
+    }
+
+    /// Sets whether the text is `dark` (`false`) or `light` (`true`).
+    pub fn light_text(mut self, light: bool) -> Self {
+        self.text_color = Some(if light { "light" } else { "dark" }.to_owned());
+        self
+    }
+
+    /// Sets the CSS class used by old Reddit.
+    pub fn css_class(mut self, class: impl Into<String>) -> Self {
+        self.css_class = Some(class.into());
+        self
+    }
+
+    /// Sets whether only moderators can select the flair.
+    pub fn mod_only(mut self, mod_only: bool) -> Self {
+        self.mod_only = Some(mod_only);
+        self
+    }
+
+    /// Sets whether users can edit the text when selecting the flair.
+    pub fn text_editable(mut self, editable: bool) -> Self {
+        self.text_editable = Some(editable);
+        self
+    }
+
+    /// Sets what the text may contain.
+    pub fn allowable_content(mut self, content: FlairAllowableContent) -> Self {
+        self.allowable_content = Some(content);
+        self
+    }
+
+    /// Sets the most emojis the text may contain, up to [`DEFAULT_MAX_FLAIR_EMOJIS`].
+    pub fn max_emojis(mut self, max_emojis: usize) -> Self {
+        self.max_emojis = Some(max_emojis.clamp(1, DEFAULT_MAX_FLAIR_EMOJIS));
+        self
+    }
+
+    /// Adds these options to a `flairtemplate_v2` request.
+    pub(crate) fn add_to<'a>(&'a self, form: FormBuilder<'a>) -> FormBuilder<'a> {
+        let form = form
+            .with("text", self.text.as_str())
+            .with_opt("background_color", self.background_color.as_deref())
+            .with_opt("text_color", self.text_color.as_deref())
+            .with_opt("css_class", self.css_class.as_deref())
+            .with_opt(
+                "allowable_content",
+                self.allowable_content.as_ref().map(|c| c.as_str()),
+            )
+            .with_opt("max_emojis", self.max_emojis.map(|max| max.to_string()));
+
+        let form = match self.mod_only {
+            Some(mod_only) => form.with_bool("mod_only", mod_only),
+            None => form,
+        };
+        match self.text_editable {
+            Some(editable) => form.with_bool("text_editable", editable),
+            None => form,
+        }
+    }
+}
+
+/// Counts the emojis in flair text, which are written as `:name:`.
+pub fn count_emojis(text: &str) -> usize {
+    let mut count = 0;
+    let mut rest = text;
+
+    while let Some(start) = rest.find(':') {
+        let after = &rest[start + 1..];
+        let name_length = after
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(after.len());
+
+        if name_length > 0 && after[name_length..].starts_with(':') {
+            count += 1;
+            rest = &after[name_length + 1..];
+        } else {
+            rest = after;
+        }
+    }
+
+    count
+}
+
+/// Why flair text is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlairTextError {
+    /// The text is longer than [`MAX_FLAIR_TEXT_LENGTH`] characters.
+    TooLong {
+        /// The length of the text, in characters.
+        length: usize,
+    },
+    /// The text contains emojis, but the subreddit has disabled them.
+    EmojisDisabled,
+    /// The text contains more emojis than allowed.
+    TooManyEmojis {
+        /// The number of emojis in the text.
+        count: usize,
+        /// The most emojis allowed.
+        max: usize,
+    },
+}
+
+impl std::fmt::Display for FlairTextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlairTextError::TooLong { length } => write!(
+                f,
+                "flair text is {length} characters, the limit is {MAX_FLAIR_TEXT_LENGTH}"
+            ),
+            FlairTextError::EmojisDisabled => {
+                write!(f, "flair text contains emojis, which are disabled")
+            }
+            FlairTextError::TooManyEmojis { count, max } => {
+                write!(f, "flair text contains {count} emojis, the limit is {max}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FlairTextError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        count_emojis, FlairTemplateOptions, FlairTextConstraints, FlairTextError,
+        MAX_FLAIR_TEXT_LENGTH,
+    };
+    use crate::api::subreddit::FlairAllowableContent;
+    use crate::builders::form::FormBuilder;
+
+    #[test]
+    fn test_flair_template_options_form() {
+        let options = FlairTemplateOptions::new("Rustacean")
+            .background_color("#dea584")
+            .light_text(false)
+            .mod_only(true)
+            .allowable_content(FlairAllowableContent::Text)
+            .max_emojis(50);
+
+        let form = options.add_to(FormBuilder::new());
+        assert_eq!(
+            serde_urlencoded::to_string(&form).unwrap(),
+            "api_type=json&text=Rustacean&background_color=%23dea584&text_color=dark&allowable_content=text&max_emojis=10&mod_only=true"
+        );
+    }
+
+    #[test]
+    fn test_count_emojis() {
+        assert_eq!(count_emojis("Rustacean :ferris: :crab_2:"), 2);
+        assert_eq!(count_emojis(":a::b:"), 2);
+        assert_eq!(count_emojis("Time: 12:30"), 0);
+        assert_eq!(count_emojis(":not an emoji:"), 0);
+    }
+
+    #[test]
+    fn test_validate() {
+        let constraints = FlairTextConstraints::default().with_max_emojis(1);
+        assert_eq!(constraints.validate(":ferris: Rustacean"), Ok(()));
+        assert_eq!(
+            constraints.validate(":ferris::ferris:"),
+            Err(FlairTextError::TooManyEmojis { count: 2, max: 1 })
+        );
+
+        let disabled = FlairTextConstraints {
+            emojis_enabled: false,
+            ..constraints
+        };
+        assert_eq!(
+            disabled.validate(":ferris:"),
+            Err(FlairTextError::EmojisDisabled)
+        );
+
+        let text = "a".repeat(MAX_FLAIR_TEXT_LENGTH + 1);
+        assert_eq!(
+            constraints.validate(&text),
+            Err(FlairTextError::TooLong {
+                length: MAX_FLAIR_TEXT_LENGTH + 1
+            })
+        );
+    }
+}
@@ -2,22 +2,26 @@ use std::borrow::Cow;
 
 use serde::Serialize;
 
+/// A form body, sent with `api_type=json` so that errors are returned as JSON.
 pub struct FormBuilder<'a> {
     values: Vec<(Cow<'a, str>, Cow<'a, str>)>,
 }
 
 impl<'a> FormBuilder<'a> {
+    /// Creates a form with only `api_type=json`.
     pub fn new() -> Self {
         Self {
             values: vec![(Cow::Borrowed("api_type"), Cow::Borrowed("json"))],
         }
     }
 
+    /// Adds the key and value.
     pub fn with(mut self, key: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
         self.add(key, value);
         self
     }
 
+    /// Adds the key and value, if there is a value.
     pub fn with_opt<V>(mut self, key: impl Into<Cow<'a, str>>, value: Option<V>) -> Self
     where
         V: Into<Cow<'a, str>>,
@@ -28,15 +32,60 @@ impl<'a> FormBuilder<'a> {
         self
     }
 
+    /// Adds the key once for each value, e.g. `type=a&type=b`.
+    pub fn with_many<V>(
+        mut self,
+        key: impl Into<Cow<'a, str>>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self
+    where
+        V: Into<Cow<'a, str>>,
+    {
+        let key = key.into();
+        for value in values {
+            self.add(key.clone(), value);
+        }
+        self
+    }
+
+    /// Adds the values joined with commas, e.g. `id=t3_a,t3_b`.
+    pub fn with_joined<V: AsRef<str>>(
+        self,
+        key: impl Into<Cow<'a, str>>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.with(key, join_comma(values))
+    }
+
+    /// Adds the key with `true` or `false`.
     pub fn with_bool(self, key: impl Into<Cow<'a, str>>, value: bool) -> Self {
         self.with(key, if value { "true" } else { "false" })
     }
 
+    /// Adds the key and value in place.
     pub fn add(&mut self, key: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) {
         self.values.push((key.into(), value.into()));
     }
 }
 
+impl Default for FormBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Joins the values with commas, as Reddit expects lists of IDs or names.
+pub fn join_comma<V: AsRef<str>>(values: impl IntoIterator<Item = V>) -> String {
+    let mut joined = String::new();
+    for value in values {
+        if !joined.is_empty() {
+            joined.push(',');
+        }
+        joined.push_str(value.as_ref());
+    }
+    joined
+}
+
 impl<'form> Serialize for FormBuilder<'form> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -70,7 +119,7 @@ impl<'f> std::fmt::Debug for FormBuilder<'f> {
 
 #[cfg(test)]
 mod tests {
-    use super::FormBuilder;
+    use super::{join_comma, FormBuilder};
 
     #[test]
     pub fn test_url_encoded() {
@@ -85,4 +134,25 @@ mod tests {
             r#"api_type=json&text=goeshere&another-value=somemore+stuff"#
         );
     }
+
+    #[test]
+    pub fn test_many_and_joined() {
+        let form = FormBuilder::new()
+            .with_many("type", ["links", "comments"])
+            .with_joined("id", ["t3_a", "t3_b"]);
+
+        let encoded = serde_urlencoded::to_string(&form).unwrap();
+
+        assert_eq!(
+            encoded,
+            r#"api_type=json&type=links&type=comments&id=t3_a%2Ct3_b"#
+        );
+        assert_eq!(join_comma(Vec::<&str>::new()), "");
+
+        let form = FormBuilder::new().with("text", "C++ & Rust = 100% #1");
+        assert_eq!(
+            serde_urlencoded::to_string(&form).unwrap(),
+            "api_type=json&text=C%2B%2B+%26+Rust+%3D+100%25+%231"
+        );
+    }
 }
@@ -1,4 +1,17 @@
 /// Builder to create a submission to a subreddit.
 pub mod submission;
 
-pub(crate) mod form;
+/// Flair template settings and validation for flair text.
+pub mod flair;
+
+/// Checking posts and comments before they are sent.
+pub mod draft;
+
+/// Options for banning users.
+pub mod ban;
+
+/// Composing search queries.
+pub mod search;
+
+/// Form bodies for [`RedditClient::post`](crate::client::RedditClient::post).
+pub mod form;
@@ -0,0 +1,210 @@
+use std::fmt;
+
+/// A builder to compose a search query, for
+/// [`Subreddit::search`](crate::client::Subreddit::search) and
+/// [`RedditClient::search`](crate::client::RedditClient::search).
+///
+/// Values are quoted and escaped as needed, so they cannot change the meaning of the rest of
+/// the query. Terms are combined with `AND`.
+///
+/// ```
+/// use roux::builders::search::SearchQueryBuilder;
+///
+/// let query = SearchQueryBuilder::new()
+///     .text("borrow checker")
+///     .flair("Help")
+///     .is_self(true)
+///     .or(SearchQueryBuilder::new().author("ferris"))
+///     .build();
+///
+/// assert_eq!(
+///     query,
+///     r#"(("borrow checker" AND flair:Help AND self:yes) OR (author:ferris))"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQueryBuilder {
+    terms: Vec<String>,
+}
+
+impl SearchQueryBuilder {
+    /// Creates an empty query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches text anywhere in a submission, as a single phrase.
+    pub fn text(self, text: &str) -> Self {
+        self.term(quote(text))
+    }
+
+    /// Matches submissions by the user, without the `/u/` prefix.
+    pub fn author(self, name: &str) -> Self {
+        self.field("author", name)
+    }
+
+    /// Matches submissions in the subreddit, without the `/r/` prefix.
+    pub fn subreddit(self, name: &str) -> Self {
+        self.field("subreddit", name)
+    }
+
+    /// Matches submissions whose flair has the text.
+    pub fn flair(self, text: &str) -> Self {
+        self.field("flair", text)
+    }
+
+    /// Matches submissions whose title has the text.
+    pub fn title(self, text: &str) -> Self {
+        self.field("title", text)
+    }
+
+    /// Matches self posts whose body has the text.
+    pub fn selftext(self, text: &str) -> Self {
+        self.field("selftext", text)
+    }
+
+    /// Matches link posts to the URL.
+    pub fn url(self, url: &str) -> Self {
+        self.field("url", url)
+    }
+
+    /// Matches link posts to the domain, e.g. `github.com`.
+    pub fn site(self, domain: &str) -> Self {
+        self.field("site", domain)
+    }
+
+    /// Matches only self posts, or only link posts.
+    pub fn is_self(self, is_self: bool) -> Self {
+        self.term(format!("self:{}", yes_no(is_self)))
+    }
+
+    /// Matches only NSFW submissions, or only those which are not.
+    pub fn nsfw(self, nsfw: bool) -> Self {
+        self.term(format!("nsfw:{}", yes_no(nsfw)))
+    }
+
+    /// Excludes submissions matching the other query.
+    pub fn not(self, other: SearchQueryBuilder) -> Self {
+        match other.group() {
+            Some(group) => self.term(format!("NOT {group}")),
+            None => self,
+        }
+    }
+
+    /// Matches submissions matching either this query or the other.
+    pub fn or(self, other: SearchQueryBuilder) -> Self {
+        match (self.group(), other.group()) {
+            (Some(this), Some(other)) => Self {
+                terms: vec![format!("({this} OR {other})")],
+            },
+            (Some(_), None) => self,
+            (None, _) => other,
+        }
+    }
+
+    /// Builds the query, to be used as the `q` parameter.
+    pub fn build(&self) -> String {
+        self.terms.join(" AND ")
+    }
+
+    fn term(mut self, term: String) -> Self {
+        self.terms.push(term);
+        self
+    }
+
+    fn field(self, name: &str, value: &str) -> Self {
+        self.term(format!("{name}:{}", quote(value)))
+    }
+
+    /// The query in parentheses, so it can be combined with other queries.
+    fn group(&self) -> Option<String> {
+        match self.terms.len() {
+            0 => None,
+            _ => Some(format!("({})", self.build())),
+        }
+    }
+}
+
+impl fmt::Display for SearchQueryBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.build())
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// Quotes a value if it would otherwise be read as more than a single plain word.
+fn quote(value: &str) -> String {
+    let is_operator = matches!(value, "AND" | "OR" | "NOT");
+    let is_plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| !c.is_whitespace() && !"\"\\():".contains(c));
+
+    if is_plain && !is_operator {
+        return value.to_owned();
+    }
+
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchQueryBuilder;
+
+    #[test]
+    fn test_quoting() {
+        let query = SearchQueryBuilder::new()
+            .author("some_user")
+            .flair("C++ & Rust")
+            .title(r#"say "hi" \o/"#)
+            .text("OR")
+            .url("https://example.com/a?b=c")
+            .build();
+
+        assert_eq!(
+            query,
+            r#"author:some_user AND flair:"C++ & Rust" AND title:"say \"hi\" \\o/" AND "OR" AND url:"https://example.com/a?b=c""#
+        );
+    }
+
+    #[test]
+    fn test_operators() {
+        let query = SearchQueryBuilder::new()
+            .subreddit("rust")
+            .nsfw(false)
+            .not(SearchQueryBuilder::new().site("github.com"))
+            .not(SearchQueryBuilder::new());
+
+        assert_eq!(
+            query.build(),
+            "subreddit:rust AND nsfw:no AND NOT (site:github.com)"
+        );
+        assert_eq!(
+            SearchQueryBuilder::new().or(query.clone()).build(),
+            query.build()
+        );
+        assert_eq!(SearchQueryBuilder::new().build(), "");
+    }
+
+    #[test]
+    fn test_term_after_or() {
+        let query = SearchQueryBuilder::new()
+            .author("ferris")
+            .or(SearchQueryBuilder::new().author("crab"))
+            .subreddit("rust")
+            .build();
+
+        assert_eq!(
+            query,
+            "((author:ferris) OR (author:crab)) AND subreddit:rust"
+        );
+    }
+}
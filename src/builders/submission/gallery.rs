@@ -0,0 +1,143 @@
+use serde::Serialize;
+
+/// The longest caption Reddit accepts for a gallery item, in characters.
+pub const MAX_GALLERY_CAPTION_LENGTH: usize = 180;
+
+/// An image in a gallery post.
+///
+/// Captions and outbound URLs are validated when they are set, as Reddit's errors for invalid
+/// gallery items don't say which item or field was at fault.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GalleryItem {
+    media_id: String,
+    caption: String,
+    outbound_url: String,
+}
+
+impl GalleryItem {
+    /// Creates a gallery item from the ID of uploaded media.
+    pub fn new(media_id: impl Into<String>) -> Self {
+        Self {
+            media_id: media_id.into(),
+            caption: String::new(),
+            outbound_url: String::new(),
+        }
+    }
+
+    /// Sets the caption shown beneath the image.
+    ///
+    /// Fails if it is longer than [`MAX_GALLERY_CAPTION_LENGTH`] characters.
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Result<Self, GalleryItemError> {
+        let caption = caption.into();
+        let length = caption.chars().count();
+        if length > MAX_GALLERY_CAPTION_LENGTH {
+            return Err(GalleryItemError::CaptionTooLong { length });
+        }
+
+        self.caption = caption;
+        Ok(self)
+    }
+
+    /// Sets the link shown with the image.
+    ///
+    /// Fails unless it is an absolute `http` or `https` URL.
+    pub fn with_outbound_url(mut self, url: impl Into<String>) -> Result<Self, GalleryItemError> {
+        let url = url.into();
+        match reqwest::Url::parse(&url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => {}
+            _ => return Err(GalleryItemError::InvalidUrl(url)),
+        }
+
+        self.outbound_url = url;
+        Ok(self)
+    }
+
+    /// The ID of the uploaded media.
+    pub fn media_id(&self) -> &str {
+        &self.media_id
+    }
+
+    /// The caption, which is empty if not set.
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    /// The outbound URL, which is empty if not set.
+    pub fn outbound_url(&self) -> &str {
+        &self.outbound_url
+    }
+}
+
+/// Why a gallery item is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GalleryItemError {
+    /// The caption is longer than [`MAX_GALLERY_CAPTION_LENGTH`] characters.
+    CaptionTooLong {
+        /// The length of the caption, in characters.
+        length: usize,
+    },
+    /// The outbound URL is not an absolute `http` or `https` URL.
+    InvalidUrl(String),
+}
+
+impl std::fmt::Display for GalleryItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GalleryItemError::CaptionTooLong { length } => write!(
+                f,
+                "gallery caption is {length} characters, the limit is {MAX_GALLERY_CAPTION_LENGTH}"
+            ),
+            GalleryItemError::InvalidUrl(url) => {
+                write!(f, "gallery outbound url {url:?} is not an http(s) url")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GalleryItemError {}
+
+/// Payload for a gallery post.
+///
+/// Galleries are sent as JSON rather than a form, so this can only be submitted with
+/// [`AuthedClient::submit_gallery`](crate::client::AuthedClient::submit_gallery).
+#[derive(Debug, Clone, Serialize)]
+pub struct PayloadGallery {
+    pub(crate) items: Vec<GalleryItem>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GalleryItem, GalleryItemError, MAX_GALLERY_CAPTION_LENGTH};
+
+    #[test]
+    fn test_caption_length() {
+        let item = GalleryItem::new("abc");
+
+        let caption = "é".repeat(MAX_GALLERY_CAPTION_LENGTH);
+        assert!(item.clone().with_caption(caption).is_ok());
+
+        let caption = "a".repeat(MAX_GALLERY_CAPTION_LENGTH + 1);
+        assert_eq!(
+            item.with_caption(caption),
+            Err(GalleryItemError::CaptionTooLong {
+                length: MAX_GALLERY_CAPTION_LENGTH + 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_outbound_url() {
+        let item = GalleryItem::new("abc");
+
+        assert!(item
+            .clone()
+            .with_outbound_url("https://example.com/a")
+            .is_ok());
+        for url in ["example.com", "ftp://example.com", "javascript:alert(1)"] {
+            assert_eq!(
+                item.clone().with_outbound_url(url),
+                Err(GalleryItemError::InvalidUrl(url.to_owned()))
+            );
+        }
+    }
+}
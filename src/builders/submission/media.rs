@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::client::req::fs;
+
+/// An image or video to upload, either from a file or from memory.
+#[derive(Debug, Clone)]
+pub enum MediaSource {
+    /// A file on disk.
+    Path(PathBuf),
+    /// A file already in memory.
+    Bytes {
+        /// The file name, whose extension determines the media type (e.g. `cat.png`).
+        file_name: String,
+        /// The file contents.
+        bytes: Vec<u8>,
+    },
+}
+
+impl MediaSource {
+    /// Uploads the file at `path`.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        MediaSource::Path(path.into())
+    }
+
+    /// Uploads `bytes`, with `file_name` determining the media type (e.g. `cat.png`).
+    pub fn bytes(file_name: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        MediaSource::Bytes {
+            file_name: file_name.into(),
+            bytes: bytes.into(),
+        }
+    }
+
+    /// The file name sent to Reddit.
+    pub fn file_name(&self) -> Cow<'_, str> {
+        match self {
+            MediaSource::Path(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy())
+                .unwrap_or_default(),
+            MediaSource::Bytes { file_name, .. } => Cow::Borrowed(file_name),
+        }
+    }
+
+    /// The media type, based on the file extension, or `None` if Reddit doesn't accept it.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        let name = self.file_name();
+        let extension = Path::new(name.as_ref()).extension()?.to_str()?;
+
+        let mime = match extension.to_ascii_lowercase().as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "webp" => "image/webp",
+            "mp4" => "video/mp4",
+            "mov" => "video/quicktime",
+            _ => return None,
+        };
+        Some(mime)
+    }
+
+    #[maybe_async::maybe_async]
+    pub(crate) async fn read(&self) -> std::io::Result<Cow<'_, [u8]>> {
+        match self {
+            MediaSource::Path(path) => fs::read(path).await.map(Cow::Owned),
+            MediaSource::Bytes { bytes, .. } => Ok(Cow::Borrowed(bytes)),
+        }
+    }
+}
+
+/// Payload for an image post.
+///
+/// The image is uploaded when submitting, so this can only be submitted with
+/// [`AuthedClient::submit_image_lazy`](crate::client::AuthedClient::submit_image_lazy).
+#[derive(Debug, Clone)]
+pub struct PayloadImage {
+    pub(crate) source: MediaSource,
+}
+
+/// Payload for an image post whose image has been uploaded.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct PayloadUploadedImage {
+    kind: &'static str,
+    url: String,
+}
+
+impl PayloadUploadedImage {
+    pub(crate) fn new(url: String) -> Self {
+        Self { kind: "image", url }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MediaSource;
+
+    #[test]
+    fn test_mime_type() {
+        assert_eq!(
+            MediaSource::path("/tmp/cats/Cat.JPG").mime_type(),
+            Some("image/jpeg")
+        );
+        assert_eq!(
+            MediaSource::path("/tmp/cats/Cat.JPG").file_name(),
+            "Cat.JPG"
+        );
+        assert_eq!(
+            MediaSource::bytes("clip.mp4", vec![0]).mime_type(),
+            Some("video/mp4")
+        );
+        assert_eq!(MediaSource::bytes("notes.txt", vec![0]).mime_type(), None);
+        assert_eq!(MediaSource::bytes("noextension", vec![0]).mime_type(), None);
+    }
+}
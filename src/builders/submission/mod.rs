@@ -1,10 +1,19 @@
 use serde::{ser::SerializeStruct, Serialize};
 
+use crate::api::submission::DiscussionType;
+
+mod gallery;
+pub use gallery::{GalleryItem, GalleryItemError, PayloadGallery, MAX_GALLERY_CAPTION_LENGTH};
+
+mod media;
+pub(crate) use media::PayloadUploadedImage;
+pub use media::{MediaSource, PayloadImage};
+
 /// Payload for a text-only post
 #[derive(Debug, Clone, Serialize)]
 pub struct PayloadSelfText {
     kind: &'static str,
-    text: String,
+    pub(crate) text: String,
 }
 
 /// Payload for a rich text post
@@ -18,31 +27,45 @@ pub struct PayloadRichText {
 #[derive(Debug, Clone, Serialize)]
 pub struct PayloadLink {
     kind: &'static str,
-    url: String,
+    pub(crate) url: String,
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     resubmit: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<String>,
+    pub(crate) text: Option<String>,
+}
+
+/// The form sent to `api/submit`.
+#[derive(Serialize)]
+pub(crate) struct SubmitRequest<'a, Kind> {
+    pub(crate) sr: &'a str,
+    #[serde(flatten)]
+    pub(crate) data: &'a SubmissionSubmitBuilder<Kind>,
 }
 
 /// A builder to gather the data to submit a post
 #[derive(Debug, Clone, Serialize)]
 pub struct SubmissionSubmitBuilder<Kind> {
-    title: String,
+    pub(crate) title: String,
     #[serde(rename = "sendreplies")]
     send_replies: bool,
     nsfw: bool,
     spoiler: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    flair_id: Option<String>,
+    pub(crate) flair_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    flair_text: Option<String>,
+    pub(crate) flair_text: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     collection_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    discussion_type: Option<String>,
+    discussion_type: Option<DiscussionType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     draft_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_tz: Option<String>,
     /// The submission kind
     #[serde(flatten)]
     pub kind: Kind,
@@ -63,10 +86,34 @@ impl<Kind> SubmissionSubmitBuilder<Kind> {
             collection_id: None,
             discussion_type: None,
             draft_id: None,
+            event_start: None,
+            event_end: None,
+            event_tz: None,
             api_type: "json",
             validate_on_submit: false,
         }
     }
+
+    /// Copies this builder with a different kind of payload.
+    pub(crate) fn with_kind<New>(&self, kind: New) -> SubmissionSubmitBuilder<New> {
+        SubmissionSubmitBuilder {
+            title: self.title.clone(),
+            send_replies: self.send_replies,
+            nsfw: self.nsfw,
+            spoiler: self.spoiler,
+            flair_id: self.flair_id.clone(),
+            flair_text: self.flair_text.clone(),
+            collection_id: self.collection_id.clone(),
+            discussion_type: self.discussion_type.clone(),
+            draft_id: self.draft_id.clone(),
+            event_start: self.event_start.clone(),
+            event_end: self.event_end.clone(),
+            event_tz: self.event_tz.clone(),
+            kind,
+            api_type: self.api_type,
+            validate_on_submit: self.validate_on_submit,
+        }
+    }
 }
 
 impl SubmissionSubmitBuilder<PayloadSelfText> {
@@ -122,6 +169,27 @@ impl SubmissionSubmitBuilder<PayloadLink> {
     }
 }
 
+impl SubmissionSubmitBuilder<PayloadImage> {
+    /// Creates a submission builder for an image post.
+    ///
+    /// The image is uploaded when the post is submitted with
+    /// [`AuthedClient::submit_image_lazy`](crate::client::AuthedClient::submit_image_lazy).
+    pub fn image(title: impl Into<String>, image: MediaSource) -> Self {
+        Self::new(title, PayloadImage { source: image })
+    }
+}
+
+impl SubmissionSubmitBuilder<PayloadGallery> {
+    /// Creates a submission builder for a gallery post, from images uploaded with
+    /// [`AuthedClient::upload_media`](crate::client::AuthedClient::upload_media).
+    ///
+    /// The gallery is posted with
+    /// [`AuthedClient::submit_gallery`](crate::client::AuthedClient::submit_gallery).
+    pub fn gallery(title: impl Into<String>, items: Vec<GalleryItem>) -> Self {
+        Self::new(title, PayloadGallery { items })
+    }
+}
+
 impl<Kind> SubmissionSubmitBuilder<Kind> {
     /// Whether comments to the post should be sent to your inbox as messages.
     /// Defaults to `true`
@@ -155,6 +223,28 @@ impl<Kind> SubmissionSubmitBuilder<Kind> {
         self.flair_text = Some(flair_text.into());
         self
     }
+
+    /// Makes the post a live chat, where comments are shown as a chat.
+    pub fn with_live_chat(mut self) -> Self {
+        self.discussion_type = Some(DiscussionType::Chat);
+        self
+    }
+
+    /// Makes the post an event, such as an AMA, running from `start` to `end`.
+    ///
+    /// The times are local to the time zone `tz`, formatted as `YYYY-MM-DDTHH:MM:SS`, and `tz`
+    /// is an IANA time zone name such as `America/New_York`.
+    pub fn with_event(
+        mut self,
+        start: impl Into<String>,
+        end: impl Into<String>,
+        tz: impl Into<String>,
+    ) -> Self {
+        self.event_start = Some(start.into());
+        self.event_end = Some(end.into());
+        self.event_tz = Some(tz.into());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -171,6 +261,50 @@ mod tests {
         );
     }
     #[test]
+    pub fn test_live_chat_serialize() {
+        let builder = super::SubmissionSubmitBuilder::text("Game thread", "")
+            .with_send_replies(false)
+            .with_live_chat();
+
+        let value = serde_json::to_string(&builder).unwrap();
+        assert_eq!(
+            value,
+            r#"{"title":"Game thread","sendreplies":false,"nsfw":false,"spoiler":false,"discussion_type":"CHAT","kind":"self","text":"","api_type":"json","validate_on_submit":false}"#,
+        );
+    }
+    #[test]
+    pub fn test_event_serialize() {
+        let builder = super::SubmissionSubmitBuilder::text("AMA", "Ask away").with_event(
+            "2024-05-01T18:00:00",
+            "2024-05-01T20:00:00",
+            "Europe/London",
+        );
+
+        let value = serde_urlencoded::to_string(&builder).unwrap();
+        assert_eq!(
+            value,
+            "title=AMA&sendreplies=true&nsfw=false&spoiler=false&event_start=2024-05-01T18%3A00%3A00&event_end=2024-05-01T20%3A00%3A00&event_tz=Europe%2FLondon&kind=self&text=Ask+away&api_type=json&validate_on_submit=false",
+        );
+    }
+    #[test]
+    pub fn test_gallery_serialize() {
+        let items = vec![
+            super::GalleryItem::new("abc")
+                .with_caption("A cat")
+                .unwrap(),
+            super::GalleryItem::new("def")
+                .with_outbound_url("https://example.com")
+                .unwrap(),
+        ];
+        let builder = super::SubmissionSubmitBuilder::gallery("Cats", items);
+
+        let value = serde_json::to_string(&builder).unwrap();
+        assert_eq!(
+            value,
+            r#"{"title":"Cats","sendreplies":true,"nsfw":false,"spoiler":false,"items":[{"media_id":"abc","caption":"A cat","outbound_url":""},{"media_id":"def","caption":"","outbound_url":"https://example.com"}],"api_type":"json","validate_on_submit":false}"#,
+        );
+    }
+    #[test]
     pub fn test_url_serialize() {
         let builder = super::SubmissionSubmitBuilder::link("Another test", "https://example.com")
             .with_send_replies(false)
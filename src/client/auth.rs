@@ -1,31 +1,49 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
 
 use reqwest::header::HeaderValue;
 use reqwest::Method;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::api::comment::article::MoreChildrenData;
 use crate::api::comment::APICreatedComments;
-use crate::api::live::LiveThreadData;
-use crate::api::me::MeData;
-use crate::api::response::{BasicThing, LazyThingCreatedData, MultipleBasicThingsData};
+use crate::api::edited::{EditedItem, EditedItems};
+use crate::api::live::{LiveThreadData, LiveUpdates};
+use crate::api::me::{MeData, PrefsData, UnreadCounts};
+use crate::api::media::{MediaAssetLease, MediaSubmitted, UploadedMedia};
+use crate::api::modmail::{ModmailConversationResponse, ModmailConversations};
+use crate::api::multi::MultiList;
+use crate::api::response::{BasicThing, MultipleBasicThingsData, PostResponse};
+use crate::api::saved::{SavedCategories, SavedCategory};
+use crate::api::submission::{SubmittedGallery, SubmittedPost};
 use crate::api::subreddit::SubredditsData;
-use crate::api::{APIInbox, APISaved, Friend, ThingFullname};
+use crate::api::{APIInbox, APISaved, Friend, ModPermission, ThingFullname};
+use crate::builders::ban::BanOptions;
+use crate::builders::flair::{FlairTextConstraints, FlairTextError};
 use crate::builders::form::FormBuilder;
-use crate::builders::submission::SubmissionSubmitBuilder;
-use crate::client::{inner::ClientInner, req::*};
+use crate::builders::submission::{
+    MediaSource, PayloadGallery, PayloadImage, PayloadUploadedImage, SubmissionSubmitBuilder,
+    SubmitRequest,
+};
+use crate::client::{inner::ClientInner, req::*, Scope};
 use crate::models::inbox::Inbox;
-use crate::models::live::LiveThread;
+use crate::models::live::{LiveThread, LiveUpdate};
+use crate::models::me::Me;
+use crate::models::modmail::ModmailConversation;
+use crate::models::multi::Multireddit;
 use crate::models::{
-    CreatedComment, CreatedCommentWithLinkInfo, Distinguish, FromClientAndData, Listing, Message,
-    Saved,
+    ArticleCommentOrMore, CreatedComment, CreatedCommentWithLinkInfo, Distinguish, Edited,
+    FromClientAndData, Listing, Message, Saved,
 };
+use crate::util::error::RouxErrorKind;
 use crate::util::{maybe_async_handler, FeedOption, RouxError};
 use crate::Config;
 
 use super::endpoint::EndpointBuilder;
 use super::inner::ExecuteError;
 use super::traits::RedditClient;
+use super::FrontPage;
 
 type ListSaved = Listing<Saved<AuthedClient>>;
 
@@ -54,7 +72,10 @@ impl AuthClientInner {
         endpoint: &EndpointBuilder,
     ) -> RequestBuilder {
         let builder = self.base.request(method, endpoint);
-        let token = self.access_token.read().unwrap();
+        let token = self
+            .access_token
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
         let value: &HeaderValue = &token;
         builder.header(reqwest::header::AUTHORIZATION, value)
     }
@@ -72,73 +93,365 @@ impl AuthedClient {
         Ok(Self(Arc::new(inner)))
     }
 
+    /// The access token currently used to authenticate requests.
+    pub fn access_token(&self) -> String {
+        let header = self
+            .0
+            .access_token
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let value = header.to_str().unwrap_or_default();
+        value.trim_start_matches("Bearer ").to_owned()
+    }
+
+    /// The scopes granted to the current access token.
+    ///
+    /// This is `None` if the token was restored from a [`TokenStore`](crate::client::TokenStore),
+    /// as Reddit only reports the granted scopes when a token is issued.
+    pub fn granted_scopes(&self) -> Option<Vec<Scope>> {
+        self.0.base.granted_scopes()
+    }
+
+    pub(crate) fn set_granted_scopes(&self, scopes: Option<Vec<Scope>>) {
+        self.0.base.set_granted_scopes(scopes);
+    }
+
+    /// Creates a front page helper, to get the logged in user's home feed.
+    pub fn front_page(&self) -> FrontPage {
+        FrontPage::new(self.clone())
+    }
+
+    /// Get the logged in user's multireddits, including private ones.
+    #[maybe_async::maybe_async]
+    pub async fn my_multireddits(&self) -> Result<Vec<Multireddit<Self>>, RouxError> {
+        let multis: MultiList = self.get_json("api/multi/mine").await?;
+
+        Ok(multis
+            .into_iter()
+            .map(|multi| Multireddit::new(self.clone(), multi.data))
+            .collect())
+    }
+
     /// Get me
     #[maybe_async::maybe_async]
     pub async fn me(&self) -> Result<MeData, RouxError> {
         self.get_json("api/v1/me").await
     }
 
+    /// Get the logged in account, with helpers for its inbox, preferences and subreddits.
+    #[maybe_async::maybe_async]
+    pub async fn me_model(&self) -> Result<Me<Self>, RouxError> {
+        let data = self.me().await?;
+        Ok(Me::new(self.clone(), data))
+    }
+
+    /// Get the logged in account's preferences.
+    #[maybe_async::maybe_async]
+    pub async fn prefs(&self) -> Result<PrefsData, RouxError> {
+        self.get_json("api/v1/me/prefs").await
+    }
+
+    /// Get the number of unread messages and whether there is unread (mod) mail,
+    /// without fetching the inbox itself.
+    #[maybe_async::maybe_async]
+    pub async fn unread_counts(&self) -> Result<UnreadCounts, RouxError> {
+        let me = self.me().await?;
+        Ok(UnreadCounts::from(&me))
+    }
+
     /// Submits a new post to the subreddit from the builder
     ///
     /// Note that `subreddit_name` is the display name of the subreddit without the `/r/` prefix, NOT the "full name" (e.g. `t5_abcde`)
+    ///
+    /// This fetches the new post afterwards; use [`AuthedClient::submit_lazy`] if only its URL
+    /// or fullname is needed.
     #[maybe_async::maybe_async]
     pub async fn submit<Kind: Serialize>(
         &self,
         subreddit_name: &str,
         submission: &SubmissionSubmitBuilder<Kind>,
     ) -> Result<crate::models::Submission<Self>, RouxError> {
-        #[derive(Serialize)]
-        struct SubmitRequest<'a, Kind> {
-            sr: &'a str,
-            #[serde(flatten)]
-            data: &'a SubmissionSubmitBuilder<Kind>,
-            api_type: &'static str,
-        }
+        let submitted = self.submit_lazy(subreddit_name, submission).await?;
+
+        let mut submissions = self.get_submissions(&[&submitted.name]).await?;
 
+        Ok(submissions.children.pop().unwrap())
+    }
+
+    /// Submits a new post to the subreddit from the builder, without fetching the new post.
+    ///
+    /// See [`AuthedClient::submit`].
+    #[maybe_async::maybe_async]
+    pub async fn submit_lazy<Kind: Serialize>(
+        &self,
+        subreddit_name: &str,
+        submission: &SubmissionSubmitBuilder<Kind>,
+    ) -> Result<SubmittedPost, RouxError> {
         let req = SubmitRequest {
             sr: subreddit_name,
             data: submission,
-            api_type: "json",
         };
 
         let endpoint = EndpointBuilder::new("api/submit");
 
-        let parsed: LazyThingCreatedData = self.post_with_response(endpoint, &req).await?;
+        self.post_with_response(endpoint, &req).await
+    }
+
+    /// Submits a gallery of images as a new post to the subreddit.
+    ///
+    /// The images must already be uploaded, see [`SubmissionSubmitBuilder::gallery`].
+    #[maybe_async::maybe_async]
+    pub async fn submit_gallery(
+        &self,
+        subreddit_name: &str,
+        submission: &SubmissionSubmitBuilder<PayloadGallery>,
+    ) -> Result<SubmittedGallery, RouxError> {
+        let req = SubmitRequest {
+            sr: subreddit_name,
+            data: submission,
+        };
+
+        let response: PostResponse<SubmittedGallery> = self
+            .send_json(Method::POST, "api/submit_gallery_post", &req)
+            .await?;
+        if !response.json.errors.is_empty() {
+            return Err(RouxError::reddit_error(response.json.errors));
+        }
 
-        let mut submissions = self.get_submissions(&[&parsed.name]).await?;
+        response.json.data.ok_or_else(|| {
+            RouxError::unexpected_response("api/submit_gallery_post returned no post")
+        })
+    }
 
-        Ok(submissions.children.pop().unwrap())
+    /// Uploads an image or video to Reddit, for use in media posts.
+    #[maybe_async::maybe_async]
+    pub async fn upload_media(&self, source: &MediaSource) -> Result<UploadedMedia, RouxError> {
+        let file_name = source.file_name().into_owned();
+        let Some(mime_type) = source.mime_type() else {
+            return Err(RouxErrorKind::UnsupportedMediaType(file_name).into());
+        };
+        let bytes = source.read().await?.into_owned();
+
+        let form = FormBuilder::new()
+            .with("filepath", file_name.as_str())
+            .with("mimetype", mime_type);
+        let mut endpoint = EndpointBuilder::new("api/media/asset.json");
+        endpoint.with_dot_json = false;
+        let lease: MediaAssetLease = self.post_with_response_raw(endpoint, &form).await?;
+
+        let action = format!("https:{}", lease.args.action);
+        let mut key = None;
+        let mut upload = multipart::Form::new();
+        for field in lease.args.fields {
+            if field.name == "key" {
+                key = Some(field.value.clone());
+            }
+            upload = upload.text(field.name, field.value);
+        }
+        let file = multipart::Part::bytes(bytes)
+            .file_name(file_name)
+            .mime_str(mime_type)?;
+        self.0
+            .base
+            .upload(&action, upload.part("file", file))
+            .await?;
+
+        Ok(UploadedMedia {
+            asset_id: lease.asset.asset_id,
+            url: format!("{action}/{}", key.unwrap_or_default()),
+            websocket_url: lease.asset.websocket_url,
+        })
+    }
+
+    /// Uploads the image and submits it as a new post to the subreddit, without waiting for the
+    /// post to be created.
+    ///
+    /// Reddit creates the post once it has processed the image, after which it is listed in the
+    /// account's submissions, see [`MediaSubmitted`].
+    #[maybe_async::maybe_async]
+    pub async fn submit_image_lazy(
+        &self,
+        subreddit_name: &str,
+        submission: &SubmissionSubmitBuilder<PayloadImage>,
+    ) -> Result<MediaSubmitted, RouxError> {
+        let media = self.upload_media(&submission.kind.source).await?;
+        let submission = submission.with_kind(PayloadUploadedImage::new(media.url));
+
+        let req = SubmitRequest {
+            sr: subreddit_name,
+            data: &submission,
+        };
+
+        self.post_with_response("api/submit", &req).await
+    }
+
+    /// Adds a user to a subreddit with the given relationship, e.g. banning them or inviting them to moderate.
+    ///
+    /// Errors reported by Reddit (e.g. `USER_DOESNT_EXIST`) are returned as
+    /// [`RouxErrorKind::RedditError`](crate::util::error::RouxErrorKind::RedditError).
+    #[maybe_async::maybe_async]
+    pub async fn add_subreddit_relationship(
+        &self,
+        username: &str,
+        relationship: SubredditRelationship,
+        sub: &str,
+    ) -> Result<(), RouxError> {
+        self.subreddit_friend("friend", username, relationship.as_str(), sub)
+            .await
+    }
+
+    /// Removes a user's relationship with a subreddit, e.g. unbanning them or removing them as a moderator.
+    ///
+    /// Errors reported by Reddit are returned as
+    /// [`RouxErrorKind::RedditError`](crate::util::error::RouxErrorKind::RedditError).
+    #[maybe_async::maybe_async]
+    pub async fn remove_subreddit_relationship(
+        &self,
+        username: &str,
+        relationship: SubredditRelationship,
+        sub: &str,
+    ) -> Result<(), RouxError> {
+        self.subreddit_friend("unfriend", username, relationship.as_str(), sub)
+            .await
+    }
+
+    /// Bans a user from a subreddit.
+    ///
+    /// Errors reported by Reddit are returned as
+    /// [`RouxErrorKind::RedditError`](crate::util::error::RouxErrorKind::RedditError).
+    #[maybe_async::maybe_async]
+    pub async fn ban_user(
+        &self,
+        username: &str,
+        sub: &str,
+        options: &BanOptions,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("name", username)
+            .with("type", SubredditRelationship::Banned.as_str());
+
+        self.subreddit_friend_form("friend", options.add_to(form), sub)
+            .await
+    }
+
+    /// Unbans a user from a subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn unban_user(&self, username: &str, sub: &str) -> Result<(), RouxError> {
+        self.remove_subreddit_relationship(username, SubredditRelationship::Banned, sub)
+            .await
+    }
+
+    /// Invites a user to moderate a subreddit, with only the given permissions.
+    ///
+    /// Errors reported by Reddit are returned as
+    /// [`RouxErrorKind::RedditError`](crate::util::error::RouxErrorKind::RedditError).
+    #[maybe_async::maybe_async]
+    pub async fn invite_moderator(
+        &self,
+        username: &str,
+        sub: &str,
+        permissions: &[ModPermission],
+    ) -> Result<(), RouxError> {
+        let permissions = ModPermission::to_param(permissions);
+        let form = FormBuilder::new()
+            .with("name", username)
+            .with("type", SubredditRelationship::ModeratorInvite.as_str())
+            .with("permissions", &permissions);
+
+        self.subreddit_friend_form("friend", form, sub).await
+    }
+
+    /// Withdraws a pending invitation for a user to moderate a subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn uninvite_moderator(&self, username: &str, sub: &str) -> Result<(), RouxError> {
+        self.remove_subreddit_relationship(username, SubredditRelationship::ModeratorInvite, sub)
+            .await
+    }
+
+    /// Removes a moderator from a subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn remove_moderator(&self, username: &str, sub: &str) -> Result<(), RouxError> {
+        self.remove_subreddit_relationship(username, SubredditRelationship::Moderator, sub)
+            .await
+    }
+
+    /// Replaces the permissions of a moderator of a subreddit.
+    ///
+    /// Set `invited` to change the permissions of a pending invitation instead.
+    ///
+    /// Errors reported by Reddit are returned as
+    /// [`RouxErrorKind::RedditError`](crate::util::error::RouxErrorKind::RedditError).
+    #[maybe_async::maybe_async]
+    pub async fn set_moderator_permissions(
+        &self,
+        username: &str,
+        sub: &str,
+        permissions: &[ModPermission],
+        invited: bool,
+    ) -> Result<(), RouxError> {
+        let relationship = if invited {
+            SubredditRelationship::ModeratorInvite
+        } else {
+            SubredditRelationship::Moderator
+        };
+        let permissions = ModPermission::to_param(permissions);
+        let form = FormBuilder::new()
+            .with("name", username)
+            .with("type", relationship.as_str())
+            .with("permissions", &permissions);
+
+        self.subreddit_friend_form("setpermissions", form, sub)
+            .await
     }
 
     /// Adds a friend to a subreddit with the specified type
+    #[deprecated(note = "use `add_subreddit_relationship` instead")]
     #[maybe_async::maybe_async]
     pub async fn add_subreddit_friend(
         &self,
         username: &str,
         typ: &str,
         sub: &str,
-    ) -> Result<bool, RouxError> {
-        let form = FormBuilder::new().with("name", username).with("type", typ);
-        let resp: Friend = self
-            .post_with_response_raw(format!("r/{}/api/friend", sub).as_str(), &form)
-            .await?;
-
-        Ok(resp.success)
+    ) -> Result<(), RouxError> {
+        self.subreddit_friend("friend", username, typ, sub).await
     }
 
     /// Removes a friend to a subreddit with the specified type
+    #[deprecated(note = "use `remove_subreddit_relationship` instead")]
     #[maybe_async::maybe_async]
     pub async fn remove_subreddit_friend(
         &self,
         username: &str,
         typ: &str,
         sub: &str,
-    ) -> Result<bool, RouxError> {
+    ) -> Result<(), RouxError> {
+        self.subreddit_friend("unfriend", username, typ, sub).await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn subreddit_friend(
+        &self,
+        action: &str,
+        username: &str,
+        typ: &str,
+        sub: &str,
+    ) -> Result<(), RouxError> {
         let form = FormBuilder::new().with("name", username).with("type", typ);
+        self.subreddit_friend_form(action, form, sub).await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn subreddit_friend_form(
+        &self,
+        action: &str,
+        form: FormBuilder<'_>,
+        sub: &str,
+    ) -> Result<(), RouxError> {
         let resp: Friend = self
-            .post_with_response_raw(format!("r/{}/api/unfriend", sub).as_str(), &form)
+            .post_with_response_raw(format!("r/{sub}/api/{action}").as_str(), &form)
             .await?;
-        Ok(resp.success)
+
+        resp.into_result()
     }
 
     /// Fetches subreddits the current account has the relationship with
@@ -157,6 +470,68 @@ impl AuthedClient {
         self.get_json(endpoint).await
     }
 
+    /// Subscribes to the subreddits, given by their fullnames (e.g. `t5_2qh1i`).
+    ///
+    /// New accounts are automatically subscribed to a set of default subreddits on their first
+    /// subscription; set `skip_initial_defaults` to prevent that.
+    #[maybe_async::maybe_async]
+    pub async fn subscribe(
+        &self,
+        subreddits: &[&ThingFullname],
+        skip_initial_defaults: bool,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("action", "sub")
+            .with_joined("sr", subreddits.iter().map(|name| name.full()))
+            .with_bool("skip_initial_defaults", skip_initial_defaults);
+        self.post("api/subscribe", &form).await?;
+        Ok(())
+    }
+
+    /// Unsubscribes from the subreddits, given by their fullnames (e.g. `t5_2qh1i`).
+    #[maybe_async::maybe_async]
+    pub async fn unsubscribe(&self, subreddits: &[&ThingFullname]) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("action", "unsub")
+            .with_joined("sr", subreddits.iter().map(|name| name.full()));
+        self.post("api/subscribe", &form).await?;
+        Ok(())
+    }
+
+    /// Loads the comments hidden behind a "load more comments" marker on the submission `link`.
+    ///
+    /// Reddit accepts at most 100 children at once, so larger sets are loaded in several requests.
+    /// See also [`MoreComments::expand`](crate::models::MoreComments::expand).
+    #[maybe_async::maybe_async]
+    pub async fn more_children(
+        &self,
+        link: &ThingFullname,
+        children: &[&str],
+    ) -> Result<Vec<ArticleCommentOrMore<Self>>, RouxError> {
+        let mut comments = Vec::with_capacity(children.len());
+
+        for chunk in children.chunks(100) {
+            let endpoint = EndpointBuilder::new("api/morechildren")
+                .query("api_type", "json")
+                .query("link_id", link.full())
+                .query_joined("children", chunk);
+
+            let response: PostResponse<MoreChildrenData> = self.get_json(endpoint).await?;
+            if !response.json.errors.is_empty() {
+                return Err(RouxError::reddit_error(response.json.errors));
+            }
+
+            let things = response.json.data.map(|d| d.things).unwrap_or_default();
+            comments.extend(
+                things
+                    .into_iter()
+                    .map(|thing| ArticleCommentOrMore::new(self.clone(), thing)),
+            );
+        }
+
+        Ok(comments)
+    }
+
     /// Compose message
     #[maybe_async::maybe_async]
     pub async fn compose_message(
@@ -186,17 +561,18 @@ impl AuthedClient {
 
     #[maybe_async::maybe_async]
     async fn _saved(&self, ty: &str, options: Option<FeedOption>) -> Result<ListSaved, RouxError> {
-        let mut url = EndpointBuilder::new(format!(
+        let source = EndpointBuilder::new(format!(
             "user/{}/{ty}",
             self.0.base.config.username.as_ref().unwrap()
         ));
+        let mut url = source.clone();
 
-        if let Some(options) = options {
+        if let Some(options) = options.clone() {
             options.build_url(&mut url);
         }
 
         let response: APISaved = self.get_json(url).await?;
-        let conv = Listing::new(response, self.clone());
+        let conv = Listing::new(response, self.clone()).with_source(source, options);
 
         Ok(conv)
     }
@@ -207,17 +583,18 @@ impl AuthedClient {
         &self,
         options: Option<FeedOption>,
     ) -> Result<Listing<CreatedCommentWithLinkInfo<Self>>, RouxError> {
-        let mut url = EndpointBuilder::new(format!(
+        let source = EndpointBuilder::new(format!(
             "user/{}/comments",
             self.0.base.config.username.as_ref().unwrap()
         ));
+        let mut url = source.clone();
 
-        if let Some(options) = options {
+        if let Some(options) = options.clone() {
             options.build_url(&mut url);
         }
 
         let response: APICreatedComments = self.get_json(url).await?;
-        let conv = Listing::new(response, self.clone());
+        let conv = Listing::new(response, self.clone()).with_source(source, options);
         Ok(conv)
     }
 
@@ -291,6 +668,56 @@ impl AuthedClient {
         self._comment(text, parent).await
     }
 
+    /// Posts several comments one after another, spaced out to stay within Reddit's comment
+    /// ratelimit, which is much stricter than the general API ratelimit.
+    ///
+    /// Comments rejected with a `RATELIMIT` error are retried after the wait Reddit asks for,
+    /// within the limits of `spacing`. A failed comment does not stop the rest from being posted.
+    #[maybe_async::maybe_async]
+    pub async fn comment_many<'a, I>(
+        &self,
+        items: I,
+        spacing: &CommentSpacing,
+    ) -> Vec<CommentBatchResult<Self>>
+    where
+        I: IntoIterator<Item = (&'a ThingFullname, &'a str)>,
+    {
+        let mut results = Vec::new();
+
+        for (index, (parent, text)) in items.into_iter().enumerate() {
+            if index > 0 && !spacing.interval.is_zero() {
+                sleep(spacing.interval).await;
+            }
+
+            let mut attempts = 0;
+            let mut waited = Duration::ZERO;
+            let result = loop {
+                attempts += 1;
+                let error = match self.comment(text, parent).await {
+                    Ok(comment) => break Ok(comment),
+                    Err(error) => error,
+                };
+
+                match error.retry_after() {
+                    Some(wait) if attempts <= spacing.max_retries && wait <= spacing.max_wait => {
+                        sleep(wait).await;
+                        waited += wait;
+                    }
+                    _ => break Err(error),
+                }
+            };
+
+            results.push(CommentBatchResult {
+                parent: parent.clone(),
+                result,
+                attempts,
+                waited,
+            });
+        }
+
+        results
+    }
+
     /// Adds a reply to an inbox message.
     #[maybe_async::maybe_async]
     pub async fn reply(
@@ -301,17 +728,34 @@ impl AuthedClient {
         self._comment(text, parent).await
     }
 
-    /// Edit a 'thing'
+    /// Edits the text of a comment or self post, returning it as updated by Reddit.
     #[maybe_async::maybe_async]
     pub async fn edit(
         &self,
         text: &str,
         parent: &ThingFullname,
-    ) -> Result<super::req::Response, RouxError> {
+    ) -> Result<Edited<Self>, RouxError> {
+        let item = self.edit_raw(text, parent).await?;
+        Ok(Edited::new(self.clone(), item))
+    }
+
+    #[maybe_async::maybe_async]
+    pub(crate) async fn edit_raw(
+        &self,
+        text: &str,
+        parent: &ThingFullname,
+    ) -> Result<EditedItem, RouxError> {
         let form = FormBuilder::new()
             .with("text", text)
             .with("thing_id", parent.full());
-        self.post("api/editusertext", &form).await
+        let response: EditedItems = self.post_with_response("api/editusertext", &form).await?;
+
+        match response.things.into_iter().next() {
+            Some(item) => Ok(item),
+            None => Err(RouxError::unexpected_response(
+                "api/editusertext returned no edited item",
+            )),
+        }
     }
 
     /// Removes a 'thing', potentially for spam.
@@ -326,6 +770,85 @@ impl AuthedClient {
         Ok(())
     }
 
+    /// Approves a submission or comment, removing it from the moderation queues.
+    ///
+    /// This requires moderation permissions and will error without it.
+    #[maybe_async::maybe_async]
+    pub async fn approve(&self, thing_id: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", thing_id.full());
+        self.post("api/approve", &form).await?;
+        Ok(())
+    }
+
+    /// Stops a submission or comment's reports from being shown in the moderation queues.
+    ///
+    /// This requires moderation permissions and will error without it.
+    #[maybe_async::maybe_async]
+    pub async fn ignore_reports(&self, thing_id: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", thing_id.full());
+        self.post("api/ignore_reports", &form).await?;
+        Ok(())
+    }
+
+    /// Shows a submission or comment's reports in the moderation queues again.
+    ///
+    /// This requires moderation permissions and will error without it.
+    #[maybe_async::maybe_async]
+    pub async fn unignore_reports(&self, thing_id: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", thing_id.full());
+        self.post("api/unignore_reports", &form).await?;
+        Ok(())
+    }
+
+    /// Votes on a submission or comment.
+    ///
+    /// Reddit's API rules require that votes are cast by a human, not automated by a bot.
+    #[maybe_async::maybe_async]
+    pub async fn vote(
+        &self,
+        fullname: &ThingFullname,
+        dir: VoteDirection,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("id", fullname.full())
+            .with("dir", dir.as_str());
+        self.post("api/vote", &form).await?;
+        Ok(())
+    }
+
+    /// Saves a submission or comment, optionally into a category.
+    ///
+    /// Categories require Reddit Premium; see [`AuthedClient::saved_categories`].
+    #[maybe_async::maybe_async]
+    pub async fn save(
+        &self,
+        fullname: &ThingFullname,
+        category: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("id", fullname.full())
+            .with_opt("category", category);
+        self.post("api/save", &form).await?;
+        Ok(())
+    }
+
+    /// Unsaves a submission or comment.
+    #[maybe_async::maybe_async]
+    pub async fn unsave(&self, fullname: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", fullname.full());
+        self.post("api/unsave", &form).await?;
+        Ok(())
+    }
+
+    /// Get the categories that saved items can be sorted into.
+    ///
+    /// This is empty unless the account has Reddit Premium.
+    #[maybe_async::maybe_async]
+    pub async fn saved_categories(&self) -> Result<Vec<SavedCategory>, RouxError> {
+        let response: SavedCategories = self.get_json("api/saved_categories").await?;
+        Ok(response.categories)
+    }
+
     /// Locks a submission or comment.
     #[maybe_async::maybe_async]
     pub async fn lock(&self, fullname: &ThingFullname) -> Result<(), RouxError> {
@@ -342,6 +865,53 @@ impl AuthedClient {
         Ok(())
     }
 
+    /// Follows or unfollows a submission, which sends updates about it (e.g. popular comments)
+    /// to the inbox.
+    #[maybe_async::maybe_async]
+    pub async fn follow_post(
+        &self,
+        fullname: &ThingFullname,
+        follow: bool,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("fullname", fullname.full())
+            .with_bool("follow", follow);
+        self.post("api/follow_post", &form).await?;
+        Ok(())
+    }
+
+    /// Marks a submission as NSFW.
+    #[maybe_async::maybe_async]
+    pub async fn mark_nsfw(&self, fullname: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", fullname.full());
+        self.post("api/marknsfw", &form).await?;
+        Ok(())
+    }
+
+    /// Removes the NSFW mark from a submission.
+    #[maybe_async::maybe_async]
+    pub async fn unmark_nsfw(&self, fullname: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", fullname.full());
+        self.post("api/unmarknsfw", &form).await?;
+        Ok(())
+    }
+
+    /// Marks a submission as a spoiler.
+    #[maybe_async::maybe_async]
+    pub async fn spoiler(&self, fullname: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", fullname.full());
+        self.post("api/spoiler", &form).await?;
+        Ok(())
+    }
+
+    /// Removes the spoiler mark from a submission.
+    #[maybe_async::maybe_async]
+    pub async fn unspoiler(&self, fullname: &ThingFullname) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", fullname.full());
+        self.post("api/unspoiler", &form).await?;
+        Ok(())
+    }
+
     /// Distinguishes a 'thing'.
     #[maybe_async::maybe_async]
     pub async fn distinguish(
@@ -397,24 +967,16 @@ impl AuthedClient {
         state: bool,
         slot: crate::models::SubmissionStickySlot,
     ) -> Result<(), RouxError> {
-        #[derive(Debug, Serialize)]
-        struct Body<'a> {
-            id: &'a str,
-            state: bool,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            num: Option<u8>,
-        }
-
-        let body = Body {
-            id: post.full(),
-            state,
-            num: match (state, slot) {
-                (true, crate::models::SubmissionStickySlot::Top) => Some(1),
-                _ => None,
-            },
+        let num = match (state, slot) {
+            (true, crate::models::SubmissionStickySlot::Top) => Some("1"),
+            _ => None,
         };
+        let form = FormBuilder::new()
+            .with("id", post.full())
+            .with_bool("state", state)
+            .with_opt("num", num);
 
-        self.post("api/set_subreddit_sticky", &body).await?;
+        self.post("api/set_subreddit_sticky", &form).await?;
         Ok(())
     }
 
@@ -485,6 +1047,57 @@ impl AuthedClient {
         Ok(())
     }
 
+    /// Gets the most recent updates posted to the live thread.
+    ///
+    /// To get older updates, pass the `name` of the last update as the `after` option.
+    #[maybe_async::maybe_async]
+    pub async fn live_updates(
+        &self,
+        id: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Vec<LiveUpdate<Self>>, RouxError> {
+        let mut url = EndpointBuilder::new(format!("live/{id}"));
+        if let Some(options) = options {
+            options.build_url(&mut url);
+        }
+
+        let response: LiveUpdates = self.get_json(url).await?;
+        let updates = response
+            .data
+            .children
+            .into_iter()
+            .map(|thing| LiveUpdate::new(self.clone(), id, thing.data))
+            .collect();
+
+        Ok(updates)
+    }
+
+    /// Strikes an update in the live thread, marking it as incorrect without deleting it.
+    ///
+    /// `update` is the update's `name`, e.g. `LiveUpdate_...`.
+    #[maybe_async::maybe_async]
+    pub async fn strike_live_update(&self, id: &str, update: &str) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", update);
+
+        self.post(format!("/api/live/{id}/strike_update"), &form)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes an update from the live thread.
+    ///
+    /// `update` is the update's `name`, e.g. `LiveUpdate_...`.
+    #[maybe_async::maybe_async]
+    pub async fn delete_live_update(&self, id: &str, update: &str) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("id", update);
+
+        self.post(format!("/api/live/{id}/delete_update"), &form)
+            .await?;
+
+        Ok(())
+    }
+
     /// Closes a live thread, preventing further updates.
     #[maybe_async::maybe_async]
     pub async fn close_live_thread(&self, id: &str) -> Result<(), RouxError> {
@@ -496,6 +1109,147 @@ impl AuthedClient {
         Ok(())
     }
 
+    /// Lists modmail conversations in the given subreddits, which must be moderated by this user.
+    ///
+    /// Each conversation only includes its most recent message. To page through conversations, pass
+    /// the ID of the last conversation as the `after` option.
+    #[maybe_async::maybe_async]
+    pub async fn modmail_conversations(
+        &self,
+        subreddits: &[&str],
+        state: ModmailState,
+        sort: ModmailSort,
+        options: Option<FeedOption>,
+    ) -> Result<Vec<ModmailConversation<Self>>, RouxError> {
+        let mut url = EndpointBuilder::new("api/mod/conversations")
+            .query_joined("entity", subreddits)
+            .query("state", state.as_str())
+            .query("sort", sort.as_str());
+
+        if let Some(options) = options {
+            options.build_url(&mut url);
+        }
+
+        let mut response: ModmailConversations = self.get_json(url).await?;
+        let conversations = response
+            .conversation_ids
+            .iter()
+            .filter_map(|id| response.conversations.remove(id))
+            .map(|data| ModmailConversation::new(self.clone(), data, &mut response.messages))
+            .collect();
+
+        Ok(conversations)
+    }
+
+    /// Fetches a modmail conversation with all of its messages, without marking it as read.
+    #[maybe_async::maybe_async]
+    pub async fn modmail_conversation(
+        &self,
+        id: &str,
+    ) -> Result<ModmailConversation<Self>, RouxError> {
+        let url =
+            EndpointBuilder::new(format!("api/mod/conversations/{id}")).query("markRead", "false");
+
+        let mut response: ModmailConversationResponse = self.get_json(url).await?;
+        Ok(ModmailConversation::new(
+            self.clone(),
+            response.conversation,
+            &mut response.messages,
+        ))
+    }
+
+    /// Replies to a modmail conversation, returning the updated conversation.
+    ///
+    /// Internal replies are only visible to moderators; hidden replies are sent as the subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn reply_modmail(
+        &self,
+        id: &str,
+        body: &str,
+        is_internal: bool,
+        is_author_hidden: bool,
+    ) -> Result<ModmailConversation<Self>, RouxError> {
+        let form = FormBuilder::new()
+            .with("body", body)
+            .with_bool("isInternal", is_internal)
+            .with_bool("isAuthorHidden", is_author_hidden);
+
+        let mut response: ModmailConversationResponse = self
+            .post_with_response_raw(format!("api/mod/conversations/{id}"), &form)
+            .await?;
+        Ok(ModmailConversation::new(
+            self.clone(),
+            response.conversation,
+            &mut response.messages,
+        ))
+    }
+
+    /// Archives a modmail conversation.
+    #[maybe_async::maybe_async]
+    pub async fn archive_modmail(&self, id: &str) -> Result<(), RouxError> {
+        self.post(
+            format!("api/mod/conversations/{id}/archive"),
+            &FormBuilder::new(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Unarchives a modmail conversation.
+    #[maybe_async::maybe_async]
+    pub async fn unarchive_modmail(&self, id: &str) -> Result<(), RouxError> {
+        self.post(
+            format!("api/mod/conversations/{id}/unarchive"),
+            &FormBuilder::new(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Highlights a modmail conversation.
+    #[maybe_async::maybe_async]
+    pub async fn highlight_modmail(&self, id: &str) -> Result<(), RouxError> {
+        self.post(
+            format!("api/mod/conversations/{id}/highlight"),
+            &FormBuilder::new(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Removes the highlight from a modmail conversation.
+    #[maybe_async::maybe_async]
+    pub async fn unhighlight_modmail(&self, id: &str) -> Result<(), RouxError> {
+        self.delete(format!("api/mod/conversations/{id}/highlight"))
+            .await?;
+        Ok(())
+    }
+
+    /// Mutes the user a modmail conversation is with, preventing them from sending modmail to the
+    /// subreddit for the duration.
+    #[maybe_async::maybe_async]
+    pub async fn mute_modmail_user(
+        &self,
+        id: &str,
+        duration: ModmailMuteDuration,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("num_hours", duration.hours().to_string());
+        self.post(format!("api/mod/conversations/{id}/mute"), &form)
+            .await?;
+        Ok(())
+    }
+
+    /// Unmutes the user a modmail conversation is with.
+    #[maybe_async::maybe_async]
+    pub async fn unmute_modmail_user(&self, id: &str) -> Result<(), RouxError> {
+        self.post(
+            format!("api/mod/conversations/{id}/unmute"),
+            &FormBuilder::new(),
+        )
+        .await?;
+        Ok(())
+    }
+
     /// Adds a removal reason for the specified comment or submission.
     ///
     /// It must already be removed first.
@@ -526,13 +1280,7 @@ impl AuthedClient {
         };
 
         let json = serde_json::to_string(&request).expect("can serialize");
-
-        #[derive(Serialize)]
-        struct JsonForm {
-            json: String,
-        }
-
-        let form = JsonForm { json };
+        let form = FormBuilder::new().with("json", json);
 
         self.post("/api/v1/modactions/removal_reasons", &form)
             .await?;
@@ -545,7 +1293,11 @@ impl AuthedClient {
     pub async fn logout(self) -> Result<(), RouxError> {
         let url = EndpointBuilder::new("https://www.reddit.com/api/v1/revoke_token");
 
-        let read = self.0.access_token.read().unwrap();
+        let read = self
+            .0
+            .access_token
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
         let form = [("access_token", read.to_str().unwrap())];
 
         let response = self
@@ -577,7 +1329,7 @@ impl RedditClient for AuthedClient {
                         return Err(RouxError::credentials_not_set());
                     }
                     has_retried = true;
-                    let mut write = self.0.access_token.write().unwrap();
+                    let mut write = self.0.access_token.write().unwrap_or_else(PoisonError::into_inner);
                     let token = self.0.base.attempt_login().await?;
                     *write = form_auth_header(&token);
                 }
@@ -589,6 +1341,10 @@ impl RedditClient for AuthedClient {
     fn make_req(&self, method: Method, endpoint: &EndpointBuilder) -> RequestBuilder {
         self.0.request(method, endpoint)
     }
+
+    fn make_external_req(&self, method: Method, url: &str) -> RequestBuilder {
+        self.0.base.external_request(method, url)
+    }
 }
 
 /// The kind of relation with the subreddit
@@ -603,6 +1359,222 @@ pub enum SubRelation {
     Streams,
 }
 
+/// A relationship a user can have with a subreddit, used with
+/// [`AuthedClient::add_subreddit_relationship`] and [`AuthedClient::remove_subreddit_relationship`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubredditRelationship {
+    /// A moderator
+    Moderator,
+    /// A pending invitation to moderate
+    ModeratorInvite,
+    /// An approved user
+    Contributor,
+    /// A banned user
+    Banned,
+    /// A user muted from modmail
+    Muted,
+    /// A user banned from editing the wiki
+    WikiBanned,
+    /// An approved wiki editor
+    WikiContributor,
+}
+
+impl SubredditRelationship {
+    /// The `type` Reddit uses for this relationship.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubredditRelationship::Moderator => "moderator",
+            SubredditRelationship::ModeratorInvite => "moderator_invite",
+            SubredditRelationship::Contributor => "contributor",
+            SubredditRelationship::Banned => "banned",
+            SubredditRelationship::Muted => "muted",
+            SubredditRelationship::WikiBanned => "wikibanned",
+            SubredditRelationship::WikiContributor => "wikicontributor",
+        }
+    }
+}
+
+/// The direction of a vote, used with [`AuthedClient::vote`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteDirection {
+    /// An upvote
+    Up,
+    /// A downvote
+    Down,
+    /// Removes an existing vote
+    None,
+}
+
+impl VoteDirection {
+    /// The `dir` Reddit uses for this direction.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoteDirection::Up => "1",
+            VoteDirection::Down => "-1",
+            VoteDirection::None => "0",
+        }
+    }
+
+    /// The direction as the `likes` field represents it: `Some(true)` for an upvote,
+    /// `Some(false)` for a downvote and `None` for no vote.
+    pub fn likes(&self) -> Option<bool> {
+        match self {
+            VoteDirection::Up => Some(true),
+            VoteDirection::Down => Some(false),
+            VoteDirection::None => None,
+        }
+    }
+
+    /// The direction represented by a `likes` field.
+    pub fn from_likes(likes: Option<bool>) -> Self {
+        match likes {
+            Some(true) => VoteDirection::Up,
+            Some(false) => VoteDirection::Down,
+            None => VoteDirection::None,
+        }
+    }
+
+    fn value(&self) -> i32 {
+        match self {
+            VoteDirection::Up => 1,
+            VoteDirection::Down => -1,
+            VoteDirection::None => 0,
+        }
+    }
+
+    /// How `ups` and `score` change when a vote changes from `self` to `new`.
+    pub(crate) fn deltas_to(&self, new: VoteDirection) -> (i32, i32) {
+        let ups = i32::from(new == VoteDirection::Up) - i32::from(*self == VoteDirection::Up);
+        (ups, new.value() - self.value())
+    }
+}
+
+/// Which modmail conversations to list, used with [`AuthedClient::modmail_conversations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModmailState {
+    /// Every conversation
+    All,
+    /// Conversations without a moderator reply
+    New,
+    /// Conversations which have been replied to
+    InProgress,
+    /// Archived conversations
+    Archived,
+    /// Highlighted conversations
+    Highlighted,
+    /// Internal moderator discussions
+    Mod,
+    /// Automatic notifications
+    Notifications,
+    /// Ban appeals
+    Appeals,
+    /// Requests to join a private subreddit
+    JoinRequests,
+    /// Conversations filtered as spam
+    Filtered,
+}
+
+impl ModmailState {
+    /// The `state` Reddit uses for this filter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModmailState::All => "all",
+            ModmailState::New => "new",
+            ModmailState::InProgress => "inprogress",
+            ModmailState::Archived => "archived",
+            ModmailState::Highlighted => "highlighted",
+            ModmailState::Mod => "mod",
+            ModmailState::Notifications => "notifications",
+            ModmailState::Appeals => "appeals",
+            ModmailState::JoinRequests => "join_requests",
+            ModmailState::Filtered => "filtered",
+        }
+    }
+}
+
+/// How long a user is muted from modmail, used with [`AuthedClient::mute_modmail_user`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModmailMuteDuration {
+    /// 72 hours
+    Hours72,
+    /// 7 days
+    Days7,
+    /// 28 days
+    Days28,
+}
+
+impl ModmailMuteDuration {
+    /// The `num_hours` Reddit uses for this duration.
+    pub fn hours(&self) -> u32 {
+        match self {
+            ModmailMuteDuration::Hours72 => 72,
+            ModmailMuteDuration::Days7 => 7 * 24,
+            ModmailMuteDuration::Days28 => 28 * 24,
+        }
+    }
+}
+
+/// How modmail conversations are ordered, used with [`AuthedClient::modmail_conversations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModmailSort {
+    /// Most recently updated first
+    Recent,
+    /// Most recent moderator reply first
+    Mod,
+    /// Most recent user reply first
+    User,
+    /// Unread conversations first
+    Unread,
+}
+
+impl ModmailSort {
+    /// The `sort` Reddit uses for this order.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModmailSort::Recent => "recent",
+            ModmailSort::Mod => "mod",
+            ModmailSort::User => "user",
+            ModmailSort::Unread => "unread",
+        }
+    }
+}
+
+/// How [`AuthedClient::comment_many`] spaces out comments.
+#[derive(Debug, Clone)]
+pub struct CommentSpacing {
+    /// How long to wait between comments. Defaults to 5 seconds.
+    pub interval: Duration,
+    /// How many times a ratelimited comment is retried. Defaults to 3.
+    pub max_retries: u32,
+    /// The longest ratelimit wait to retry after; comments asked to wait longer fail instead.
+    /// Defaults to 10 minutes.
+    pub max_wait: Duration,
+}
+
+impl Default for CommentSpacing {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            max_retries: 3,
+            max_wait: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+/// The outcome of posting one comment with [`AuthedClient::comment_many`].
+pub struct CommentBatchResult<T> {
+    /// The thing that was replied to.
+    pub parent: ThingFullname,
+    /// The created comment, or the error from the last attempt.
+    ///
+    /// If the comment was still ratelimited, [`RouxError::retry_after`] gives how long Reddit asked to wait.
+    pub result: Result<CreatedComment<T>, RouxError>,
+    /// How many times posting the comment was attempted.
+    pub attempts: u32,
+    /// How long was spent waiting for ratelimits before the last attempt.
+    pub waited: Duration,
+}
+
 /// The target to apply the flair to
 pub enum SelectFlairTarget {
     /// A submission
@@ -612,6 +1584,7 @@ pub enum SelectFlairTarget {
 }
 
 /// Builder to provide flair data to a submission or user.
+#[derive(Debug, Clone)]
 pub struct SelectFlairData {
     template: Option<String>,
     text: Option<String>,
@@ -622,6 +1595,18 @@ impl SelectFlairData {
     pub fn new(template: Option<String>, text: Option<String>) -> Self {
         Self { template, text }
     }
+
+    /// Creates flair info, checking the text against the subreddit's constraints first.
+    pub fn validated(
+        template: Option<String>,
+        text: Option<String>,
+        constraints: &FlairTextConstraints,
+    ) -> Result<Self, FlairTextError> {
+        if let Some(text) = &text {
+            constraints.validate(text)?;
+        }
+        Ok(Self::new(template, text))
+    }
 }
 
 /// Reason for a comment or submission being removed.
@@ -638,3 +1623,18 @@ pub enum RemoveReason<'a> {
         reason_id: &'a str,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::VoteDirection;
+
+    #[test]
+    fn test_vote_deltas() {
+        use VoteDirection::*;
+
+        assert_eq!(None.deltas_to(Up), (1, 1));
+        assert_eq!(Up.deltas_to(Down), (-1, -2));
+        assert_eq!(Down.deltas_to(None), (0, 1));
+        assert_eq!(Up.deltas_to(Up), (0, 0));
+    }
+}
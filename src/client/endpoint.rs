@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use crate::builders::form::join_comma;
+
+#[derive(Clone, Debug)]
 pub struct EndpointBuilder {
     pub path: String,
     pub query: Vec<(String, String)>,
@@ -26,6 +28,28 @@ impl EndpointBuilder {
         self
     }
 
+    /// Adds the key once for each value, e.g. `type=a&type=b`.
+    pub fn query_many<V: Into<String>>(
+        mut self,
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        let key = key.into();
+        for value in values {
+            self.with_query(key.clone(), value);
+        }
+        self
+    }
+
+    /// Adds the values joined with commas, e.g. `id=t3_a,t3_b`.
+    pub fn query_joined<V: AsRef<str>>(
+        self,
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.query(key, join_comma(values))
+    }
+
     pub fn with_query(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
         self.query.push((key.into(), value.into()));
         self
@@ -33,26 +57,68 @@ impl EndpointBuilder {
 
     pub fn build(&self, base_url: &str) -> String {
         let dot_json = if self.with_dot_json { ".json" } else { "" };
-        let mut joined = if self.path.len() == 0 || self.path.starts_with('/') {
-            format!("{base_url}{}/{dot_json}", self.path)
+        let path = encode_path(&self.path);
+        let mut joined = if path.is_empty() || path.starts_with('/') {
+            format!("{base_url}{path}/{dot_json}")
         } else {
-            format!("{base_url}/{}/{dot_json}", self.path)
+            format!("{base_url}/{path}/{dot_json}")
         };
 
         if self.query.len() > 0 {
             joined.push('?');
         }
 
-        for (key, value) in &self.query {
-            joined.push_str(&key);
-            joined.push('=');
-            joined.push_str(&value);
+        for pair in &self.query {
+            // Values such as search queries may contain reserved characters (`:`, `/`, `&`, ...).
+            joined.push_str(&serde_urlencoded::to_string([pair]).unwrap());
             joined.push('&');
         }
         joined
     }
 }
 
+/// Percent-encodes the characters which cannot appear in a URL path, so that names and IDs given by
+/// users (e.g. a wiki page containing `?`) cannot end the path early.
+///
+/// `/`, `+` and `,` are left as they are, since paths such as `r/rust+programming` and
+/// `by_id/t3_a,t3_b` rely on them.
+fn encode_path(path: &str) -> std::borrow::Cow<'_, str> {
+    let needs_encoding = |b: u8| {
+        b <= b' '
+            || b >= 0x7f
+            || matches!(
+                b,
+                b'"' | b'#' | b'<' | b'>' | b'?' | b'`' | b'{' | b'}' | b'%'
+            )
+    };
+
+    if !path.bytes().any(needs_encoding) {
+        return std::borrow::Cow::Borrowed(path);
+    }
+
+    let mut encoded = String::with_capacity(path.len());
+    for b in path.bytes() {
+        if needs_encoding(b) {
+            encoded.push_str(&format!("%{b:02X}"));
+        } else {
+            encoded.push(b as char);
+        }
+    }
+    std::borrow::Cow::Owned(encoded)
+}
+
+impl EndpointBuilder {
+    /// Builds the URL with `raw_json=1`, which stops Reddit escaping `&`, `<` and `>` in the
+    /// response as `&amp;`, `&lt;` and `&gt;`.
+    pub fn build_raw_json(&self, base_url: &str) -> String {
+        let mut endpoint = self.clone();
+        if !endpoint.query.iter().any(|(key, _)| key == "raw_json") {
+            endpoint.with_query("raw_json", "1");
+        }
+        endpoint.build(base_url)
+    }
+}
+
 impl<'a> From<&'a str> for EndpointBuilder {
     fn from(value: &'a str) -> Self {
         EndpointBuilder::new(value)
@@ -64,3 +130,76 @@ impl From<String> for EndpointBuilder {
         EndpointBuilder::new(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EndpointBuilder;
+
+    #[test]
+    fn test_build_encodes_query() {
+        let endpoint = EndpointBuilder::new("search")
+            .query("q", "url:https://example.com/a?b=c&d")
+            .query("limit", "5");
+
+        assert_eq!(
+            endpoint.build(""),
+            "/search/.json?q=url%3Ahttps%3A%2F%2Fexample.com%2Fa%3Fb%3Dc%26d&limit=5&"
+        );
+    }
+
+    #[test]
+    fn test_build_encodes_hostile_query() {
+        let endpoint = EndpointBuilder::new("search")
+            .query("q", "flair:\"C++ & Rust\" a=b #1 100% ünïcode\nline")
+            .query("after", "t3_a&limit=100")
+            .query_joined("sr", ["a+b", "c&d"]);
+
+        assert_eq!(
+            endpoint.build(""),
+            "/search/.json?q=flair%3A%22C%2B%2B+%26+Rust%22+a%3Db+%231+100%25+%C3%BCn%C3%AFcode%0Aline&after=t3_a%26limit%3D100&sr=a%2Bb%2Cc%26d&"
+        );
+    }
+
+    #[test]
+    fn test_build_encodes_hostile_path() {
+        assert_eq!(
+            EndpointBuilder::new("r/rust/wiki/what? #1 100%").build(""),
+            "/r/rust/wiki/what%3F%20%231%20100%25/.json"
+        );
+        assert_eq!(
+            EndpointBuilder::new("r/rust+programming/by_id/t3_a,t3_b").build(""),
+            "/r/rust+programming/by_id/t3_a,t3_b/.json"
+        );
+        assert_eq!(
+            EndpointBuilder::new("user/ünï").build(""),
+            "/user/%C3%BCn%C3%AF/.json"
+        );
+    }
+
+    #[test]
+    fn test_query_many_and_joined() {
+        let endpoint = EndpointBuilder::new("api/info")
+            .query_joined("id", ["t3_a", "t1_b"])
+            .query_many("type", ["links", "comments"]);
+
+        assert_eq!(
+            endpoint.build(""),
+            "/api/info/.json?id=t3_a%2Ct1_b&type=links&type=comments&"
+        );
+    }
+
+    #[test]
+    fn test_build_raw_json() {
+        let endpoint = EndpointBuilder::new("r/rust/hot").query("limit", "5");
+        assert_eq!(
+            endpoint.build_raw_json(""),
+            "/r/rust/hot/.json?limit=5&raw_json=1&"
+        );
+
+        let endpoint = endpoint.query("raw_json", "0");
+        assert_eq!(
+            endpoint.build_raw_json(""),
+            "/r/rust/hot/.json?limit=5&raw_json=0&"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::models::submission::Submissions;
+use crate::models::Listing;
+use crate::util::{FeedOption, RouxError};
+
+use super::endpoint::EndpointBuilder;
+use super::traits::RedditClient;
+use super::AuthedClient;
+
+/// The logged in user's front page, made up of the subreddits they are subscribed to.
+///
+/// For `r/all` and `r/popular`, use [`Subreddit::all`](super::Subreddit::all) and
+/// [`Subreddit::popular`](super::Subreddit::popular).
+#[derive(Clone)]
+pub struct FrontPage {
+    client: AuthedClient,
+}
+
+impl FrontPage {
+    pub(crate) fn new(client: AuthedClient) -> Self {
+        Self { client }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get_feed(
+        &self,
+        sort: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<AuthedClient>, RouxError> {
+        let source = EndpointBuilder::new(sort);
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut endpoint);
+        }
+
+        let api: crate::api::APISubmissions = self.client.get_json(endpoint).await?;
+        Ok(Listing::new(api, self.client.clone()).with_source(source, options))
+    }
+
+    /// Get the best posts, as ordered by Reddit for the user.
+    #[maybe_async::maybe_async]
+    pub async fn best(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<AuthedClient>, RouxError> {
+        self.get_feed("best", options).await
+    }
+
+    /// Get hot posts.
+    #[maybe_async::maybe_async]
+    pub async fn hot(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<AuthedClient>, RouxError> {
+        self.get_feed("hot", options).await
+    }
+
+    /// Get rising posts.
+    #[maybe_async::maybe_async]
+    pub async fn rising(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<AuthedClient>, RouxError> {
+        self.get_feed("rising", options).await
+    }
+
+    /// Get top posts.
+    #[maybe_async::maybe_async]
+    pub async fn top(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<AuthedClient>, RouxError> {
+        self.get_feed("top", options).await
+    }
+
+    /// Get controversial posts.
+    ///
+    /// Use [`FeedOption::period`] to choose how far back to look, Reddit defaults to the last day.
+    #[maybe_async::maybe_async]
+    pub async fn controversial(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<AuthedClient>, RouxError> {
+        self.get_feed("controversial", options).await
+    }
+
+    /// Get latest posts.
+    #[maybe_async::maybe_async]
+    pub async fn latest(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<AuthedClient>, RouxError> {
+        self.get_feed("new", options).await
+    }
+}
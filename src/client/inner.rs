@@ -1,12 +1,13 @@
 use std::error::Error;
 use std::future::Future;
+use std::sync::PoisonError;
 use std::time::Duration;
 
 use reqwest::{header, Method, StatusCode};
 use serde::{Deserialize, Serialize};
 
-use crate::client::ratelimit::Ratelimit;
-use crate::client::{req::*, ParseJsonError};
+use crate::client::ratelimit::{Pacer, Ratelimit};
+use crate::client::{req::*, ParseJsonError, Scope};
 use crate::util::RouxError;
 use crate::Config;
 
@@ -15,12 +16,16 @@ use crate::util::maybe_async_handler;
 use super::endpoint::EndpointBuilder;
 
 enum RetryableExecuteError {
-    RetryAfter(Duration),
+    Ratelimited {
+        retry_after: Option<Duration>,
+        reset: Option<Duration>,
+    },
     RetryExponential {
         max_retries: Option<u8>,
         last_error: reqwest::Error,
     },
     Unauthorized,
+    InsufficientScope,
     OtherResponseError(Response, reqwest::Error),
     Other(reqwest::Error),
 }
@@ -56,6 +61,11 @@ impl From<reqwest::Error> for RetryableExecuteError {
 pub(crate) enum ExecuteError {
     AuthorizationRequired,
     AuthError(String),
+    InsufficientScope(Vec<Scope>),
+    Ratelimited {
+        retry_after: Option<Duration>,
+        reset: Option<Duration>,
+    },
     ErrorOnly(reqwest::Error),
     ResponseAndError(Response, reqwest::Error),
     JsonError(ParseJsonError),
@@ -73,6 +83,10 @@ impl From<ExecuteError> for RouxError {
             ExecuteError::AuthorizationRequired => RouxError::credentials_not_set(),
             ExecuteError::ErrorOnly(error) => RouxError::network(error),
             ExecuteError::AuthError(error) => RouxError::auth(error),
+            ExecuteError::InsufficientScope(missing) => RouxError::insufficient_scope(missing),
+            ExecuteError::Ratelimited { retry_after, reset } => {
+                RouxError::ratelimited(retry_after, reset)
+            }
             ExecuteError::ResponseAndError(response, error) => {
                 RouxError::full_network(response, error)
             }
@@ -81,19 +95,30 @@ impl From<ExecuteError> for RouxError {
     }
 }
 
+// Locks are recovered when poisoned: every write leaves the guarded state consistent, so a thread
+// panicking while holding one shouldn't break every other clone of the client.
 pub(crate) struct ClientInner {
     pub(crate) config: Config,
-    base_url: &'static str,
+    base_url: String,
+    auth_url: String,
     inner: Client,
     ratelimit: Mutex<Ratelimit>,
+    pacer: Option<std::sync::Mutex<Pacer>>,
+    granted_scopes: std::sync::RwLock<Option<Vec<Scope>>>,
 }
 
 impl ClientInner {
     pub(crate) fn new(config: Config) -> Result<Self, RouxError> {
-        let base_url = if config.password.is_some() {
-            "https://oauth.reddit.com"
-        } else {
-            "https://www.reddit.com"
+        let (base_url, auth_url) = match &config.base_url {
+            Some(url) => (url.clone(), url.clone()),
+            None if config.password.is_some() => (
+                "https://oauth.reddit.com".to_owned(),
+                "https://www.reddit.com".to_owned(),
+            ),
+            None => (
+                "https://www.reddit.com".to_owned(),
+                "https://www.reddit.com".to_owned(),
+            ),
         };
 
         let mut headers = header::HeaderMap::new();
@@ -118,23 +143,61 @@ impl ClientInner {
             client
         };
 
+        let pacer =
+            (!config.pacing.is_empty()).then(|| std::sync::Mutex::new(Pacer::new(&config.pacing)));
+
         Ok(Self {
             base_url,
+            auth_url,
             config,
             inner: client.build()?,
             ratelimit: Mutex::new(Ratelimit::new()),
+            pacer,
+            granted_scopes: std::sync::RwLock::new(None),
         })
     }
 
     pub(crate) fn request(&self, method: Method, endpoint: &EndpointBuilder) -> RequestBuilder {
-        let url = endpoint.build(&self.base_url);
+        let url = if self.config.raw_json {
+            endpoint.build_raw_json(&self.base_url)
+        } else {
+            endpoint.build(&self.base_url)
+        };
         println!("[roux] {method:?} {url}");
         self.inner.request(method, url)
     }
 
+    /// Builds a request to a URL outside of Reddit's API, without credentials.
+    pub(crate) fn external_request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.inner.request(method, url)
+    }
+
+    /// Uploads a multipart form to a URL outside of Reddit's API, such as a media upload lease.
+    #[maybe_async::maybe_async]
+    pub(crate) async fn upload(&self, url: &str, form: multipart::Form) -> Result<(), RouxError> {
+        let response = self.inner.post(url).multipart(form).send().await?;
+        if !response.status().is_success() {
+            return Err(RouxError::status(response));
+        }
+        Ok(())
+    }
+
+    /// How long to wait before sending the request, according to the configured pacing.
+    fn pacing_delay(&self, request: &Request) -> Option<Duration> {
+        let pacer = self.pacer.as_ref()?;
+        let mut pacer = pacer.lock().unwrap_or_else(PoisonError::into_inner);
+        pacer.reserve(request.url().path(), std::time::Instant::now())
+    }
+
     #[cfg(feature = "blocking")]
     pub(crate) fn with_ratelimits(&self, request: Request) -> Result<Response, reqwest::Error> {
-        let mut lock = self.ratelimit.lock().unwrap();
+        if let Some(delay) = self.pacing_delay(&request) {
+            sleep(delay);
+        }
+        let mut lock = self
+            .ratelimit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
         lock.delay();
         let response = self.inner.execute(request)?;
         lock.update(response.headers());
@@ -145,6 +208,9 @@ impl ClientInner {
         &self,
         request: Request,
     ) -> Result<Response, reqwest::Error> {
+        if let Some(delay) = self.pacing_delay(&request) {
+            sleep(delay).await;
+        }
         let mut lock = self.ratelimit.lock().await;
         lock.delay().await;
         let response = self.inner.execute(request).await?;
@@ -161,19 +227,10 @@ impl ClientInner {
         let status = error.status().unwrap_or(StatusCode::BAD_REQUEST);
         println!("[roux] Response error: {status:?}");
         match status {
-            StatusCode::TOO_MANY_REQUESTS => {
-                if let Some(value) = response.headers().get("Retry-After") {
-                    if let Ok(value) = value.to_str() {
-                        if let Ok(value) = value.parse() {
-                            return RetryableExecuteError::RetryAfter(Duration::from_secs(value));
-                        }
-                    }
-                }
-                RetryableExecuteError::RetryExponential {
-                    max_retries: None,
-                    last_error: error,
-                }
-            }
+            StatusCode::TOO_MANY_REQUESTS => RetryableExecuteError::Ratelimited {
+                retry_after: header_duration(response.headers(), "Retry-After"),
+                reset: header_duration(response.headers(), "X-Ratelimit-Reset"),
+            },
             StatusCode::INTERNAL_SERVER_ERROR => {
                 if let Ok(t) = response.text().await {
                     println!("500: {t}");
@@ -188,6 +245,9 @@ impl ClientInner {
                 }
             }
             StatusCode::UNAUTHORIZED => RetryableExecuteError::Unauthorized,
+            StatusCode::FORBIDDEN if is_insufficient_scope(&response) => {
+                RetryableExecuteError::InsufficientScope
+            }
             _ => RetryableExecuteError::OtherResponseError(response, error),
         }
     }
@@ -220,6 +280,10 @@ impl ClientInner {
         loop {
             let request = builder().build()?;
 
+            if let Some(missing) = self.request_missing_scopes(&request) {
+                return Err(ExecuteError::InsufficientScope(missing));
+            }
+
             let response = self.inner_execute(request).await;
 
             let result = match response {
@@ -237,8 +301,14 @@ impl ClientInner {
 
             match result {
                 Ok(t) => return Ok(t),
-                Err(RetryableExecuteError::RetryAfter(duration)) => {
+                Err(RetryableExecuteError::Ratelimited { retry_after, reset }) => {
                     retries += 1;
+                    if retries > self.config.max_ratelimit_retries {
+                        println!("[roux] Exceeded max ratelimit retries for request, raising err.");
+                        return Err(ExecuteError::Ratelimited { retry_after, reset });
+                    }
+                    let duration = retry_after
+                        .unwrap_or_else(|| Duration::from_secs(std::cmp::min(60, 2u64.saturating_pow(retries))));
                     println!("[roux] Retrying request after {duration:?} ({retries})");
                     sleep(duration).await;
                 }
@@ -253,7 +323,7 @@ impl ClientInner {
                             return Err(ExecuteError::ErrorOnly(last_error));
                         }
                     }
-                    let secs = std::cmp::min(60, 2u64.pow(retries));
+                    let secs = std::cmp::min(60, 2u64.saturating_pow(retries));
                     let duration = Duration::from_secs(secs);
                     println!(
                         "[roux] Exp retrying request after {duration:?} ({retries}/{max_retries:?})"
@@ -269,10 +339,44 @@ impl ClientInner {
                 Err(RetryableExecuteError::Unauthorized) => {
                     return Err(ExecuteError::AuthorizationRequired)
                 }
+                Err(RetryableExecuteError::InsufficientScope) => {
+                    return Err(ExecuteError::InsufficientScope(self.missing_scopes()))
+                }
             }
         }
     });
 
+    /// The scopes granted to the most recent access token, if known.
+    pub(crate) fn granted_scopes(&self) -> Option<Vec<Scope>> {
+        self.granted_scopes
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub(crate) fn set_granted_scopes(&self, scopes: Option<Vec<Scope>>) {
+        *self
+            .granted_scopes
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = scopes;
+    }
+
+    /// The scopes a request needs which were not granted, if it is known which were.
+    fn request_missing_scopes(&self, request: &Request) -> Option<Vec<Scope>> {
+        let granted = self.granted_scopes()?;
+        crate::endpoints::missing_scopes(request.method().as_str(), request.url().path(), &granted)
+    }
+
+    /// The declared scopes which were not granted.
+    ///
+    /// If it is not known which scopes were granted, all declared scopes are returned.
+    fn missing_scopes(&self) -> Vec<Scope> {
+        match self.granted_scopes() {
+            Some(granted) => Scope::missing(&self.config.scopes, &granted),
+            None => self.config.scopes.clone(),
+        }
+    }
+
     #[maybe_async::maybe_async]
     pub(crate) async fn attempt_login(&self) -> Result<String, ExecuteError> {
         #[derive(Serialize)]
@@ -285,27 +389,31 @@ impl ClientInner {
         #[derive(Deserialize, Debug)]
         #[serde(untagged)]
         enum AuthResponse {
-            AuthData { access_token: String },
-            ErrorData { error: String },
+            AuthData {
+                access_token: String,
+                scope: Option<String>,
+            },
+            ErrorData {
+                error: String,
+            },
         }
 
+        let (username, password) = self
+            .config
+            .credentials()
+            .ok_or(ExecuteError::AuthorizationRequired)?;
         let login = LoginRequest {
             grant_type: "password",
-            username: &self
-                .config
-                .username
-                .as_ref()
-                .ok_or(ExecuteError::AuthorizationRequired)?,
-            password: &self
-                .config
-                .password
-                .as_ref()
-                .ok_or(ExecuteError::AuthorizationRequired)?,
+            username,
+            password,
         };
 
         let request = self
             .inner
-            .request(Method::POST, "https://www.reddit.com/api/v1/access_token")
+            .request(
+                Method::POST,
+                format!("{}/api/v1/access_token", self.auth_url),
+            )
             .basic_auth(&self.config.client_id, Some(&self.config.client_secret))
             .form(&login);
 
@@ -316,12 +424,30 @@ impl ClientInner {
                 let auth_data = response.json().await?;
 
                 let access_token = match auth_data {
-                    AuthResponse::AuthData { access_token } => access_token,
+                    AuthResponse::AuthData {
+                        access_token,
+                        scope,
+                    } => {
+                        let granted = scope.map(|s| Scope::parse_list(&s));
+                        if let Some(granted) = &granted {
+                            let missing = Scope::missing(&self.config.scopes, granted);
+                            if !missing.is_empty() {
+                                return Err(ExecuteError::InsufficientScope(missing));
+                            }
+                        }
+                        self.set_granted_scopes(granted);
+
+                        access_token
+                    }
                     AuthResponse::ErrorData { error } => {
                         return Err(ExecuteError::AuthError(error))
                     }
                 };
 
+                if let Some(store) = &self.config.token_store {
+                    store.save(&access_token);
+                }
+
                 return Ok(access_token);
             }
             Err(err) => {
@@ -330,3 +456,73 @@ impl ClientInner {
         }
     }
 }
+
+/// Parses a header holding a number of seconds, as used by `Retry-After` and `X-Ratelimit-Reset`.
+fn header_duration(headers: &header::HeaderMap, name: &str) -> Option<Duration> {
+    let secs: f64 = headers.get(name)?.to_str().ok()?.trim().parse().ok()?;
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+/// Reddit indicates a token lacks the scope for a request through the `WWW-Authenticate` header, e.g.
+/// `Bearer realm="reddit", error="insufficient_scope"`
+fn is_insufficient_scope(response: &Response) -> bool {
+    response
+        .headers()
+        .get(header::WWW_AUTHENTICATE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("insufficient_scope"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    use super::{header_duration, ClientInner};
+    use crate::client::Scope;
+    use crate::Config;
+
+    #[test]
+    fn test_header_duration() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", HeaderValue::from_static("30"));
+        headers.insert("X-Ratelimit-Reset", HeaderValue::from_static("12.5"));
+        headers.insert(
+            "X-Other",
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert_eq!(
+            header_duration(&headers, "Retry-After"),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            header_duration(&headers, "X-Ratelimit-Reset"),
+            Some(Duration::from_millis(12500))
+        );
+        assert_eq!(header_duration(&headers, "X-Other"), None);
+        assert_eq!(header_duration(&headers, "Missing"), None);
+    }
+
+    #[test]
+    fn test_poisoned_lock_recovers() {
+        let inner = ClientInner::new(Config::new("roux test", "id", "secret")).unwrap();
+        inner.set_granted_scopes(Some(vec![Scope::Read]));
+
+        std::thread::scope(|s| {
+            let result = s
+                .spawn(|| {
+                    let _guard = inner.granted_scopes.write().unwrap();
+                    panic!("poison the lock");
+                })
+                .join();
+            assert!(result.is_err());
+        });
+        assert!(inner.granted_scopes.is_poisoned());
+
+        assert_eq!(inner.granted_scopes(), Some(vec![Scope::Read]));
+        inner.set_granted_scopes(None);
+        assert_eq!(inner.granted_scopes(), None);
+    }
+}
@@ -1,31 +1,41 @@
 #[cfg(feature = "blocking")]
 pub(crate) mod req {
-    pub use reqwest::blocking::{Client, ClientBuilder, Request, RequestBuilder, Response};
+    pub use reqwest::blocking::{
+        multipart, Client, ClientBuilder, Request, RequestBuilder, Response,
+    };
+    pub use std::fs;
     pub use std::sync::Mutex;
     pub use std::thread::sleep;
 }
 
 #[cfg(not(feature = "blocking"))]
 pub(crate) mod req {
-    pub use reqwest::{Client, ClientBuilder, Request, RequestBuilder, Response};
+    pub use reqwest::{multipart, Client, ClientBuilder, Request, RequestBuilder, Response};
+    pub use tokio::fs;
     pub use tokio::sync::Mutex;
     pub use tokio::time::sleep;
 }
 
 mod auth;
 pub(crate) mod endpoint;
+mod front_page;
 pub(crate) mod inner;
 mod noauth;
 mod oauth;
 mod ratelimit;
+mod scope;
 mod subreddits;
+mod token;
 mod traits;
 mod user;
 
 pub use auth::*;
+pub use front_page::FrontPage;
 pub use noauth::*;
 pub use oauth::*;
+pub use scope::Scope;
 pub use subreddits::*;
+pub use token::TokenStore;
 pub(crate) use traits::ParseJsonError;
 pub use traits::RedditClient;
 pub use user::*;
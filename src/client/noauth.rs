@@ -37,7 +37,7 @@ impl RedditClient for UnauthedClient {
         endpoint: impl Into<super::endpoint::EndpointBuilder>,
     ) -> Result<super::req::Response, RouxError> {
         let endpoint: EndpointBuilder = endpoint.into();
-        let endpoint = endpoint.build("https://www.reddit.com");
+        let endpoint = endpoint.build_raw_json("https://www.reddit.com");
         println!("GET {endpoint}");
         let response = self.inner.get(endpoint).send().await?;
         if response.error_for_status_ref().is_err() {
@@ -56,7 +56,7 @@ impl RedditClient for UnauthedClient {
         form: &T,
     ) -> Result<super::req::Response, RouxError> {
         let endpoint: EndpointBuilder = endpoint.into();
-        let endpoint = endpoint.build("https://www.reddit.com");
+        let endpoint = endpoint.build_raw_json("https://www.reddit.com");
         let resp = self.inner.post(endpoint).form(form).send().await?;
         Ok(resp)
     }
@@ -68,7 +68,11 @@ impl RedditClient for UnauthedClient {
     });
 
     fn make_req(&self, method: Method, endpoint: &EndpointBuilder) -> RequestBuilder {
-        let endpoint = endpoint.build("https://www.reddit.com");
+        let endpoint = endpoint.build_raw_json("https://www.reddit.com");
         self.inner.request(method, &endpoint)
     }
+
+    fn make_external_req(&self, method: Method, url: &str) -> RequestBuilder {
+        self.inner.request(method, url)
+    }
 }
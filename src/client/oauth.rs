@@ -31,12 +31,39 @@ impl OAuthClient {
             inner: Arc::new(inner),
         })
     }
+    /// Whether the config has a username and password, so [`OAuthClient::login`] can log in.
+    ///
+    /// Apps which can also run read-only can check this at startup to pick a mode.
+    pub fn is_authenticatable(&self) -> bool {
+        self.inner.config.credentials().is_some()
+    }
+
     /// Attempts to login this client and produce an [`AuthedClient`].
-    /// This will immediately error if the config does not have a username and password set.
+    /// This will immediately error with [`RouxErrorKind::CredentialsNotSet`](crate::util::error::RouxErrorKind::CredentialsNotSet)
+    /// if the config does not have a username and password set, or either is empty.
+    ///
+    /// If a [`TokenStore`](crate::client::TokenStore) is configured and has a saved token,
+    /// that token is used instead of logging in again.
     #[maybe_async::maybe_async]
     pub async fn login(self) -> Result<AuthedClient, RouxError> {
+        if let Some(token) = self
+            .inner
+            .config
+            .token_store
+            .as_ref()
+            .and_then(|s| s.load())
+        {
+            return AuthedClient::new(self.inner.config.clone(), token);
+        }
+
+        if !self.is_authenticatable() {
+            return Err(RouxError::credentials_not_set());
+        }
+
         let token = self.inner.attempt_login().await?;
-        AuthedClient::new(self.inner.config.clone(), token)
+        let client = AuthedClient::new(self.inner.config.clone(), token)?;
+        client.set_granted_scopes(self.inner.granted_scopes());
+        Ok(client)
     }
 
     pub(crate) fn config(&self) -> &Config {
@@ -52,6 +79,10 @@ impl RedditClient for OAuthClient {
     fn make_req(&self, method: Method, endpoint: &EndpointBuilder) -> RequestBuilder {
         self.inner.request(method, endpoint)
     }
+
+    fn make_external_req(&self, method: Method, url: &str) -> RequestBuilder {
+        self.inner.external_request(method, url)
+    }
 }
 
 impl Clone for OAuthClient {
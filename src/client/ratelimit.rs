@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fmt::Debug,
     str::FromStr,
     time::{Duration, Instant},
@@ -18,18 +19,21 @@ impl Ratelimit {
     const WINDOW: f64 = 600.0;
 
     pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    fn starting_at(now: Instant) -> Self {
         Self {
             remaining: 100.0,
             used: 0,
-            next_request: Instant::now(),
-            next_reset: Instant::now() + Duration::from_secs(Self::WINDOW as u64),
+            next_request: now,
+            next_reset: now + Duration::from_secs(Self::WINDOW as u64),
         }
     }
 
     #[maybe_async::maybe_async]
     pub async fn delay(&self) {
-        let now = Instant::now();
-        let Some(diff) = self.next_request.checked_duration_since(now.clone()) else {
+        let Some(diff) = self.wait(Instant::now()) else {
             return;
         };
 
@@ -37,6 +41,13 @@ impl Ratelimit {
         sleep(diff).await;
     }
 
+    /// How long to wait at `now` before the next request can be sent.
+    fn wait(&self, now: Instant) -> Option<Duration> {
+        self.next_request
+            .checked_duration_since(now)
+            .filter(|wait| !wait.is_zero())
+    }
+
     fn get<T>(headers: &HeaderMap, name: &str) -> T
     where
         T: FromStr,
@@ -48,14 +59,17 @@ impl Ratelimit {
     }
 
     pub fn update(&mut self, headers: &HeaderMap) {
+        self.update_at(headers, Instant::now());
+    }
+
+    /// Updates the ratelimit from a response's headers, as if it were received at `now`.
+    fn update_at(&mut self, headers: &HeaderMap, now: Instant) {
         if !headers.contains_key("X-Ratelimit-Remaining") {
             self.remaining -= 1.0;
             self.used += 1;
             return;
         };
 
-        let now = Instant::now();
-
         let reset_seconds = Self::get(headers, "X-Ratelimit-Reset");
         self.remaining = Self::get(headers, "X-Ratelimit-Remaining");
         self.used = Self::get(headers, "X-Ratelimit-Used");
@@ -107,3 +121,126 @@ fn clamp(min: f64, value: f64, max: f64) -> f64 {
         value
     }
 }
+
+/// Spaces out requests to endpoints under configured path prefixes, independently of the global
+/// [`Ratelimit`]. See [`Config::pace_endpoint`](crate::Config::pace_endpoint).
+pub struct Pacer {
+    rules: Vec<(String, Duration)>,
+    next_slot: HashMap<String, Instant>,
+}
+
+impl Pacer {
+    pub fn new(rules: &[(String, Duration)]) -> Self {
+        Self {
+            rules: rules
+                .iter()
+                .map(|(prefix, interval)| (normalize(prefix), *interval))
+                .collect(),
+            next_slot: HashMap::new(),
+        }
+    }
+
+    /// Reserves the next slot for a request to `path`, returning how long to wait until then.
+    ///
+    /// The longest matching prefix wins. Slots are reserved up front, so concurrent requests
+    /// are queued behind each other rather than all waking at once.
+    pub fn reserve(&mut self, path: &str, now: Instant) -> Option<Duration> {
+        let path = normalize(path);
+        let (prefix, interval) = self
+            .rules
+            .iter()
+            .filter(|(prefix, _)| {
+                path.strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '.']))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())?;
+
+        let slot = self
+            .next_slot
+            .get(prefix)
+            .map_or(now, |next| std::cmp::max(*next, now));
+        self.next_slot.insert(prefix.clone(), slot + *interval);
+
+        slot.checked_duration_since(now)
+            .filter(|wait| !wait.is_zero())
+    }
+}
+
+fn normalize(path: &str) -> String {
+    path.trim_matches('/').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use reqwest::header::HeaderMap;
+
+    use super::{Pacer, Ratelimit};
+
+    fn headers(remaining: u64, used: u64, reset: u64) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Ratelimit-Remaining", remaining.into());
+        headers.insert("X-Ratelimit-Used", used.into());
+        headers.insert("X-Ratelimit-Reset", reset.into());
+        headers
+    }
+
+    #[test]
+    fn test_ratelimit_window() {
+        let second = Duration::from_secs(1);
+        let now = Instant::now();
+        let mut ratelimit = Ratelimit::starting_at(now);
+        assert_eq!(ratelimit.wait(now), None);
+
+        // Half the requests used with half the window left is on schedule.
+        ratelimit.update_at(&headers(50, 50, 300), now);
+        assert_eq!(ratelimit.wait(now), None);
+
+        // 40 of 100 requests should take 240 of the 600 seconds, leaving 360, but the window
+        // resets in 363 so we are 3 seconds ahead.
+        ratelimit.update_at(&headers(60, 40, 363), now);
+        assert_eq!(ratelimit.wait(now), Some(second * 3));
+        assert_eq!(ratelimit.wait(now + second), Some(second * 2));
+        assert_eq!(ratelimit.wait(now + second * 3), None);
+
+        // The delay is capped at 10 seconds.
+        ratelimit.update_at(&headers(10, 90, 500), now);
+        assert_eq!(ratelimit.wait(now), Some(second * 10));
+
+        // Without remaining requests, wait for the window to reset.
+        ratelimit.update_at(&headers(0, 100, 30), now);
+        assert_eq!(ratelimit.wait(now), Some(second * 30));
+
+        // Responses without the headers only count the request.
+        ratelimit.update_at(&HeaderMap::new(), now);
+        assert_eq!(ratelimit.remaining, -1.0);
+        assert_eq!(ratelimit.used, 101);
+        assert_eq!(ratelimit.wait(now), Some(second * 30));
+    }
+
+    #[test]
+    fn test_pacer_reserves_slots_per_prefix() {
+        let second = Duration::from_secs(1);
+        let mut pacer = Pacer::new(&[
+            ("/r/rust".to_owned(), second),
+            ("r/rust/new".to_owned(), second * 5),
+        ]);
+        let now = Instant::now();
+
+        assert_eq!(pacer.reserve("/r/Rust/hot/.json", now), None);
+        assert_eq!(pacer.reserve("/r/rust/about/.json", now), Some(second));
+        assert_eq!(pacer.reserve("/r/rust/about/.json", now), Some(second * 2));
+
+        // The longer prefix is paced separately.
+        assert_eq!(pacer.reserve("/r/rust/new/.json", now), None);
+        assert_eq!(pacer.reserve("/r/rust/new/.json", now), Some(second * 5));
+
+        // Neither a different subreddit nor one sharing the name as a prefix is paced.
+        assert_eq!(pacer.reserve("/r/rustjerk/hot/.json", now), None);
+        assert_eq!(pacer.reserve("/r/python/hot/.json", now), None);
+
+        // Once the slot has passed, there is no wait.
+        assert_eq!(pacer.reserve("/r/rust/hot/.json", now + second * 10), None);
+    }
+}
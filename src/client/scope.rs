@@ -0,0 +1,218 @@
+//! # OAuth Scopes
+//! The permissions an access token can be granted.
+//! See <https://www.reddit.com/api/v1/scopes> for the descriptions Reddit gives of each scope.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// An OAuth scope which grants access to a particular set of endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    /// All scopes. This is what script apps logging in with a password are granted.
+    #[serde(rename = "*")]
+    All,
+    /// Update preferences and related account information.
+    Account,
+    /// Spend reddit gold creddits on giving gold to other users.
+    Creddits,
+    /// Edit and delete my comments and submissions.
+    Edit,
+    /// Select my subreddit flair. Change link flair on my submissions.
+    Flair,
+    /// Access my voting history and comments or submissions I've saved or hidden.
+    History,
+    /// Access my reddit username and signup date.
+    Identity,
+    /// Manage settings and contributors of live threads I contribute to.
+    LiveManage,
+    /// Manage the configuration, sidebar, and CSS of subreddits I moderate.
+    ModConfig,
+    /// Add/remove users to approved user lists and ban/unban or mute/unmute users from subreddits I moderate.
+    ModContributors,
+    /// Manage and assign flair in subreddits I moderate.
+    ModFlair,
+    /// Access the moderation log in subreddits I moderate.
+    ModLog,
+    /// Access and manage modmail via mod.reddit.com.
+    ModMail,
+    /// Manage moderation notes on users in subreddits I moderate.
+    ModNote,
+    /// Invite or remove other moderators from subreddits I moderate.
+    ModOthers,
+    /// Approve, remove, mark nsfw, and distinguish content in subreddits I moderate.
+    ModPosts,
+    /// Accept invitations to moderate a subreddit. Remove myself as a moderator or contributor of subreddits I moderate or contribute to.
+    ModSelf,
+    /// Access traffic stats in subreddits I moderate.
+    ModTraffic,
+    /// Change editors and visibility of wiki pages in subreddits I moderate.
+    ModWiki,
+    /// Access the list of subreddits I moderate, contribute to, and subscribe to.
+    MySubreddits,
+    /// Access my inbox and send private messages to other users.
+    PrivateMessages,
+    /// Access posts and comments through my account.
+    Read,
+    /// Report content for rules violations. Hide & show individual submissions.
+    Report,
+    /// Save and unsave comments and submissions.
+    Save,
+    /// Edit structured styles for a subreddit I moderate.
+    StructuredStyles,
+    /// Submit links and comments from my account.
+    Submit,
+    /// Manage my subreddit subscriptions. Manage "friends" - users whose content I follow.
+    Subscribe,
+    /// Submit and change my votes on comments and submissions.
+    Vote,
+    /// Edit wiki pages on my behalf.
+    WikiEdit,
+    /// Read wiki pages through my account.
+    WikiRead,
+}
+
+impl Scope {
+    /// The name of the scope, as used in Reddit's API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::All => "*",
+            Scope::Account => "account",
+            Scope::Creddits => "creddits",
+            Scope::Edit => "edit",
+            Scope::Flair => "flair",
+            Scope::History => "history",
+            Scope::Identity => "identity",
+            Scope::LiveManage => "livemanage",
+            Scope::ModConfig => "modconfig",
+            Scope::ModContributors => "modcontributors",
+            Scope::ModFlair => "modflair",
+            Scope::ModLog => "modlog",
+            Scope::ModMail => "modmail",
+            Scope::ModNote => "modnote",
+            Scope::ModOthers => "modothers",
+            Scope::ModPosts => "modposts",
+            Scope::ModSelf => "modself",
+            Scope::ModTraffic => "modtraffic",
+            Scope::ModWiki => "modwiki",
+            Scope::MySubreddits => "mysubreddits",
+            Scope::PrivateMessages => "privatemessages",
+            Scope::Read => "read",
+            Scope::Report => "report",
+            Scope::Save => "save",
+            Scope::StructuredStyles => "structuredstyles",
+            Scope::Submit => "submit",
+            Scope::Subscribe => "subscribe",
+            Scope::Vote => "vote",
+            Scope::WikiEdit => "wikiedit",
+            Scope::WikiRead => "wikiread",
+        }
+    }
+
+    /// Parses a space or comma separated list of scopes, as returned by Reddit when granting a token.
+    ///
+    /// Unknown scopes are ignored.
+    pub fn parse_list(scopes: &str) -> Vec<Scope> {
+        scopes
+            .split([' ', ','])
+            .filter_map(|s| s.parse().ok())
+            .collect()
+    }
+
+    /// Joins the scopes into the space separated form expected by Reddit.
+    pub fn join(scopes: &[Scope]) -> String {
+        scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns the scopes in `required` which are not covered by `granted`.
+    pub fn missing(required: &[Scope], granted: &[Scope]) -> Vec<Scope> {
+        if granted.contains(&Scope::All) {
+            return Vec::new();
+        }
+
+        required
+            .iter()
+            .filter(|scope| !granted.contains(scope))
+            .copied()
+            .collect()
+    }
+}
+
+impl FromStr for Scope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let scope = match s {
+            "*" => Scope::All,
+            "account" => Scope::Account,
+            "creddits" => Scope::Creddits,
+            "edit" => Scope::Edit,
+            "flair" => Scope::Flair,
+            "history" => Scope::History,
+            "identity" => Scope::Identity,
+            "livemanage" => Scope::LiveManage,
+            "modconfig" => Scope::ModConfig,
+            "modcontributors" => Scope::ModContributors,
+            "modflair" => Scope::ModFlair,
+            "modlog" => Scope::ModLog,
+            "modmail" => Scope::ModMail,
+            "modnote" => Scope::ModNote,
+            "modothers" => Scope::ModOthers,
+            "modposts" => Scope::ModPosts,
+            "modself" => Scope::ModSelf,
+            "modtraffic" => Scope::ModTraffic,
+            "modwiki" => Scope::ModWiki,
+            "mysubreddits" => Scope::MySubreddits,
+            "privatemessages" => Scope::PrivateMessages,
+            "read" => Scope::Read,
+            "report" => Scope::Report,
+            "save" => Scope::Save,
+            "structuredstyles" => Scope::StructuredStyles,
+            "submit" => Scope::Submit,
+            "subscribe" => Scope::Subscribe,
+            "vote" => Scope::Vote,
+            "wikiedit" => Scope::WikiEdit,
+            "wikiread" => Scope::WikiRead,
+            other => return Err(other.to_owned()),
+        };
+
+        Ok(scope)
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            Scope::parse_list("identity read modposts unknownscope"),
+            vec![Scope::Identity, Scope::Read, Scope::ModPosts]
+        );
+        assert_eq!(Scope::parse_list("*"), vec![Scope::All]);
+        assert_eq!(Scope::join(&[Scope::Read, Scope::Vote]), "read vote");
+    }
+
+    #[test]
+    fn test_missing() {
+        let required = [Scope::Read, Scope::Submit, Scope::ModMail];
+
+        assert_eq!(
+            Scope::missing(&required, &[Scope::Read]),
+            vec![Scope::Submit, Scope::ModMail]
+        );
+        assert!(Scope::missing(&required, &[Scope::All]).is_empty());
+    }
+}
@@ -63,31 +63,44 @@
 //! let next_hot = subreddit.hot(25, Some(after_options)).await;
 //! # }
 //! ```
-use reqwest::StatusCode;
-use serde::Serialize;
+use reqwest::Method;
+use serde::{de::IgnoredAny, Serialize};
 
 use crate::api::comment::latest::LatestCommentData;
+use crate::api::media::MediaSubmitted;
+use crate::api::submission::SubmittedGallery;
 use crate::api::subreddit::{
-    FlairList, FlairSelection, ModActionData, ModActionType, ModLogListing, SubredditData,
-    SubredditRemovalReasons, SubredditResponse, SubredditsData,
+    BannedUserData, CommentSort, ContributorData, CreatedRemovalReason, FlairList, FlairSelection,
+    FlairTemplate, FlairType, MenuLink, MenuWidget, MenuWidgetRequest, ModActionData,
+    ModActionType, ModLogListing, MutedUserData, PostRequirements, StructuredStyles,
+    StructuredStylesResponse, StructuredWidgets, Stylesheet, SubredditData,
+    SubredditRemovalReasons, SubredditResponse, SubredditSettings, SubredditSettingsResponse,
+    SubredditsData, TrendingSubreddits, WelcomeMessage, Widget, WikiPageSettings, WikiPermLevel,
 };
 
+use crate::builders::ban::BanOptions;
+use crate::builders::flair::FlairTemplateOptions;
 use crate::builders::form::FormBuilder;
-use crate::builders::submission::SubmissionSubmitBuilder;
+use crate::builders::submission::{PayloadGallery, PayloadImage, SubmissionSubmitBuilder};
 use crate::models::comment::{ArticleComments, LatestComments};
-use crate::models::modqueue::Modqueue;
+use crate::models::modmail::ModmailConversation;
+use crate::models::modqueue::{Modqueue, QueueThing};
 use crate::models::submission::Submissions;
-use crate::models::{FromClientAndData, Listing, Submission, SubmissionStickySlot};
-use crate::util::error::RouxErrorKind;
+use crate::models::{
+    BannedUser, Contributor, FromClientAndData, Listing, MutedUser, Submission,
+    SubmissionStickySlot, SubmissionWithComments, SubredditAbout,
+};
 use crate::util::ser_enumstr::get_enum_name;
-use crate::util::{FeedOption, RouxError};
+use crate::util::{FeedOption, RouxError, SearchOptions};
 
-use crate::api::response::BasicListing as APIListing;
-use crate::api::{Moderators, ThingFullname};
+use crate::api::response::{
+    BasicListing as APIListing, BasicThing, OuterBasicListing, PostResponse,
+};
+use crate::api::{FlairId, ModPermission, Moderators, ThingFullname};
 
 use super::endpoint::EndpointBuilder;
 use super::traits::RedditClient;
-use super::AuthedClient;
+use super::{AuthedClient, ModmailSort, ModmailState, SelectFlairTarget, SubredditRelationship};
 
 /// Access subreddits API
 pub struct Subreddits<T>(pub(crate) T);
@@ -108,6 +121,14 @@ impl<T: RedditClient> Subreddits<T> {
 
         self.0.get_json(url).await
     }
+
+    /// Gets the day's trending subreddits, and the thread discussing them.
+    #[maybe_async::maybe_async]
+    pub async fn trending(&self) -> Result<TrendingSubreddits, RouxError> {
+        self.0
+            .get_json(EndpointBuilder::new("api/trending_subreddits"))
+            .await
+    }
 }
 
 /// Subreddit
@@ -115,6 +136,8 @@ impl<T: RedditClient> Subreddits<T> {
 pub struct Subreddit<T> {
     /// Name of subreddit, prefixed with /r/.
     name_prefixed: String,
+    /// The region to filter listings to, only used by `r/popular`.
+    geo_filter: Option<String>,
     /// The reddit client used.
     pub client: T,
 }
@@ -143,20 +166,56 @@ impl<T: RedditClient + Clone> Subreddit<T> {
 
         Subreddit {
             name_prefixed: name,
+            geo_filter: None,
             client,
         }
     }
 
+    /// Create a `Subreddit` instance for `r/all`, which has posts from all public subreddits.
+    pub fn all(client: T) -> Subreddit<T> {
+        Subreddit::new("all", client)
+    }
+
+    /// Create a `Subreddit` instance for `r/popular`, which has the most popular posts.
+    ///
+    /// Use [`Subreddit::with_geo_filter`] to get the posts popular in a region.
+    pub fn popular(client: T) -> Subreddit<T> {
+        Subreddit::new("popular", client)
+    }
+
+    /// Filters listings to a region, such as `GB` or `US_CA`, or `GLOBAL` for everywhere.
+    ///
+    /// This only has an effect on `r/popular`.
+    pub fn with_geo_filter(mut self, region: impl Into<String>) -> Subreddit<T> {
+        self.geo_filter = Some(region.into());
+        self
+    }
+
     pub(crate) fn endpoint(&self, endpoint: impl Into<EndpointBuilder>) -> EndpointBuilder {
         EndpointBuilder::new(format!("{}/", self.name_prefixed)).join(endpoint)
     }
 
     /// Get subreddit data.
     #[maybe_async::maybe_async]
-    pub async fn about(&self) -> Result<SubredditData, RouxError> {
+    pub async fn about(&self) -> Result<SubredditAbout<T>, RouxError> {
         let endpoint = self.endpoint("about");
         let resp: SubredditResponse = self.client.get_json(endpoint).await?;
-        Ok(resp.data)
+        Ok(SubredditAbout::new(self.client.clone(), resp.data))
+    }
+
+    /// Get the subreddit's theme (banner, icon and colors) and menu links.
+    #[maybe_async::maybe_async]
+    pub async fn structured_styles(&self) -> Result<StructuredStyles, RouxError> {
+        let endpoint = format!("api/v1/structured_styles/{}", self.name());
+        let response: StructuredStylesResponse = self.client.get_json(endpoint).await?;
+        Ok(response.data)
+    }
+
+    /// Get the subreddit's widgets.
+    #[maybe_async::maybe_async]
+    pub async fn widgets(&self) -> Result<StructuredWidgets, RouxError> {
+        let endpoint = self.endpoint("api/widgets");
+        self.client.get_json(endpoint).await
     }
 
     #[maybe_async::maybe_async]
@@ -165,14 +224,18 @@ impl<T: RedditClient + Clone> Subreddit<T> {
         ty: &str,
         options: Option<FeedOption>,
     ) -> Result<Submissions<T>, RouxError> {
-        let mut endpoint = self.endpoint(format!("{ty}"));
+        let mut source = self.endpoint(format!("{ty}"));
+        if let Some(region) = &self.geo_filter {
+            source.with_query("geo_filter", region);
+        }
+        let mut endpoint = source.clone();
 
-        if let Some(options) = options {
+        if let Some(options) = options.clone() {
             options.build_url(&mut endpoint);
         }
 
         let api: crate::api::APISubmissions = self.client.get_json(endpoint).await?;
-        let listing = Listing::new(api, self.client.clone());
+        let listing = Listing::new(api, self.client.clone()).with_source(source, options);
 
         Ok(listing)
     }
@@ -195,24 +258,94 @@ impl<T: RedditClient + Clone> Subreddit<T> {
         self.get_feed("top", options).await
     }
 
+    /// Get controversial posts.
+    ///
+    /// Use [`FeedOption::period`] to choose how far back to look, Reddit defaults to the last day.
+    #[maybe_async::maybe_async]
+    pub async fn controversial(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<T>, RouxError> {
+        self.get_feed("controversial", options).await
+    }
+
     /// Get latest posts.
     #[maybe_async::maybe_async]
     pub async fn latest(&self, options: Option<FeedOption>) -> Result<Submissions<T>, RouxError> {
         self.get_feed("new", options).await
     }
 
+    /// Get a random submission from this subreddit, with its comments.
+    ///
+    /// As with [`RedditClient::submission_with_comments`], the submission is still returned if
+    /// the comments fail to parse.
+    #[maybe_async::maybe_async]
+    pub async fn random(&self) -> Result<SubmissionWithComments<T>, RouxError> {
+        let response: crate::api::comment::PartialArticleCommentsResponse =
+            self.client.get_json(self.endpoint("random")).await?;
+
+        Ok(SubmissionWithComments::new(self.client.clone(), response))
+    }
+
+    /// Get gilded submissions and comments, most recently gilded first.
+    #[maybe_async::maybe_async]
+    pub async fn gilded(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Listing<QueueThing<T>>, RouxError> {
+        let source = self.endpoint("gilded");
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut endpoint);
+        }
+
+        let response: crate::api::subreddit::ModQueueItems = self.client.get_json(endpoint).await?;
+
+        Ok(Listing::new_outer(response, self.client.clone()).with_source(source, options))
+    }
+
+    /// Searches submissions in this subreddit.
+    ///
+    /// To search across all of Reddit, use [`RedditClient::search`].
+    #[maybe_async::maybe_async]
+    pub async fn search(
+        &self,
+        query: &str,
+        options: Option<SearchOptions>,
+    ) -> Result<Submissions<T>, RouxError> {
+        let (source, feed) = options
+            .unwrap_or_default()
+            .build_search(self.endpoint("search").query("restrict_sr", "on"), query);
+        let mut endpoint = source.clone();
+        feed.clone().build_url(&mut endpoint);
+
+        let api: crate::api::APISubmissions = self.client.get_json(endpoint).await?;
+        Ok(Listing::new(api, self.client.clone()).with_source(source, Some(feed)))
+    }
+
     /// Gets things requiring moderator review.
     #[maybe_async::maybe_async]
     pub async fn modqueue(&self, options: Option<FeedOption>) -> Result<Modqueue<T>, RouxError> {
-        let mut endpoint = self.endpoint("about/modqueue");
+        self.mod_queue("modqueue", options).await
+    }
 
-        if let Some(options) = options {
+    #[maybe_async::maybe_async]
+    async fn mod_queue(
+        &self,
+        queue: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Modqueue<T>, RouxError> {
+        let source = self.endpoint(format!("about/{queue}"));
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
             options.build_url(&mut endpoint);
         }
 
         let response: crate::api::subreddit::ModQueueItems = self.client.get_json(endpoint).await?;
 
-        Ok(Listing::new_outer(response, self.client.clone()))
+        Ok(Listing::new_outer(response, self.client.clone()).with_source(source, options))
     }
 
     /// Get latest comments.
@@ -256,39 +389,35 @@ impl<T: RedditClient + Clone> Subreddit<T> {
             .await
     }
 
-    /// Fetches the stickied post on the subreddit, if there is one.
+    /// Fetches the stickied post in the slot, if there is one.
+    ///
+    /// Stickied posts are listed first in `hot`, so only the first two posts are fetched.
     #[maybe_async::maybe_async]
     pub async fn sticky(
         &self,
         slot: SubmissionStickySlot,
     ) -> Result<Option<Submission<T>>, RouxError> {
-        let mut url = self.endpoint("about/sticky");
-
-        match slot {
-            SubmissionStickySlot::Top => (),
-            SubmissionStickySlot::Bottom => {
-                url.with_query("num", "2");
-            }
-        }
-
-        let response = match self.client.get(url).await {
-            Ok(response) => response,
-            Err(error) => match error.kind {
-                RouxErrorKind::FullNetwork(response, _)
-                    if response.status() == StatusCode::NOT_FOUND =>
-                {
-                    return Ok(None)
-                }
-                _ => return Err(error),
-            },
+        let index = match slot {
+            SubmissionStickySlot::Top => 0,
+            SubmissionStickySlot::Bottom => 1,
         };
 
-        let data: crate::api::comment::ArticleCommentsResponseWithoutComments =
-            response.json().await?;
-
-        let post = Submission::new(self.client.clone(), data.submission);
+        let hot = self.hot(Some(FeedOption::new().limit(2))).await?;
+        Ok(hot
+            .children
+            .into_iter()
+            .filter(|post| post.stickied())
+            .nth(index))
+    }
 
-        Ok(Some(post))
+    /// Fetches the fullname of the stickied post in the slot, if there is one.
+    #[maybe_async::maybe_async]
+    pub async fn sticky_fullname(
+        &self,
+        slot: SubmissionStickySlot,
+    ) -> Result<Option<ThingFullname>, RouxError> {
+        let post = self.sticky(slot).await?;
+        Ok(post.map(|post| post.name().clone()))
     }
 }
 
@@ -300,6 +429,211 @@ impl Subreddit<AuthedClient> {
         }
     }
 
+    /// Accesses the newer moderator tools for this subreddit, such as the welcome message.
+    pub fn community_settings(&self) -> CommunitySettings<AuthedClient> {
+        CommunitySettings {
+            subreddit: self.clone(),
+        }
+    }
+
+    /// Gets the subreddit's settings, such as the submission types allowed, spam filters and
+    /// wiki mode.
+    #[maybe_async::maybe_async]
+    pub async fn settings(&self) -> Result<SubredditSettings, RouxError> {
+        self.community_settings().get().await
+    }
+
+    /// Sets the order comments are sorted in by default, see
+    /// [`CommunitySettings::set_default_comment_sort`].
+    #[maybe_async::maybe_async]
+    pub async fn set_default_comment_sort(
+        &self,
+        sort: Option<CommentSort>,
+    ) -> Result<(), RouxError> {
+        self.community_settings()
+            .set_default_comment_sort(sort)
+            .await
+    }
+
+    /// Bans a user from this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn ban_user(&self, username: &str, options: &BanOptions) -> Result<(), RouxError> {
+        self.client.ban_user(username, self.name(), options).await
+    }
+
+    /// Unbans a user from this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn unban_user(&self, username: &str) -> Result<(), RouxError> {
+        self.client.unban_user(username, self.name()).await
+    }
+
+    /// Invites a user to moderate this subreddit, with only the given permissions.
+    #[maybe_async::maybe_async]
+    pub async fn invite_moderator(
+        &self,
+        username: &str,
+        permissions: &[ModPermission],
+    ) -> Result<(), RouxError> {
+        self.client
+            .invite_moderator(username, self.name(), permissions)
+            .await
+    }
+
+    /// Withdraws a pending invitation for a user to moderate this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn uninvite_moderator(&self, username: &str) -> Result<(), RouxError> {
+        self.client.uninvite_moderator(username, self.name()).await
+    }
+
+    /// Removes a moderator from this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn remove_moderator(&self, username: &str) -> Result<(), RouxError> {
+        self.client.remove_moderator(username, self.name()).await
+    }
+
+    /// Replaces the permissions of a moderator of this subreddit, or of a pending invitation if
+    /// `invited` is set.
+    #[maybe_async::maybe_async]
+    pub async fn set_moderator_permissions(
+        &self,
+        username: &str,
+        permissions: &[ModPermission],
+        invited: bool,
+    ) -> Result<(), RouxError> {
+        self.client
+            .set_moderator_permissions(username, self.name(), permissions, invited)
+            .await
+    }
+
+    /// Gets submissions and comments which no moderator has approved or removed yet.
+    #[maybe_async::maybe_async]
+    pub async fn unmoderated(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Modqueue<AuthedClient>, RouxError> {
+        self.mod_queue("unmoderated", options).await
+    }
+
+    /// Gets submissions and comments which have been removed, or caught by the spam filter.
+    #[maybe_async::maybe_async]
+    pub async fn spam(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Modqueue<AuthedClient>, RouxError> {
+        self.mod_queue("spam", options).await
+    }
+
+    /// Gets submissions and comments which have been edited, most recently edited first.
+    #[maybe_async::maybe_async]
+    pub async fn edited(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Modqueue<AuthedClient>, RouxError> {
+        self.mod_queue("edited", options).await
+    }
+
+    /// Gets submissions and comments which have been reported.
+    #[maybe_async::maybe_async]
+    pub async fn reports(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Modqueue<AuthedClient>, RouxError> {
+        self.mod_queue("reports", options).await
+    }
+
+    /// Get the users banned from this subreddit, most recently banned first.
+    #[maybe_async::maybe_async]
+    pub async fn banned(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Listing<BannedUser<AuthedClient>>, RouxError> {
+        let source = self.endpoint("about/banned");
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut endpoint);
+        }
+
+        let api: OuterBasicListing<BannedUserData> = self.client.get_json(endpoint).await?;
+        let listing = Listing::new_outer(api, self.client.clone()).with_source(source, options);
+
+        Ok(listing)
+    }
+
+    /// Get the users muted in this subreddit's modmail, most recently muted first.
+    #[maybe_async::maybe_async]
+    pub async fn muted(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Listing<MutedUser<AuthedClient>>, RouxError> {
+        let source = self.endpoint("about/muted");
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut endpoint);
+        }
+
+        let api: OuterBasicListing<MutedUserData> = self.client.get_json(endpoint).await?;
+        let listing = Listing::new_outer(api, self.client.clone()).with_source(source, options);
+
+        Ok(listing)
+    }
+
+    /// Mutes a user in this subreddit's modmail.
+    #[maybe_async::maybe_async]
+    pub async fn mute_user(&self, username: &str) -> Result<(), RouxError> {
+        self.client
+            .add_subreddit_relationship(username, SubredditRelationship::Muted, self.name())
+            .await
+    }
+
+    /// Unmutes a user in this subreddit's modmail.
+    #[maybe_async::maybe_async]
+    pub async fn unmute_user(&self, username: &str) -> Result<(), RouxError> {
+        self.client
+            .remove_subreddit_relationship(username, SubredditRelationship::Muted, self.name())
+            .await
+    }
+
+    /// Get the approved submitters of this subreddit, most recently approved first.
+    #[maybe_async::maybe_async]
+    pub async fn contributors(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Listing<Contributor<AuthedClient>>, RouxError> {
+        let source = self.endpoint("about/contributors");
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut endpoint);
+        }
+
+        let api: OuterBasicListing<ContributorData> = self.client.get_json(endpoint).await?;
+        let listing = Listing::new_outer(api, self.client.clone()).with_source(source, options);
+
+        Ok(listing)
+    }
+
+    /// Approves a user to submit to this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn add_contributor(&self, username: &str) -> Result<(), RouxError> {
+        self.client
+            .add_subreddit_relationship(username, SubredditRelationship::Contributor, self.name())
+            .await
+    }
+
+    /// Removes a user's approval to submit to this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn remove_contributor(&self, username: &str) -> Result<(), RouxError> {
+        self.client
+            .remove_subreddit_relationship(
+                username,
+                SubredditRelationship::Contributor,
+                self.name(),
+            )
+            .await
+    }
+
     /// Get moderators (requires authentication)
     #[maybe_async::maybe_async]
     pub async fn moderators(&self) -> Result<Moderators, RouxError> {
@@ -307,6 +641,212 @@ impl Subreddit<AuthedClient> {
         self.client.get_json(endpoint).await
     }
 
+    /// Subscribes to this subreddit.
+    ///
+    /// See [`AuthedClient::subscribe`] for `skip_initial_defaults`.
+    #[maybe_async::maybe_async]
+    pub async fn subscribe(&self, skip_initial_defaults: bool) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("action", "sub")
+            .with("sr_name", self.name())
+            .with_bool("skip_initial_defaults", skip_initial_defaults);
+        self.client.post("api/subscribe", &form).await?;
+        Ok(())
+    }
+
+    /// Unsubscribes from this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn unsubscribe(&self) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("action", "unsub")
+            .with("sr_name", self.name());
+        self.client.post("api/subscribe", &form).await?;
+        Ok(())
+    }
+
+    /// Adds a menu widget to the subreddit's menu bar.
+    #[maybe_async::maybe_async]
+    pub async fn add_menu_widget(
+        &self,
+        links: &[MenuLink],
+        show_wiki: bool,
+    ) -> Result<MenuWidget, RouxError> {
+        let body = MenuWidgetRequest::new(links, show_wiki);
+        self.client
+            .send_json(Method::POST, self.endpoint("api/widget"), &body)
+            .await
+    }
+
+    /// Replaces the links of a menu widget.
+    #[maybe_async::maybe_async]
+    pub async fn update_menu_widget(
+        &self,
+        id: &str,
+        links: &[MenuLink],
+        show_wiki: bool,
+    ) -> Result<MenuWidget, RouxError> {
+        let body = MenuWidgetRequest::new(links, show_wiki);
+        self.client
+            .send_json(
+                Method::PUT,
+                self.endpoint(format!("api/widget/{id}")),
+                &body,
+            )
+            .await
+    }
+
+    /// Adds a widget to the subreddit's sidebar, returning the created widget.
+    #[maybe_async::maybe_async]
+    pub async fn add_widget(&self, widget: &Widget) -> Result<Widget, RouxError> {
+        self.client
+            .send_json(Method::POST, self.endpoint("api/widget"), widget)
+            .await
+    }
+
+    /// Replaces a widget, returning the updated widget.
+    ///
+    /// The widget's kind cannot be changed.
+    #[maybe_async::maybe_async]
+    pub async fn update_widget(&self, id: &str, widget: &Widget) -> Result<Widget, RouxError> {
+        self.client
+            .send_json(
+                Method::PUT,
+                self.endpoint(format!("api/widget/{id}")),
+                widget,
+            )
+            .await
+    }
+
+    /// Reorders the widgets in the sidebar. `order` must contain the IDs of all of the
+    /// sidebar's widgets.
+    #[maybe_async::maybe_async]
+    pub async fn reorder_sidebar_widgets(&self, order: &[&str]) -> Result<(), RouxError> {
+        self.client
+            .patch_json(self.endpoint("api/widget_order/sidebar"), &order)
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a widget.
+    #[maybe_async::maybe_async]
+    pub async fn delete_widget(&self, id: &str) -> Result<(), RouxError> {
+        self.client
+            .delete(self.endpoint(format!("api/widget/{id}")))
+            .await?;
+        Ok(())
+    }
+
+    /// Get the subreddit's old Reddit stylesheet and the images it can use.
+    #[maybe_async::maybe_async]
+    pub async fn stylesheet(&self) -> Result<Stylesheet, RouxError> {
+        let response: BasicThing<Stylesheet> = self
+            .client
+            .get_json(self.endpoint("about/stylesheet"))
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Replaces the subreddit's old Reddit stylesheet.
+    ///
+    /// `reason` is shown in the stylesheet's revision history. Reddit rejects CSS it cannot parse
+    /// or which uses images that have not been uploaded.
+    #[maybe_async::maybe_async]
+    pub async fn update_stylesheet(
+        &self,
+        css: &str,
+        reason: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("op", "save")
+            .with("stylesheet_contents", css)
+            .with_opt("reason", reason);
+
+        let response: PostResponse<IgnoredAny> = self
+            .client
+            .post_with_response_raw(self.endpoint("api/subreddit_stylesheet"), &form)
+            .await?;
+        if response.json.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(RouxError::reddit_error(response.json.errors))
+        }
+    }
+
+    /// Get the settings of a wiki page.
+    #[maybe_async::maybe_async]
+    pub async fn wiki_page_settings(&self, page: &str) -> Result<WikiPageSettings, RouxError> {
+        let endpoint = self.endpoint(format!("wiki/settings/{page}"));
+        let response: BasicThing<WikiPageSettings> = self.client.get_json(endpoint).await?;
+        Ok(response.data)
+    }
+
+    /// Changes who can edit a wiki page and whether it is listed, returning the new settings.
+    #[maybe_async::maybe_async]
+    pub async fn set_wiki_page_settings(
+        &self,
+        page: &str,
+        permlevel: WikiPermLevel,
+        listed: bool,
+    ) -> Result<WikiPageSettings, RouxError> {
+        let form = FormBuilder::new()
+            .with("page", page)
+            .with("permlevel", permlevel.as_str())
+            .with_bool("listed", listed);
+
+        let response: BasicThing<WikiPageSettings> = self
+            .client
+            .post_with_response_raw(self.endpoint(format!("wiki/settings/{page}")), &form)
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Approves a user to edit a wiki page.
+    #[maybe_async::maybe_async]
+    pub async fn add_wiki_editor(&self, page: &str, username: &str) -> Result<(), RouxError> {
+        self.wiki_editor("add", page, username).await
+    }
+
+    /// Removes a user's approval to edit a wiki page.
+    #[maybe_async::maybe_async]
+    pub async fn remove_wiki_editor(&self, page: &str, username: &str) -> Result<(), RouxError> {
+        self.wiki_editor("del", page, username).await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn wiki_editor(&self, act: &str, page: &str, username: &str) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("page", page)
+            .with("username", username);
+
+        self.client
+            .post(self.endpoint(format!("api/wiki/alloweditor/{act}")), &form)
+            .await?;
+        Ok(())
+    }
+
+    /// Get the requirements placed on new posts to this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn post_requirements(&self) -> Result<PostRequirements, RouxError> {
+        let endpoint = format!("api/v1/{}/post_requirements", self.name());
+        self.client.get_json(endpoint).await
+    }
+
+    /// Checks whether the logged in user can submit to this subreddit, and if so, what
+    /// requirements their post must meet.
+    ///
+    /// The post requirements are only fetched if the user can submit.
+    #[maybe_async::maybe_async]
+    pub async fn can_submit(&self) -> Result<SubmitPermission, RouxError> {
+        let about = self.about().await?;
+
+        if let Some(denied) = SubmitDenied::from_about(&about) {
+            return Ok(SubmitPermission::Denied(denied));
+        }
+
+        let requirements = self.post_requirements().await?;
+        Ok(SubmitPermission::Allowed(Box::new(requirements)))
+    }
+
     /// Submits a post to this subreddit
     #[maybe_async::maybe_async]
     pub async fn submit<Kind: Serialize>(
@@ -316,6 +856,29 @@ impl Subreddit<AuthedClient> {
         self.client.submit(self.name(), submission).await
     }
 
+    /// Uploads the image and submits it as a post to this subreddit, without waiting for the post
+    /// to be created.
+    ///
+    /// See [`AuthedClient::submit_image_lazy`].
+    #[maybe_async::maybe_async]
+    pub async fn submit_image_lazy(
+        &self,
+        submission: &SubmissionSubmitBuilder<PayloadImage>,
+    ) -> Result<MediaSubmitted, RouxError> {
+        self.client.submit_image_lazy(self.name(), submission).await
+    }
+
+    /// Submits a gallery of uploaded images as a post to this subreddit.
+    ///
+    /// See [`AuthedClient::submit_gallery`].
+    #[maybe_async::maybe_async]
+    pub async fn submit_gallery(
+        &self,
+        submission: &SubmissionSubmitBuilder<PayloadGallery>,
+    ) -> Result<SubmittedGallery, RouxError> {
+        self.client.submit_gallery(self.name(), submission).await
+    }
+
     /// List possible flair options in this subreddit
     #[maybe_async::maybe_async]
     pub async fn list_flairs(&self, selecting: FlairSelector) -> Result<FlairSelection, RouxError> {
@@ -332,6 +895,92 @@ impl Subreddit<AuthedClient> {
         Ok(got)
     }
 
+    /// Sets the flair text and CSS class of a user or post directly, without a template.
+    ///
+    /// This is for subreddits which do not use flair templates; otherwise use
+    /// [`AuthedClient::select_flair`]. Passing `None` for both clears the flair.
+    #[maybe_async::maybe_async]
+    pub async fn set_flair_raw(
+        &self,
+        target: SelectFlairTarget,
+        text: Option<&str>,
+        css_class: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let mut form = FormBuilder::new()
+            .with_opt("text", text)
+            .with_opt("css_class", css_class);
+
+        match &target {
+            SelectFlairTarget::Link(thing_id) => form.add("link", thing_id.full()),
+            SelectFlairTarget::User(name) => form.add("name", name.as_str()),
+        }
+
+        self.client.post(self.endpoint("api/flair"), &form).await?;
+        Ok(())
+    }
+
+    /// Removes a user's flair in the subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn delete_user_flair(&self, user: &str) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("name", user);
+        self.client
+            .post(self.endpoint("api/deleteflair"), &form)
+            .await?;
+        Ok(())
+    }
+
+    /// Lists the subreddit's post or user flair templates.
+    #[maybe_async::maybe_async]
+    pub async fn list_flair_templates(
+        &self,
+        kind: FlairType,
+    ) -> Result<Vec<FlairTemplate>, RouxError> {
+        self.client
+            .get_json(self.endpoint(kind.templates_endpoint()))
+            .await
+    }
+
+    /// Creates a post or user flair template, returning it.
+    #[maybe_async::maybe_async]
+    pub async fn create_flair_template(
+        &self,
+        kind: FlairType,
+        options: &FlairTemplateOptions,
+    ) -> Result<FlairTemplate, RouxError> {
+        let form = options.add_to(FormBuilder::new().with("flair_type", kind.as_str()));
+        self.client
+            .post_with_response_raw(self.endpoint("api/flairtemplate_v2"), &form)
+            .await
+    }
+
+    /// Replaces the settings of a flair template, returning the updated template.
+    #[maybe_async::maybe_async]
+    pub async fn update_flair_template(
+        &self,
+        kind: FlairType,
+        id: &FlairId,
+        options: &FlairTemplateOptions,
+    ) -> Result<FlairTemplate, RouxError> {
+        let form = options.add_to(
+            FormBuilder::new()
+                .with("flair_type", kind.as_str())
+                .with("flair_template_id", id.as_str()),
+        );
+        self.client
+            .post_with_response_raw(self.endpoint("api/flairtemplate_v2"), &form)
+            .await
+    }
+
+    /// Deletes a flair template.
+    #[maybe_async::maybe_async]
+    pub async fn delete_flair_template(&self, id: &FlairId) -> Result<(), RouxError> {
+        let form = FormBuilder::new().with("flair_template_id", id.as_str());
+        self.client
+            .post(self.endpoint("api/deleteflairtemplate"), &form)
+            .await?;
+        Ok(())
+    }
+
     /// Lists flairs assigned to users in the subreddit.
     #[maybe_async::maybe_async]
     pub async fn list_user_flairs(
@@ -353,21 +1002,89 @@ impl Subreddit<AuthedClient> {
     }
 
     /// Accepts an invite to become a moderator for this subreddit. Must have been invited by a current moderator.
+    ///
+    /// If there is no invite, or the account already moderates this subreddit, the error's
+    /// [`api_error_code`](RouxError::api_error_code) is
+    /// [`ApiErrorCode::NoInviteFound`](crate::api::response::ApiErrorCode::NoInviteFound) or
+    /// [`ApiErrorCode::AlreadyModerator`](crate::api::response::ApiErrorCode::AlreadyModerator).
     #[maybe_async::maybe_async]
     pub async fn accept_moderator_invite(&self) -> Result<(), RouxError> {
         let form = FormBuilder::new();
 
         let url = self.endpoint("api/accept_moderator_invite");
-        self.client.post(url, &form).await?;
-        Ok(())
+        let response: PostResponse<IgnoredAny> =
+            self.client.post_with_response_raw(url, &form).await?;
+        if response.json.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(RouxError::reddit_error(response.json.errors))
+        }
     }
 
     /// Returns a list of removal reasons for this subreddit.
     #[maybe_async::maybe_async]
     pub async fn list_removal_reasons(&self) -> Result<SubredditRemovalReasons, RouxError> {
-        let url =
-            EndpointBuilder::new(format!("api/v1/{name}/removal_reasons", name = self.name()));
-        self.client.get_json(url).await
+        self.client
+            .get_json(self.removal_reasons_endpoint(""))
+            .await
+    }
+
+    /// Creates a removal reason, returning its ID.
+    #[maybe_async::maybe_async]
+    pub async fn create_removal_reason(
+        &self,
+        title: &str,
+        message: &str,
+    ) -> Result<String, RouxError> {
+        let form = FormBuilder::new()
+            .with("title", title)
+            .with("message", message);
+
+        let created: CreatedRemovalReason = self
+            .client
+            .post_with_response_raw(self.removal_reasons_endpoint(""), &form)
+            .await?;
+        Ok(created.id)
+    }
+
+    /// Changes the title and message of a removal reason.
+    #[maybe_async::maybe_async]
+    pub async fn update_removal_reason(
+        &self,
+        id: &str,
+        title: &str,
+        message: &str,
+    ) -> Result<(), RouxError> {
+        let form = FormBuilder::new()
+            .with("title", title)
+            .with("message", message);
+
+        self.client
+            .put(self.removal_reasons_endpoint(&format!("/{id}")), &form)
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a removal reason.
+    #[maybe_async::maybe_async]
+    pub async fn delete_removal_reason(&self, id: &str) -> Result<(), RouxError> {
+        self.client
+            .delete(self.removal_reasons_endpoint(&format!("/{id}")))
+            .await?;
+        Ok(())
+    }
+
+    /// Sets the order removal reasons appear in. Every removal reason's ID must be included.
+    #[maybe_async::maybe_async]
+    pub async fn reorder_removal_reasons(&self, ids: &[&str]) -> Result<(), RouxError> {
+        self.client
+            .patch_json(self.removal_reasons_endpoint(""), &ids)
+            .await?;
+        Ok(())
+    }
+
+    fn removal_reasons_endpoint(&self, rest: &str) -> EndpointBuilder {
+        EndpointBuilder::new(format!("api/v1/{}/removal_reasons{rest}", self.name()))
     }
 
     /// Returns a list of mod actions taken
@@ -462,6 +1179,146 @@ impl SubModmail<AuthedClient> {
 
         Ok(())
     }
+
+    /// Lists this subreddit's modmail conversations, see [`AuthedClient::modmail_conversations`].
+    #[maybe_async::maybe_async]
+    pub async fn conversations(
+        &self,
+        state: ModmailState,
+        sort: ModmailSort,
+        options: Option<FeedOption>,
+    ) -> Result<Vec<ModmailConversation<AuthedClient>>, RouxError> {
+        self.subreddit
+            .client
+            .modmail_conversations(&[self.subreddit.name()], state, sort, options)
+            .await
+    }
+}
+
+/// A helper struct to manage a subreddit's settings from the newer moderator tools, created with
+/// [`Subreddit::community_settings`].
+pub struct CommunitySettings<T> {
+    subreddit: Subreddit<T>,
+}
+
+impl CommunitySettings<AuthedClient> {
+    /// Gets the subreddit's settings.
+    #[maybe_async::maybe_async]
+    pub async fn get(&self) -> Result<SubredditSettings, RouxError> {
+        let response: SubredditSettingsResponse = self
+            .subreddit
+            .client
+            .get_json(self.subreddit.endpoint("about/edit"))
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Replaces the subreddit's settings.
+    ///
+    /// Every setting is sent, so get the current settings with [`CommunitySettings::get`] and
+    /// change those, rather than building them from scratch.
+    #[maybe_async::maybe_async]
+    pub async fn update(&self, settings: &SubredditSettings) -> Result<(), RouxError> {
+        let response: PostResponse<IgnoredAny> = self
+            .subreddit
+            .client
+            .post_with_response_raw("api/site_admin", &settings.site_admin_form())
+            .await?;
+        if response.json.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(RouxError::reddit_error(response.json.errors))
+        }
+    }
+
+    /// Gets the message sent to new members of the subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn welcome_message(&self) -> Result<WelcomeMessage, RouxError> {
+        Ok(self.get().await?.welcome_message())
+    }
+
+    /// Sets the message sent to new members of the subreddit, leaving the other settings as
+    /// they are.
+    #[maybe_async::maybe_async]
+    pub async fn set_welcome_message(&self, message: &WelcomeMessage) -> Result<(), RouxError> {
+        let mut settings = self.get().await?;
+        settings.welcome_message_enabled = message.enabled;
+        settings.welcome_message_text = Some(message.text.clone());
+        self.update(&settings).await
+    }
+
+    /// Gets the requirements posts in the subreddit must meet, see
+    /// [`Subreddit::post_requirements`].
+    #[maybe_async::maybe_async]
+    pub async fn post_requirements(&self) -> Result<PostRequirements, RouxError> {
+        self.subreddit.post_requirements().await
+    }
+
+    /// Gets the order comments are sorted in by default, if one is set.
+    #[maybe_async::maybe_async]
+    pub async fn default_comment_sort(&self) -> Result<Option<CommentSort>, RouxError> {
+        Ok(self.get().await?.suggested_comment_sort)
+    }
+
+    /// Sets the order comments are sorted in by default, or `None` for Reddit's default,
+    /// leaving the other settings as they are.
+    #[maybe_async::maybe_async]
+    pub async fn set_default_comment_sort(
+        &self,
+        sort: Option<CommentSort>,
+    ) -> Result<(), RouxError> {
+        let mut settings = self.get().await?;
+        settings.suggested_comment_sort = sort;
+        self.update(&settings).await
+    }
+
+    /// Gets the subreddit's removal reasons, see [`Subreddit::list_removal_reasons`].
+    #[maybe_async::maybe_async]
+    pub async fn removal_reasons(&self) -> Result<SubredditRemovalReasons, RouxError> {
+        self.subreddit.list_removal_reasons().await
+    }
+}
+
+/// Whether a user can submit to a subreddit, as returned by [`Subreddit::can_submit`].
+#[derive(Debug)]
+pub enum SubmitPermission {
+    /// Posts can be submitted, but must meet these requirements.
+    Allowed(Box<PostRequirements>),
+    /// Posts would be rejected.
+    Denied(SubmitDenied),
+}
+
+/// Why a user cannot submit to a subreddit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitDenied {
+    /// The user is banned from the subreddit.
+    Banned,
+    /// The subreddit is restricted or private, and the user is not an approved user.
+    NotContributor,
+    /// The subreddit is archived, so no new posts can be made.
+    Archived,
+    /// The subreddit can only be posted to by Reddit employees.
+    EmployeesOnly,
+}
+
+impl SubmitDenied {
+    fn from_about(about: &SubredditData) -> Option<SubmitDenied> {
+        if about.user_is_moderator == Some(true) {
+            return None;
+        }
+
+        if about.user_is_banned == Some(true) {
+            return Some(SubmitDenied::Banned);
+        }
+
+        let is_contributor = about.user_is_contributor == Some(true);
+        match about.subreddit_type.as_deref() {
+            Some("archived") => Some(SubmitDenied::Archived),
+            Some("employees_only") => Some(SubmitDenied::EmployeesOnly),
+            Some("restricted" | "private") if !is_contributor => Some(SubmitDenied::NotContributor),
+            _ => None,
+        }
+    }
 }
 
 /// Who or what the modmail should be sent to
@@ -476,10 +1333,44 @@ pub enum ModmailTo {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
+    use super::SubmitDenied;
+    use crate::api::subreddit::SubredditData;
     use crate::client::noauth::UnauthedClient;
     use crate::client::traits::RedditClient;
     use crate::util::FeedOption;
 
+    #[test]
+    fn test_submit_denied() {
+        let about = |extra: serde_json::Value| -> SubredditData {
+            let mut value = json!({ "display_name": "sub", "id": "1", "name": "t5_1" });
+            value
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(value).unwrap()
+        };
+
+        let public = about(json!({ "subreddit_type": "public" }));
+        assert_eq!(SubmitDenied::from_about(&public), None);
+
+        let banned = about(json!({ "subreddit_type": "public", "user_is_banned": true }));
+        assert_eq!(
+            SubmitDenied::from_about(&banned),
+            Some(SubmitDenied::Banned)
+        );
+
+        let restricted = about(json!({ "subreddit_type": "restricted" }));
+        assert_eq!(
+            SubmitDenied::from_about(&restricted),
+            Some(SubmitDenied::NotContributor)
+        );
+
+        let approved = about(json!({ "subreddit_type": "private", "user_is_contributor": true }));
+        assert_eq!(SubmitDenied::from_about(&approved), None);
+    }
+
     #[maybe_async::async_impl]
     #[tokio::test]
     async fn test_no_auth() {
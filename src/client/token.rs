@@ -0,0 +1,57 @@
+//! # Token persistence
+//! Hooks to persist the access token obtained by an [`AuthedClient`](crate::client::AuthedClient)
+//! so it can be re-used across process restarts instead of logging in each time.
+
+/// Somewhere an access token can be saved to and restored from.
+///
+/// Register a store with [`Config::token_store`](crate::Config::token_store).
+/// [`OAuthClient::login`](crate::client::OAuthClient::login) will first attempt to [`load`](TokenStore::load)
+/// a token, and only log in if none was stored. Whenever a new token is obtained (either on first login,
+/// or because the stored token expired and the client had to log in again), it is passed to [`save`](TokenStore::save).
+pub trait TokenStore: Send + Sync {
+    /// Returns the previously saved access token, if there is one.
+    fn load(&self) -> Option<String>;
+
+    /// Called with the new access token whenever it changes.
+    fn save(&self, access_token: &str);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::TokenStore;
+    use crate::client::OAuthClient;
+    use crate::Config;
+
+    #[derive(Default)]
+    struct MemoryStore(Mutex<Option<String>>);
+
+    impl TokenStore for MemoryStore {
+        fn load(&self) -> Option<String> {
+            self.0.lock().unwrap().clone()
+        }
+
+        fn save(&self, access_token: &str) {
+            *self.0.lock().unwrap() = Some(access_token.to_owned());
+        }
+    }
+
+    #[maybe_async::async_impl]
+    #[tokio::test]
+    async fn test_login_restores_stored_token() {
+        let store = Arc::new(MemoryStore::default());
+        store.save("stored-token");
+
+        let config = Config::new("roux-test", "id", "secret")
+            .username("user")
+            .password("pass")
+            .token_store(store.clone());
+
+        // No request is made, as the stored token is used as-is.
+        let client = OAuthClient::new(config).unwrap().login().await.unwrap();
+
+        assert_eq!(client.access_token(), "stored-token");
+        assert_eq!(store.load().as_deref(), Some("stored-token"));
+    }
+}
@@ -1,16 +1,22 @@
+use std::collections::HashSet;
 use std::future::Future;
 
 use reqwest::Method;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::api::info::InfoItems;
 use crate::api::response::PostResponse;
 use crate::api::{APISubmissions, ArticleCommentData, ThingFullname};
+use crate::builders::form::join_comma;
 use crate::models::comment::ArticleComments;
 use crate::models::submission::Submissions;
-use crate::models::{ArticleComment, FromClientAndData, Listing, Submission, SubmissionLinkInfo};
+use crate::models::{
+    ArticleComment, FromClientAndData, Listing, Submission, SubmissionLinkInfo,
+    SubmissionWithComments, Thing,
+};
 use crate::util::url::build_subreddit;
-use crate::util::RouxError;
+use crate::util::{FeedOption, RouxError, SearchOptions};
 
 use super::endpoint::EndpointBuilder;
 
@@ -52,6 +58,9 @@ pub trait RedditClient {
     /// Builds a request to the endpoint with the particular method
     fn make_req(&self, method: Method, endpoint: &EndpointBuilder) -> RequestBuilder;
 
+    /// Builds a request to a URL outside of Reddit's API, such as an image, without credentials.
+    fn make_external_req(&self, method: Method, url: &str) -> RequestBuilder;
+
     /// Get the endpoint, returning the raw response or an error.
     async fn get(&self, endpoint: impl Into<EndpointBuilder>) -> Result<Response, RouxError> {
         let endpoint: EndpointBuilder = endpoint.into();
@@ -91,6 +100,45 @@ pub trait RedditClient {
         .await
     }
 
+    /// Put the data to the endpoint.
+    async fn put<T: Serialize>(
+        &self,
+        endpoint: impl Into<EndpointBuilder>,
+        form: &T,
+    ) -> Result<Response, RouxError> {
+        let endpoint: EndpointBuilder = endpoint.into();
+        self.execute_with_retries(
+            &|| self.make_req(Method::PUT, &endpoint).form(form),
+            &|response| async { Ok(response) },
+        )
+        .await
+    }
+
+    /// Patch the endpoint with the data as a JSON body.
+    async fn patch_json<T: Serialize>(
+        &self,
+        endpoint: impl Into<EndpointBuilder>,
+        body: &T,
+    ) -> Result<Response, RouxError> {
+        let endpoint: EndpointBuilder = endpoint.into();
+        self.execute_with_retries(
+            &|| self.make_req(Method::PATCH, &endpoint).json(body),
+            &|response| async { Ok(response) },
+        )
+        .await
+    }
+
+    /// Send a DELETE request to the endpoint.
+    async fn delete(&self, endpoint: impl Into<EndpointBuilder>) -> Result<Response, RouxError> {
+        let endpoint: EndpointBuilder = endpoint.into();
+
+        self.execute_with_retries(
+            &|| self.make_req(Method::DELETE, &endpoint),
+            &|response| async { Ok(response) },
+        )
+        .await
+    }
+
     /// Post the data, parsing the response as a [`PostResponse<T>`](crate::api::response::PostResponse).
     /// If any errors are present, they are raised as [`RouxError::RedditError`](crate::util::error::RouxError).
     /// Otherwise, the data is unwrapped and returned.
@@ -123,6 +171,22 @@ pub trait RedditClient {
         .await
     }
 
+    /// Send the data as a JSON body with the method, parsing the response as `TResp`.
+    async fn send_json<TReq: Serialize, TResp: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: impl Into<EndpointBuilder>,
+        body: &TReq,
+    ) -> Result<TResp, RouxError> {
+        let endpoint: EndpointBuilder = endpoint.into();
+
+        self.execute_with_retries(
+            &|| self.make_req(method.clone(), &endpoint).json(body),
+            &parse_response_as_json,
+        )
+        .await
+    }
+
     /// Creates a user helper, which can be used to make further requests using this underlying client
     fn user(&self, name: &str) -> User<Self>
     where
@@ -211,27 +275,104 @@ pub trait RedditClient {
         Ok(conv)
     }
 
-    /// Get submissions by id
+    /// Get a submission and its comments.
+    ///
+    /// Unlike [`RedditClient::article_comments`], the submission is still returned if the comments
+    /// fail to parse, with the error in [`SubmissionWithComments::comments_error`].
     #[maybe_async::maybe_async]
-    async fn get_submissions(&self, ids: &[&ThingFullname]) -> Result<Submissions<Self>, RouxError>
+    async fn submission_with_comments(
+        &self,
+        subreddit_name: &str,
+        article: &ThingFullname,
+        depth: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<SubmissionWithComments<Self>, RouxError>
     where
         Self: Sized + Clone,
     {
-        let mut ids = ids.iter().map(|id| id.full());
-        let mut url = format!("by_id/");
-        url.push_str(ids.next().unwrap());
-        for next in ids {
-            url.push(',');
-            url.push_str(next);
+        let mut endpoint =
+            build_subreddit(subreddit_name).join(format!("comments/{}", article.id()));
+
+        if let Some(depth) = depth {
+            endpoint.with_query("depth", depth.to_string());
         }
 
-        let url = EndpointBuilder::new(url);
+        if let Some(limit) = limit {
+            endpoint.with_query("limit", limit.to_string());
+        }
+
+        let response: crate::api::comment::PartialArticleCommentsResponse =
+            self.get_json(endpoint).await?;
+
+        Ok(SubmissionWithComments::new(self.clone(), response))
+    }
+
+    /// Get submissions by id
+    #[maybe_async::maybe_async]
+    async fn get_submissions(&self, ids: &[&ThingFullname]) -> Result<Submissions<Self>, RouxError>
+    where
+        Self: Sized + Clone,
+    {
+        let ids = join_comma(ids.iter().map(|id| id.full()));
+        let url = EndpointBuilder::new(format!("by_id/{ids}"));
 
         let json: APISubmissions = self.get_json(url).await?;
         let conv = Listing::new(json, self.clone());
         Ok(conv)
     }
 
+    /// Gets comments, submissions and subreddits by their fullnames, in one request per 100 things.
+    ///
+    /// Things which don't exist are left out. Fullnames of other kinds, e.g. users, cannot be looked
+    /// up this way.
+    #[maybe_async::maybe_async]
+    async fn get_things(&self, ids: &[&ThingFullname]) -> Result<Vec<Thing<Self>>, RouxError>
+    where
+        Self: Sized + Clone,
+    {
+        let mut things = Vec::with_capacity(ids.len());
+
+        for chunk in ids.chunks(100) {
+            let url = EndpointBuilder::new("api/info")
+                .query_joined("id", chunk.iter().map(|id| id.full()));
+
+            let response: InfoItems = self.get_json(url).await?;
+            things.extend(
+                response
+                    .data
+                    .children
+                    .into_iter()
+                    .map(|item| Thing::new(self.clone(), item)),
+            );
+        }
+
+        Ok(things)
+    }
+
+    /// Gets the submissions linking to `url`, from the `/api/info` lookup.
+    ///
+    /// This only finds exact matches of the URL, see [`RedditClient::search_url`] to also search
+    /// for it.
+    #[maybe_async::maybe_async]
+    async fn info_by_url(
+        &self,
+        url: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<Self>, RouxError>
+    where
+        Self: Sized + Clone,
+    {
+        let source = EndpointBuilder::new("api/info").query("url", url);
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut endpoint);
+        }
+
+        let api: APISubmissions = self.get_json(endpoint).await?;
+        Ok(Listing::new(api, self.clone()).with_source(source, options))
+    }
+
     /// Gets a submission by its permalink
     #[maybe_async::maybe_async]
     async fn get_submission_by_link(&self, url: &str) -> Result<Submission<Self>, RouxError>
@@ -246,6 +387,65 @@ pub trait RedditClient {
         Ok(post)
     }
 
+    /// Searches submissions across all of Reddit.
+    ///
+    /// To search within a single subreddit, use [`Subreddit::search`].
+    #[maybe_async::maybe_async]
+    async fn search(
+        &self,
+        query: &str,
+        options: Option<SearchOptions>,
+    ) -> Result<Submissions<Self>, RouxError>
+    where
+        Self: Sized + Clone,
+    {
+        let (source, feed) = options
+            .unwrap_or_default()
+            .build_search(EndpointBuilder::new("search"), query);
+        let mut endpoint = source.clone();
+        feed.clone().build_url(&mut endpoint);
+
+        let api: APISubmissions = self.get_json(endpoint).await?;
+        Ok(Listing::new(api, self.clone()).with_source(source, Some(feed)))
+    }
+
+    /// Finds submissions linking to `url`, for example to detect reposts.
+    ///
+    /// This combines a `url:` search with the `/api/info?url=` lookup, as each can find submissions
+    /// the other misses. Submissions found by both are only returned once.
+    #[maybe_async::maybe_async]
+    async fn search_url(
+        &self,
+        url: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Vec<Submission<Self>>, RouxError>
+    where
+        Self: Sized + Clone,
+    {
+        let mut search = EndpointBuilder::new("search")
+            .query("q", format!("url:{url}"))
+            .query("type", "link");
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut search);
+        }
+
+        let searched: APISubmissions = self.get_json(search).await?;
+        let info = self.info_by_url(url, options).await?;
+
+        let mut seen = HashSet::new();
+        let submissions = searched
+            .data
+            .children
+            .into_iter()
+            .map(|thing| Submission::new(self.clone(), thing.data))
+            .chain(info.children)
+            .filter(|submission| seen.insert(submission.name().clone()))
+            .collect();
+
+        Ok(submissions)
+    }
+
     /// Gets a submission by its permalink
     #[maybe_async::maybe_async]
     async fn get_submission_by_info(
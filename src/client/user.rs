@@ -27,11 +27,17 @@
 
 extern crate serde_json;
 
+use std::time::SystemTime;
+
 use crate::models::comment::LatestComments;
+use crate::models::modqueue::QueueThing;
+use crate::models::multi::Multireddit;
 use crate::models::submission::Submissions;
-use crate::models::{LatestComment, Listing, Submission};
+use crate::models::{FromClientAndData, LatestComment, Listing, Submission};
 use crate::util::{FeedOption, RouxError};
 
+use crate::api::multi::MultiList;
+use crate::api::subreddit::ModQueueItems;
 use crate::api::{APILatestComments, APISubmissions, About, Overview};
 
 use super::endpoint::EndpointBuilder;
@@ -71,15 +77,16 @@ impl<T: RedditClient + Clone> User<T> {
         &self,
         options: Option<FeedOption>,
     ) -> Result<Submissions<T>, RouxError> {
-        let mut url = EndpointBuilder::from(format!("user/{}/submitted", self.user));
+        let source = EndpointBuilder::from(format!("user/{}/submitted", self.user));
+        let mut url = source.clone();
 
-        if let Some(options) = options {
+        if let Some(options) = options.clone() {
             options.build_url(&mut url);
         }
 
         let submissions: APISubmissions = self.client.get_json(url).await?;
 
-        let conv = Listing::new(submissions, self.client.clone());
+        let conv = Listing::new(submissions, self.client.clone()).with_source(source, options);
 
         Ok(conv)
     }
@@ -90,22 +97,54 @@ impl<T: RedditClient + Clone> User<T> {
         &self,
         options: Option<FeedOption>,
     ) -> Result<LatestComments<T>, RouxError> {
-        let mut url = EndpointBuilder::from(format!("user/{}/comments", self.user));
+        let source = EndpointBuilder::from(format!("user/{}/comments", self.user));
+        let mut url = source.clone();
 
-        if let Some(options) = options {
+        if let Some(options) = options.clone() {
             options.build_url(&mut url);
         }
 
         let api: APILatestComments = self.client.get_json(url).await?;
 
-        let conv = Listing::new(api, self.client.clone());
+        let conv = Listing::new(api, self.client.clone()).with_source(source, options);
+        Ok(conv)
+    }
+
+    /// Get user's gilded submissions and comments, most recently gilded first.
+    #[maybe_async::maybe_async]
+    pub async fn gilded(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Listing<QueueThing<T>>, RouxError> {
+        let source = EndpointBuilder::from(format!("user/{}/gilded", self.user));
+        let mut url = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut url);
+        }
+
+        let api: ModQueueItems = self.client.get_json(url).await?;
+
+        let conv = Listing::new_outer(api, self.client.clone()).with_source(source, options);
         Ok(conv)
     }
 
+    /// Get user's public multireddits.
+    #[maybe_async::maybe_async]
+    pub async fn multireddits(&self) -> Result<Vec<Multireddit<T>>, RouxError> {
+        let url = EndpointBuilder::from(format!("api/multi/user/{}", self.user));
+        let multis: MultiList = self.client.get_json(url).await?;
+
+        Ok(multis
+            .into_iter()
+            .map(|multi| Multireddit::new(self.client.clone(), multi.data))
+            .collect())
+    }
+
     /// Get user's about page
     #[maybe_async::maybe_async]
     pub async fn about(&self, options: Option<FeedOption>) -> Result<About, RouxError> {
-        let mut url = EndpointBuilder::from(format!("{}/about", self.user));
+        let mut url = EndpointBuilder::from(format!("user/{}/about", self.user));
 
         if let Some(options) = options {
             options.build_url(&mut url);
@@ -113,6 +152,13 @@ impl<T: RedditClient + Clone> User<T> {
 
         self.client.get_json(url).await
     }
+
+    /// Get when the user's account was created, fetching their about page.
+    #[maybe_async::maybe_async]
+    pub async fn created_at(&self) -> Result<Option<SystemTime>, RouxError> {
+        let about = self.about(None).await?;
+        Ok(about.data.created_at())
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,10 @@
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::client::{Scope, TokenStore};
+
 /// Configuration information for the OAuth or Authed clients.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Config {
     pub(crate) user_agent: String,
     pub(crate) client_id: String,
@@ -9,9 +12,75 @@ pub struct Config {
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
     pub(crate) timeout: Option<Duration>,
+    pub(crate) token_store: Option<Arc<dyn TokenStore>>,
+    pub(crate) scopes: Vec<Scope>,
+    pub(crate) max_ratelimit_retries: u32,
+    pub(crate) pacing: Vec<(String, Duration)>,
+    pub(crate) base_url: Option<String>,
+    pub(crate) raw_json: bool,
 }
 
 impl Config {
+    /// Creates a builder which checks the config for mistakes before it is used.
+    ///
+    /// See [`ConfigBuilder::build`].
+    pub fn builder(user_agent: &str, client_id: &str, client_secret: &str) -> ConfigBuilder {
+        ConfigBuilder {
+            config: Config::new(user_agent, client_id, client_secret),
+        }
+    }
+
+    /// Creates a config from the `REDDIT_CLIENT_ID`, `REDDIT_CLIENT_SECRET`, `REDDIT_USER_AGENT`,
+    /// `REDDIT_USERNAME` and `REDDIT_PASSWORD` environment variables.
+    ///
+    /// See [`Config::from_env_with_prefix`].
+    pub fn from_env() -> Result<Config, ConfigError> {
+        Config::from_env_with_prefix("REDDIT")
+    }
+
+    /// Creates a config from the `{prefix}_CLIENT_ID`, `{prefix}_CLIENT_SECRET`,
+    /// `{prefix}_USER_AGENT`, `{prefix}_USERNAME` and `{prefix}_PASSWORD` environment variables.
+    ///
+    /// The client ID and user agent are required. The client secret may be left unset for
+    /// installed apps, and the username and password must be set together. The config is checked
+    /// as by [`ConfigBuilder::build`].
+    ///
+    /// `.env` files are not read; load one beforehand (e.g. with the `dotenv` crate) if needed.
+    pub fn from_env_with_prefix(prefix: &str) -> Result<Config, ConfigError> {
+        Config::from_lookup(prefix, |name| std::env::var(name).ok())
+    }
+
+    /// The username and password, if both are set and not empty.
+    pub(crate) fn credentials(&self) -> Option<(&str, &str)> {
+        let username = self.username.as_deref().filter(|name| !name.is_empty())?;
+        let password = self.password.as_deref().filter(|pass| !pass.is_empty())?;
+        Some((username, password))
+    }
+
+    fn from_lookup(
+        prefix: &str,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, ConfigError> {
+        let var = |name: &str| {
+            let name = format!("{prefix}_{name}");
+            lookup(&name).filter(|value| !value.is_empty()).ok_or(name)
+        };
+
+        let client_id = var("CLIENT_ID").map_err(ConfigError::MissingEnvVar)?;
+        let user_agent = var("USER_AGENT").map_err(ConfigError::MissingEnvVar)?;
+        let client_secret = var("CLIENT_SECRET").unwrap_or_default();
+
+        let mut builder = Config::builder(&user_agent, &client_id, &client_secret);
+        if let Ok(username) = var("USERNAME") {
+            builder = builder.username(username);
+        }
+        if let Ok(password) = var("PASSWORD") {
+            builder = builder.password(password);
+        }
+
+        builder.build()
+    }
+
     /// Creates a new config using the provided values.
     pub fn new(user_agent: &str, client_id: &str, client_secret: &str) -> Config {
         Config {
@@ -21,6 +90,12 @@ impl Config {
             username: None,
             password: None,
             timeout: None,
+            token_store: None,
+            scopes: Vec::new(),
+            max_ratelimit_retries: 5,
+            pacing: Vec::new(),
+            base_url: None,
+            raw_json: true,
         }
     }
 
@@ -47,4 +122,356 @@ impl Config {
         self.timeout = Some(timeout);
         self
     }
+
+    /// Sets where the access token should be persisted to and restored from.
+    ///
+    /// By default, this is `None` and a fresh login is performed every time.
+    pub fn token_store(mut self, store: Arc<dyn TokenStore>) -> Self {
+        self.token_store = Some(store);
+        self
+    }
+
+    /// Sets how many times a request that is ratelimited (HTTP 429) is retried before giving up with
+    /// [`RouxErrorKind::Ratelimited`](crate::util::error::RouxErrorKind::Ratelimited).
+    ///
+    /// By default, this is 5.
+    pub fn max_ratelimit_retries(mut self, retries: u32) -> Self {
+        self.max_ratelimit_retries = retries;
+        self
+    }
+
+    /// Sends every request, including logging in, to `url` (e.g. `http://127.0.0.1:8080`)
+    /// instead of Reddit.
+    ///
+    /// This is intended for testing against a fake server.
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into().trim_end_matches('/').to_owned());
+        self
+    }
+
+    /// Sets whether `raw_json=1` is sent with every request.
+    ///
+    /// Without it, Reddit escapes `&`, `<` and `>` in text such as comment bodies as `&amp;`,
+    /// `&lt;` and `&gt;`. Accessors such as
+    /// [`Submission::selftext_unescaped`](crate::models::Submission::selftext_unescaped) and
+    /// [`Message::body_unescaped`](crate::models::Message::body_unescaped) reverse this, as does
+    /// [`markdown::unescape`](crate::util::markdown::unescape) for any other text.
+    ///
+    /// By default, this is `true`.
+    pub fn raw_json(mut self, enabled: bool) -> Self {
+        self.raw_json = enabled;
+        self
+    }
+
+    /// Spaces out requests to endpoints whose path starts with `prefix` (e.g. `r/rust`) so that
+    /// they are at least `min_interval` apart.
+    ///
+    /// This is in addition to the account-wide ratelimit. If several prefixes match a request,
+    /// the longest is used. Prefixes are matched case-insensitively on whole path segments.
+    pub fn pace_endpoint(mut self, prefix: impl Into<String>, min_interval: Duration) -> Self {
+        self.pacing.push((prefix.into(), min_interval));
+        self
+    }
+
+    /// Spaces out requests to the subreddit so that they are at least `min_interval` apart.
+    ///
+    /// See [`Config::pace_endpoint`].
+    pub fn pace_subreddit(self, name: &str, min_interval: Duration) -> Self {
+        self.pace_endpoint(format!("r/{name}"), min_interval)
+    }
+
+    /// Declares the scopes this application requires.
+    ///
+    /// These are requested when building an [`authorize_url`](Config::authorize_url).
+    /// When logging in, if Reddit does not grant all of them, login fails with
+    /// [`RouxErrorKind::InsufficientScope`](crate::util::error::RouxErrorKind::InsufficientScope).
+    pub fn scopes(mut self, scopes: impl IntoIterator<Item = Scope>) -> Self {
+        self.scopes = scopes.into_iter().collect();
+        self
+    }
+
+    /// Builds the URL to send a user to in order for them to authorize this application,
+    /// requesting the scopes declared with [`Config::scopes`].
+    ///
+    /// If `permanent` is true, a refresh token will be issued alongside the access token.
+    pub fn authorize_url(&self, redirect_uri: &str, state: &str, permanent: bool) -> String {
+        let scope = Scope::join(&self.scopes);
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("response_type", "code"),
+            ("state", state),
+            ("redirect_uri", redirect_uri),
+            (
+                "duration",
+                if permanent { "permanent" } else { "temporary" },
+            ),
+            ("scope", scope.as_str()),
+        ];
+
+        reqwest::Url::parse_with_params("https://www.reddit.com/api/v1/authorize", params)
+            .expect("authorize url is valid")
+            .into()
+    }
+}
+
+impl std::fmt::Debug for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config")
+            .field("user_agent", &self.user_agent)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &self.client_secret)
+            .field("username", &self.username)
+            .field("password", &self.password)
+            .field("timeout", &self.timeout)
+            .field("token_store", &self.token_store.is_some())
+            .field("scopes", &self.scopes)
+            .field("max_ratelimit_retries", &self.max_ratelimit_retries)
+            .field("pacing", &self.pacing)
+            .field("base_url", &self.base_url)
+            .field("raw_json", &self.raw_json)
+            .finish()
+    }
+}
+
+/// Builds a [`Config`], checking it for mistakes which would otherwise only be found when logging in.
+///
+/// Created with [`Config::builder`]. The setters are the same as those of [`Config`].
+#[derive(Debug, Clone)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    /// Sets the username and password.
+    pub fn credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.config = self.config.username(username).password(password);
+        self
+    }
+
+    /// See [`Config::username`].
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.config = self.config.username(username);
+        self
+    }
+
+    /// See [`Config::password`].
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.config = self.config.password(password);
+        self
+    }
+
+    /// See [`Config::timeout`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config = self.config.timeout(timeout);
+        self
+    }
+
+    /// See [`Config::token_store`].
+    pub fn token_store(mut self, store: Arc<dyn TokenStore>) -> Self {
+        self.config = self.config.token_store(store);
+        self
+    }
+
+    /// See [`Config::max_ratelimit_retries`].
+    pub fn max_ratelimit_retries(mut self, retries: u32) -> Self {
+        self.config = self.config.max_ratelimit_retries(retries);
+        self
+    }
+
+    /// See [`Config::base_url`].
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.config = self.config.base_url(url);
+        self
+    }
+
+    /// See [`Config::raw_json`].
+    pub fn raw_json(mut self, enabled: bool) -> Self {
+        self.config = self.config.raw_json(enabled);
+        self
+    }
+
+    /// See [`Config::pace_endpoint`].
+    pub fn pace_endpoint(mut self, prefix: impl Into<String>, min_interval: Duration) -> Self {
+        self.config = self.config.pace_endpoint(prefix, min_interval);
+        self
+    }
+
+    /// See [`Config::pace_subreddit`].
+    pub fn pace_subreddit(mut self, name: &str, min_interval: Duration) -> Self {
+        self.config = self.config.pace_subreddit(name, min_interval);
+        self
+    }
+
+    /// See [`Config::scopes`].
+    pub fn scopes(mut self, scopes: impl IntoIterator<Item = Scope>) -> Self {
+        self.config = self.config.scopes(scopes);
+        self
+    }
+
+    /// Checks the config and returns it.
+    ///
+    /// The user agent must follow Reddit's `<platform>:<app ID>:<version>` format (optionally
+    /// followed by ` (by /u/<username>)`), the client ID must be set, a username and password
+    /// must be set together, and a [`base_url`](ConfigBuilder::base_url) must be an `http` or
+    /// `https` URL.
+    pub fn build(self) -> Result<Config, ConfigError> {
+        let config = self.config;
+
+        let mut parts = config.user_agent.splitn(3, ':');
+        let is_valid_agent = parts
+            .next()
+            .is_some_and(|platform| !platform.trim().is_empty())
+            && parts.next().is_some_and(|app| !app.trim().is_empty())
+            && parts
+                .next()
+                .is_some_and(|version| !version.trim().is_empty());
+        if !is_valid_agent {
+            return Err(ConfigError::InvalidUserAgent(config.user_agent));
+        }
+
+        if config.client_id.is_empty() {
+            return Err(ConfigError::MissingClientId);
+        }
+
+        match (&config.username, &config.password) {
+            (Some(_), None) => return Err(ConfigError::MissingPassword),
+            (None, Some(_)) => return Err(ConfigError::MissingUsername),
+            _ => {}
+        }
+
+        if let Some(url) = &config.base_url {
+            let is_valid_url = reqwest::Url::parse(url)
+                .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.has_host());
+            if !is_valid_url {
+                return Err(ConfigError::InvalidBaseUrl(url.clone()));
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Why a [`ConfigBuilder`] could not build a [`Config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The user agent does not follow Reddit's `<platform>:<app ID>:<version>` format.
+    InvalidUserAgent(String),
+    /// The client ID is empty.
+    MissingClientId,
+    /// A password was set without a username.
+    MissingUsername,
+    /// A username was set without a password.
+    MissingPassword,
+    /// The base URL is not an `http` or `https` URL.
+    InvalidBaseUrl(String),
+    /// A required environment variable is not set, or is empty.
+    MissingEnvVar(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::InvalidUserAgent(agent) => write!(
+                f,
+                "user agent {agent:?} does not follow the format <platform>:<app ID>:<version>"
+            ),
+            ConfigError::MissingClientId => write!(f, "client ID is empty"),
+            ConfigError::MissingUsername => write!(f, "a password was set without a username"),
+            ConfigError::MissingPassword => write!(f, "a username was set without a password"),
+            ConfigError::InvalidBaseUrl(url) => {
+                write!(f, "base URL {url:?} is not an http or https URL")
+            }
+            ConfigError::MissingEnvVar(name) => {
+                write!(f, "environment variable {name} is not set")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Config, ConfigError};
+
+    const USER_AGENT: &str = "linux:roux-test:v1.0 (by /u/someone)";
+
+    #[test]
+    fn test_build() {
+        let config = Config::builder(USER_AGENT, "id", "secret")
+            .credentials("someone", "hunter2")
+            .base_url("http://127.0.0.1:8080/")
+            .build()
+            .unwrap();
+        assert_eq!(config.username.as_deref(), Some("someone"));
+        assert_eq!(config.base_url.as_deref(), Some("http://127.0.0.1:8080"));
+
+        assert!(Config::builder(USER_AGENT, "id", "").build().is_ok());
+    }
+
+    #[test]
+    fn test_build_errors() {
+        assert_eq!(
+            Config::builder("roux", "id", "secret").build().unwrap_err(),
+            ConfigError::InvalidUserAgent("roux".to_owned())
+        );
+        assert_eq!(
+            Config::builder("linux::v1.0", "id", "secret")
+                .build()
+                .unwrap_err(),
+            ConfigError::InvalidUserAgent("linux::v1.0".to_owned())
+        );
+        assert_eq!(
+            Config::builder(USER_AGENT, "", "secret")
+                .build()
+                .unwrap_err(),
+            ConfigError::MissingClientId
+        );
+        assert_eq!(
+            Config::builder(USER_AGENT, "id", "secret")
+                .password("hunter2")
+                .build()
+                .unwrap_err(),
+            ConfigError::MissingUsername
+        );
+        assert_eq!(
+            Config::builder(USER_AGENT, "id", "secret")
+                .username("someone")
+                .build()
+                .unwrap_err(),
+            ConfigError::MissingPassword
+        );
+        assert_eq!(
+            Config::builder(USER_AGENT, "id", "secret")
+                .base_url("127.0.0.1:8080")
+                .build()
+                .unwrap_err(),
+            ConfigError::InvalidBaseUrl("127.0.0.1:8080".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_from_env() {
+        let vars: HashMap<&str, &str> = [
+            ("BOT_CLIENT_ID", "id"),
+            ("BOT_USER_AGENT", USER_AGENT),
+            ("BOT_USERNAME", "someone"),
+            ("BOT_PASSWORD", "hunter2"),
+            ("BOT_CLIENT_SECRET", ""),
+        ]
+        .into();
+        let lookup = |name: &str| vars.get(name).map(|value| value.to_string());
+
+        let config = Config::from_lookup("BOT", lookup).unwrap();
+        assert_eq!(config.client_id, "id");
+        assert_eq!(config.client_secret, "");
+        assert_eq!(config.password.as_deref(), Some("hunter2"));
+
+        assert_eq!(
+            Config::from_lookup("OTHER", lookup).unwrap_err(),
+            ConfigError::MissingEnvVar("OTHER_CLIENT_ID".to_owned())
+        );
+    }
 }
@@ -0,0 +1,286 @@
+//! # Endpoints
+//! The Reddit API endpoints used by roux, and the OAuth scopes they need.
+//!
+//! Before a request is sent by an [`AuthedClient`](crate::client::AuthedClient) whose granted
+//! scopes are known, it is checked against this list, so a missing scope is reported as
+//! [`RouxErrorKind::InsufficientScope`](crate::util::error::RouxErrorKind::InsufficientScope)
+//! without a round trip to Reddit.
+
+use crate::client::Scope;
+
+/// A Reddit API endpoint used by roux.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endpoint {
+    /// The roux method which uses the endpoint, e.g. `Subreddit::hot`.
+    pub method: &'static str,
+    /// The HTTP method, e.g. `GET`.
+    pub http_method: &'static str,
+    /// The path of the endpoint, with placeholders in braces, e.g. `r/{subreddit}/{sort}`.
+    pub path: &'static str,
+    /// The scopes which allow the endpoint to be used. Any one of them is enough.
+    ///
+    /// This is empty if the endpoint can be used with any scope.
+    pub scopes: &'static [Scope],
+}
+
+impl Endpoint {
+    /// Whether the path of a request matches this endpoint.
+    fn matches(&self, http_method: &str, path: &str) -> bool {
+        if !self.http_method.eq_ignore_ascii_case(http_method) {
+            return false;
+        }
+
+        let mut expected = segments(self.path);
+        let mut actual = segments(path);
+        loop {
+            match (expected.next(), actual.next()) {
+                (None, None) => return true,
+                (Some(e), Some(a)) if e.starts_with('{') || e == a => {}
+                _ => return false,
+            }
+        }
+    }
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    let path = path.strip_suffix(".json").unwrap_or(path);
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+macro_rules! endpoints {
+    ($($method:literal => $http:ident $path:literal [$($scope:ident),*]),* $(,)?) => {
+        const ENDPOINTS: &[Endpoint] = &[$(
+            Endpoint {
+                method: $method,
+                http_method: stringify!($http),
+                path: $path,
+                scopes: &[$(Scope::$scope),*],
+            },
+        )*];
+    };
+}
+
+endpoints! {
+    "AuthedClient::me" => GET "api/v1/me" [Identity],
+    "AuthedClient::my_multireddits" => GET "api/multi/mine" [Read],
+    "FrontPage::hot" => GET "{sort}" [Read],
+    "AuthedClient::prefs" => GET "api/v1/me/prefs" [Identity],
+    "AuthedClient::submit_lazy" => POST "api/submit" [Submit],
+    "AuthedClient::upload_media" => POST "api/media/asset.json" [Submit],
+    "AuthedClient::submit_gallery" => POST "api/submit_gallery_post" [Submit],
+    "AuthedClient::add_subreddit_relationship" => POST "r/{subreddit}/api/friend" [],
+    "AuthedClient::remove_subreddit_relationship" => POST "r/{subreddit}/api/unfriend" [],
+    "AuthedClient::set_moderator_permissions" => POST "r/{subreddit}/api/setpermissions" [ModOthers],
+    "AuthedClient::get_my_subreddits" => GET "subreddits/mine/{where}" [MySubreddits],
+    "AuthedClient::subscribe" => POST "api/subscribe" [Subscribe],
+    "AuthedClient::more_children" => GET "api/morechildren" [Read],
+    "AuthedClient::compose_message" => POST "api/compose" [PrivateMessages],
+    "AuthedClient::inbox" => GET "message/inbox" [PrivateMessages],
+    "AuthedClient::unread" => GET "message/unread" [PrivateMessages],
+    "AuthedClient::mark_read" => POST "api/read_message" [PrivateMessages],
+    "AuthedClient::mark_unread" => POST "api/unread_message" [PrivateMessages],
+    "AuthedClient::comments" => GET "user/{username}/comments" [History, Read],
+    "AuthedClient::saved" => GET "user/{username}/{where}" [History, Read],
+    "AuthedClient::comment" => POST "api/comment" [Submit],
+    "AuthedClient::edit" => POST "api/editusertext" [Edit],
+    "AuthedClient::remove" => POST "api/remove" [ModPosts],
+    "AuthedClient::approve" => POST "api/approve" [ModPosts],
+    "AuthedClient::ignore_reports" => POST "api/ignore_reports" [ModPosts],
+    "AuthedClient::unignore_reports" => POST "api/unignore_reports" [ModPosts],
+    "AuthedClient::vote" => POST "api/vote" [Vote],
+    "AuthedClient::save" => POST "api/save" [Save],
+    "AuthedClient::unsave" => POST "api/unsave" [Save],
+    "AuthedClient::saved_categories" => GET "api/saved_categories" [Save],
+    "AuthedClient::lock" => POST "api/lock" [ModPosts],
+    "AuthedClient::unlock" => POST "api/unlock" [ModPosts],
+    "AuthedClient::follow_post" => POST "api/follow_post" [Subscribe],
+    "AuthedClient::mark_nsfw" => POST "api/marknsfw" [ModPosts],
+    "AuthedClient::unmark_nsfw" => POST "api/unmarknsfw" [ModPosts],
+    "AuthedClient::spoiler" => POST "api/spoiler" [ModPosts],
+    "AuthedClient::unspoiler" => POST "api/unspoiler" [ModPosts],
+    "AuthedClient::distinguish" => POST "api/distinguish" [ModPosts],
+    "AuthedClient::select_flair" => POST "r/{subreddit}/api/selectflair" [Flair],
+    "AuthedClient::sticky" => POST "api/set_subreddit_sticky" [ModPosts],
+    "AuthedClient::about_live_thread" => GET "api/live/{thread}/about" [Read],
+    "AuthedClient::create_live_thread" => POST "api/live/create" [Submit],
+    "AuthedClient::invite_live_thread_contributor" => POST "api/live/{thread}/invite_contributor" [LiveManage],
+    "AuthedClient::update_live_thread" => POST "api/live/{thread}/update" [Submit],
+    "AuthedClient::live_updates" => GET "live/{thread}" [Read],
+    "AuthedClient::strike_live_update" => POST "api/live/{thread}/strike_update" [Edit],
+    "AuthedClient::delete_live_update" => POST "api/live/{thread}/delete_update" [Edit],
+    "AuthedClient::close_live_thread" => POST "api/live/{thread}/close_thread" [LiveManage],
+    "AuthedClient::modmail_conversations" => GET "api/mod/conversations" [ModMail],
+    "AuthedClient::modmail_conversation" => GET "api/mod/conversations/{conversation}" [ModMail],
+    "AuthedClient::reply_modmail" => POST "api/mod/conversations/{conversation}" [ModMail],
+    "AuthedClient::archive_modmail" => POST "api/mod/conversations/{conversation}/archive" [ModMail],
+    "AuthedClient::unarchive_modmail" => POST "api/mod/conversations/{conversation}/unarchive" [ModMail],
+    "AuthedClient::highlight_modmail" => POST "api/mod/conversations/{conversation}/highlight" [ModMail],
+    "AuthedClient::unhighlight_modmail" => DELETE "api/mod/conversations/{conversation}/highlight" [ModMail],
+    "AuthedClient::mute_modmail_user" => POST "api/mod/conversations/{conversation}/mute" [ModMail],
+    "AuthedClient::unmute_modmail_user" => POST "api/mod/conversations/{conversation}/unmute" [ModMail],
+    "AuthedClient::add_removal_reason" => POST "api/v1/modactions/removal_reasons" [ModPosts],
+    "RedditClient::article_comments" => GET "r/{subreddit}/comments/{article}" [Read],
+    "RedditClient::article_and_comments" => GET "r/{subreddit}/comments/{article}/-/{comment}" [Read],
+    "Submission::duplicates" => GET "duplicates/{article}" [Read],
+    "RedditClient::get_submissions" => GET "by_id/{names}" [Read],
+    "RedditClient::get_things" => GET "api/info" [Read],
+    "RedditClient::info_by_url" => GET "api/info" [Read],
+    "RedditClient::search" => GET "search" [Read],
+    "RedditClient::search_url" => GET "search" [Read],
+    "Subreddits::search" => GET "subreddits/search" [Read],
+    "Subreddits::trending" => GET "api/trending_subreddits" [Read],
+    "Subreddit::about" => GET "r/{subreddit}/about" [Read],
+    "Subreddit::structured_styles" => GET "api/v1/structured_styles/{subreddit}" [StructuredStyles],
+    "Subreddit::widgets" => GET "r/{subreddit}/api/widgets" [StructuredStyles],
+    "Subreddit::hot" => GET "r/{subreddit}/{sort}" [Read],
+    "Subreddit::search" => GET "r/{subreddit}/search" [Read],
+    "Subreddit::random" => GET "r/{subreddit}/random" [Read],
+    "Subreddit::gilded" => GET "r/{subreddit}/gilded" [Read],
+    "Subreddit::modqueue" => GET "r/{subreddit}/about/modqueue" [Read],
+    "Subreddit::unmoderated" => GET "r/{subreddit}/about/unmoderated" [Read],
+    "Subreddit::spam" => GET "r/{subreddit}/about/spam" [Read],
+    "Subreddit::edited" => GET "r/{subreddit}/about/edited" [Read],
+    "Subreddit::reports" => GET "r/{subreddit}/about/reports" [Read],
+    "Subreddit::latest_comments" => GET "r/{subreddit}/comments" [Read],
+    "Subreddit::sticky" => GET "r/{subreddit}/hot" [Read],
+    "Subreddit::sticky_fullname" => GET "r/{subreddit}/hot" [Read],
+    "Subreddit::banned" => GET "r/{subreddit}/about/banned" [Read],
+    "Subreddit::muted" => GET "r/{subreddit}/about/muted" [Read],
+    "Subreddit::contributors" => GET "r/{subreddit}/about/contributors" [Read],
+    "Subreddit::moderators" => GET "r/{subreddit}/about/moderators" [Read],
+    "Subreddit::add_menu_widget" => POST "r/{subreddit}/api/widget" [StructuredStyles],
+    "Subreddit::update_menu_widget" => PUT "r/{subreddit}/api/widget/{widget}" [StructuredStyles],
+    "Subreddit::add_widget" => POST "r/{subreddit}/api/widget" [StructuredStyles],
+    "Subreddit::update_widget" => PUT "r/{subreddit}/api/widget/{widget}" [StructuredStyles],
+    "Subreddit::reorder_sidebar_widgets" => PATCH "r/{subreddit}/api/widget_order/sidebar" [StructuredStyles],
+    "Subreddit::delete_widget" => DELETE "r/{subreddit}/api/widget/{widget}" [StructuredStyles],
+    "Subreddit::stylesheet" => GET "r/{subreddit}/about/stylesheet" [ModConfig],
+    "Subreddit::update_stylesheet" => POST "r/{subreddit}/api/subreddit_stylesheet" [ModConfig],
+    "Subreddit::wiki_page_settings" => GET "r/{subreddit}/wiki/settings/{page}" [ModWiki],
+    "Subreddit::set_wiki_page_settings" => POST "r/{subreddit}/wiki/settings/{page}" [ModWiki],
+    "Subreddit::add_wiki_editor" => POST "r/{subreddit}/api/wiki/alloweditor/{act}" [ModWiki],
+    "Subreddit::post_requirements" => GET "api/v1/{subreddit}/post_requirements" [Submit],
+    "Subreddit::list_flairs" => POST "r/{subreddit}/api/flairselector" [Flair],
+    "Subreddit::set_flair_raw" => POST "r/{subreddit}/api/flair" [ModFlair],
+    "Subreddit::delete_user_flair" => POST "r/{subreddit}/api/deleteflair" [ModFlair],
+    "Subreddit::list_flair_templates" => GET "r/{subreddit}/api/link_flair_v2" [Flair],
+    "Subreddit::list_flair_templates" => GET "r/{subreddit}/api/user_flair_v2" [Flair],
+    "Subreddit::create_flair_template" => POST "r/{subreddit}/api/flairtemplate_v2" [ModFlair],
+    "Subreddit::update_flair_template" => POST "r/{subreddit}/api/flairtemplate_v2" [ModFlair],
+    "Subreddit::delete_flair_template" => POST "r/{subreddit}/api/deleteflairtemplate" [ModFlair],
+    "Subreddit::list_user_flairs" => GET "r/{subreddit}/api/flairlist" [ModFlair],
+    "Subreddit::accept_moderator_invite" => POST "r/{subreddit}/api/accept_moderator_invite" [ModSelf],
+    "Subreddit::list_removal_reasons" => GET "api/v1/{subreddit}/removal_reasons" [ModConfig],
+    "Subreddit::create_removal_reason" => POST "api/v1/{subreddit}/removal_reasons" [ModConfig],
+    "Subreddit::update_removal_reason" => PUT "api/v1/{subreddit}/removal_reasons/{reason}" [ModConfig],
+    "Subreddit::delete_removal_reason" => DELETE "api/v1/{subreddit}/removal_reasons/{reason}" [ModConfig],
+    "Subreddit::reorder_removal_reasons" => PATCH "api/v1/{subreddit}/removal_reasons" [ModConfig],
+    "Subreddit::settings" => GET "r/{subreddit}/about/edit" [ModConfig],
+    "CommunitySettings::get" => GET "r/{subreddit}/about/edit" [ModConfig],
+    "CommunitySettings::update" => POST "api/site_admin" [ModConfig],
+    "Subreddit::list_mod_log" => GET "r/{subreddit}/about/log" [ModLog],
+    "SubModmail::create" => POST "api/mod/conversations" [ModMail],
+    "User::overview" => GET "user/{username}/overview" [History, Read],
+    "User::submitted" => GET "user/{username}/submitted" [History, Read],
+    "User::gilded" => GET "user/{username}/gilded" [History, Read],
+    "User::multireddits" => GET "api/multi/user/{username}" [Read],
+    "Multireddit::hot" => GET "user/{username}/m/{multi}/{sort}" [Read],
+    "User::about" => GET "user/{username}/about" [Read],
+    "ArticleComment::delete" => POST "api/del" [Edit],
+    "Submission::report" => POST "api/report" [Report],
+}
+
+/// The Reddit API endpoints used by roux, and the scopes they need.
+///
+/// Methods which only wrap another (e.g. [`Subreddit::ban_user`](crate::client::Subreddit::ban_user))
+/// are listed under the method they wrap.
+pub fn endpoints() -> &'static [Endpoint] {
+    ENDPOINTS
+}
+
+/// The scopes needed for a request, if none of them have been granted.
+///
+/// Returns `None` if the request is allowed, or is to an endpoint which is not listed.
+pub(crate) fn missing_scopes(
+    http_method: &str,
+    path: &str,
+    granted: &[Scope],
+) -> Option<Vec<Scope>> {
+    let mut required = Vec::new();
+    for endpoint in ENDPOINTS
+        .iter()
+        .filter(|endpoint| endpoint.matches(http_method, path))
+    {
+        let missing = Scope::missing(endpoint.scopes, granted);
+        if missing.len() < endpoint.scopes.len() || endpoint.scopes.is_empty() {
+            return None;
+        }
+
+        for scope in missing {
+            if !required.contains(&scope) {
+                required.push(scope);
+            }
+        }
+    }
+
+    (!required.is_empty()).then_some(required)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{endpoints, missing_scopes};
+    use crate::client::Scope;
+
+    #[test]
+    fn test_endpoint_paths() {
+        for endpoint in endpoints() {
+            assert!(
+                !endpoint.path.starts_with('/') && !endpoint.path.ends_with('/'),
+                "{} has a badly formed path",
+                endpoint.method
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_scopes() {
+        let granted = [Scope::Read, Scope::Identity];
+
+        assert_eq!(missing_scopes("GET", "/r/rust/hot/.json", &granted), None);
+        assert_eq!(missing_scopes("GET", "/api/v1/me/.json", &granted), None);
+        assert_eq!(
+            missing_scopes("POST", "/api/vote/.json", &granted),
+            Some(vec![Scope::Vote])
+        );
+        assert_eq!(
+            missing_scopes(
+                "DELETE",
+                "//api/mod/conversations/abc/highlight/.json",
+                &granted
+            ),
+            Some(vec![Scope::ModMail])
+        );
+        assert_eq!(
+            missing_scopes("GET", "/user/someone/saved/.json", &[Scope::Identity]),
+            Some(vec![Scope::History, Scope::Read])
+        );
+        assert_eq!(
+            missing_scopes("GET", "/r/rust/api/user_flair_v2/.json", &[Scope::Flair]),
+            None
+        );
+
+        // Endpoints usable with any scope, and unlisted endpoints, are never blocked.
+        assert_eq!(
+            missing_scopes("POST", "/r/rust/api/friend/.json", &granted),
+            None
+        );
+        assert_eq!(
+            missing_scopes("GET", "/api/v1/unknown/.json", &granted),
+            None
+        );
+        assert_eq!(
+            missing_scopes("POST", "/api/vote/.json", &[Scope::All]),
+            None
+        );
+    }
+}
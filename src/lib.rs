@@ -74,7 +74,7 @@
 //! ```
 
 mod config;
-pub use config::Config;
+pub use config::{Config, ConfigBuilder, ConfigError};
 
 /// The clients and some models that store them.
 pub mod client;
@@ -90,4 +90,14 @@ pub mod builders;
 
 /// Utils for requests.
 pub mod util;
+
+pub mod endpoints;
+pub use endpoints::endpoints;
+
+/// Continuous streams of new content, built by polling listings.
+#[cfg(all(feature = "streams", not(feature = "blocking")))]
+pub mod streams;
+
+#[cfg(all(feature = "bots", not(feature = "blocking")))]
+pub mod bots;
 use util::RouxError;
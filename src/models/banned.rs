@@ -0,0 +1,34 @@
+//! Helper model for users banned from a subreddit.
+
+use crate::api::subreddit::BannedUserData;
+use crate::client::{RedditClient, User};
+
+use super::FromClientAndData;
+
+/// A user banned from a subreddit, as returned by
+/// [`Subreddit::banned`](crate::client::Subreddit::banned).
+pub struct BannedUser<T> {
+    client: T,
+    data: BannedUserData,
+}
+
+impl<T> std::ops::Deref for BannedUser<T> {
+    type Target = BannedUserData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: RedditClient + Clone> BannedUser<T> {
+    /// Creates a helper for the banned user.
+    pub fn user(&self) -> User<T> {
+        self.client.user(&self.data.name)
+    }
+}
+
+impl<T> FromClientAndData<T, BannedUserData> for BannedUser<T> {
+    fn new(client: T, data: BannedUserData) -> Self {
+        Self { client, data }
+    }
+}
@@ -3,17 +3,20 @@ use crate::{
         comment::{
             article::{ArticleCommentData, ArticleCommentOrMoreComments, MoreCommentData},
             created::{CreatedCommentData, CreatedCommentWithLinkInfoData},
+            info::InfoCommentData,
             latest::LatestCommentData,
-            replies::ArticleReplies,
+            replies::{ArticleReplies, CommentWalk},
         },
+        edited::EditedItem,
         Distinguished, ThingFullname,
     },
     builders::form::FormBuilder,
-    client::{AuthedClient, RedditClient, RemoveReason},
+    client::{AuthedClient, RedditClient, RemoveReason, VoteDirection},
     models::{Distinguish, Submission},
-    util::RouxError,
+    util::{export::Export, markdown, RouxError},
 };
 use serde_json::Value;
+use std::borrow::Cow;
 
 use super::Listing;
 
@@ -142,11 +145,18 @@ macro_rules! impl_comment {
                 self.data.common.banned_by.as_ref().map(|s| s.as_str())
             }
 
-            /// The text content of this comment
+            /// The markdown of this comment.
             pub fn body(&self) -> &str {
                 &self.data.common.body
             }
 
+            /// The markdown of this comment, with Reddit's escaping of `&`, `<` and `>` undone.
+            ///
+            /// This is only needed by clients built with [`Config::raw_json(false)`](crate::Config::raw_json).
+            pub fn body_unescaped(&self) -> Cow<'_, str> {
+                markdown::unescape(&self.data.common.body)
+            }
+
             /// The HTML encoded content of this comment.
             pub fn body_html(&self) -> &str {
                 &self.data.common.body_html
@@ -242,11 +252,17 @@ macro_rules! impl_comment {
                 self.data.common.is_submitter
             }
 
-            /// ??
+            /// `Some(true)` if the logged-in user has upvoted this comment, `Some(false)` if they
+            /// have downvoted it or `None` if they have not voted.
             pub fn likes(&self) -> &Option<Value> {
                 &self.data.common.likes
             }
 
+            /// How the logged-in user has voted on this comment.
+            pub fn my_vote(&self) -> VoteDirection {
+                VoteDirection::from_likes(self.data.common.likes.as_ref().and_then(Value::as_bool))
+            }
+
             /// The full name of the post this comment is under.
             pub fn link_id(&self) -> &ThingFullname {
                 &self.data.common.link_id
@@ -408,6 +424,12 @@ macro_rules! impl_comment {
             }
         }
 
+        impl<T> Export for $name<T> {
+            fn export_value(&self) -> Result<Value, serde_json::Error> {
+                serde_json::to_value(&self.data)
+            }
+        }
+
         impl $name<AuthedClient> {
             /// Reports this comment with a custom reason
             #[maybe_async::maybe_async]
@@ -428,11 +450,64 @@ macro_rules! impl_comment {
                 self.client.comment(text, &self.data.common.name).await
             }
 
-            /// Edits the text of this comment.
+            /// Edits the text of this comment, updating it to match Reddit's copy
+            /// (e.g. `body_html` and `edited`).
             #[maybe_async::maybe_async]
             pub async fn edit(&mut self, text: &str) -> Result<(), RouxError> {
-                self.client.edit(text, &self.data.common.name).await?;
-                self.data.common.body = text.to_owned();
+                match self.client.edit_raw(text, &self.data.common.name).await? {
+                    EditedItem::Comment(data) => self.data.common = data.common,
+                    EditedItem::Submission(_) => {
+                        return Err(RouxError::unexpected_response(
+                            "api/editusertext returned a submission for a comment",
+                        ))
+                    }
+                }
+                Ok(())
+            }
+
+            /// Votes on this comment, updating its `likes`, `ups` and `score` to match.
+            #[maybe_async::maybe_async]
+            pub async fn vote(&mut self, dir: VoteDirection) -> Result<(), RouxError> {
+                self.client.vote(&self.data.common.name, dir).await?;
+
+                let (ups, score) = self.my_vote().deltas_to(dir);
+                self.data.common.ups += ups;
+                self.data.common.score += score;
+                self.data.common.likes = dir.likes().map(Value::Bool);
+                Ok(())
+            }
+
+            /// Upvotes this comment.
+            #[maybe_async::maybe_async]
+            pub async fn upvote(&mut self) -> Result<(), RouxError> {
+                self.vote(VoteDirection::Up).await
+            }
+
+            /// Downvotes this comment.
+            #[maybe_async::maybe_async]
+            pub async fn downvote(&mut self) -> Result<(), RouxError> {
+                self.vote(VoteDirection::Down).await
+            }
+
+            /// Removes the vote on this comment.
+            #[maybe_async::maybe_async]
+            pub async fn unvote(&mut self) -> Result<(), RouxError> {
+                self.vote(VoteDirection::None).await
+            }
+
+            /// Saves this comment, optionally into a category, updating its `saved` flag.
+            #[maybe_async::maybe_async]
+            pub async fn save(&mut self, category: Option<&str>) -> Result<(), RouxError> {
+                self.client.save(&self.data.common.name, category).await?;
+                self.data.common.saved = true;
+                Ok(())
+            }
+
+            /// Unsaves this comment, updating its `saved` flag.
+            #[maybe_async::maybe_async]
+            pub async fn unsave(&mut self) -> Result<(), RouxError> {
+                self.client.unsave(&self.data.common.name).await?;
+                self.data.common.saved = false;
                 Ok(())
             }
 
@@ -450,6 +525,21 @@ macro_rules! impl_comment {
                 self.client.remove(self.name(), spam).await
             }
 
+            /// Approves this comment, requires moderator permission in the subreddit.
+            ///
+            /// Updates its `approved`, `removed` and `spam` flags to match.
+            #[maybe_async::maybe_async]
+            pub async fn approve(&mut self) -> Result<(), RouxError> {
+                self.client.approve(&self.data.common.name).await?;
+                let common = &mut self.data.common;
+                common.approved = Some(true);
+                common.approved_at_utc = Some(crate::util::utc_now());
+                common.removed = Some(false);
+                common.spam = Some(false);
+                common.banned_by = None;
+                Ok(())
+            }
+
             /// Removes this comment with the provided reason.
             #[maybe_async::maybe_async]
             pub async fn remove_with_reason(
@@ -513,10 +603,22 @@ impl_comment!(LatestComment, LatestCommentData, "Represents a comment found thro
 impl_comment!(ArticleComment, ArticleCommentData, "Represents a comment with full information found through either creating it or [`crate::models::Submission::article_comments`]. For a comment with less information, see [`LatestComment`](crate::models::comment::LatestComment)");
 impl_comment!(CreatedComment, CreatedCommentData, "Represents a comment that you have created, either under a submission or in reply to another comment.");
 impl_comment!(CreatedCommentWithLinkInfo, CreatedCommentWithLinkInfoData, "Represents a comment that you have created, with information about the submission it was made under.");
+impl_comment!(InfoComment, InfoCommentData, "Represents a comment looked up by its fullname through [`RedditClient::get_things`](crate::client::RedditClient::get_things).");
 
 impl_comment_with_link_info!(LatestComment);
 impl_comment_with_link_info!(CreatedCommentWithLinkInfo);
 
+impl<T: RedditClient + Clone> LatestComment<T> {
+    /// Fetches the submission this comment is under, from its [`link_id`](LatestComment::link_id).
+    ///
+    /// Returns `None` if Reddit no longer returns the submission.
+    #[maybe_async::maybe_async]
+    pub async fn fetch_submission(&self) -> Result<Option<Submission<T>>, RouxError> {
+        let submissions = self.client.get_submissions(&[self.link_id()]).await?;
+        Ok(submissions.children.into_iter().next())
+    }
+}
+
 impl<T> ArticleComment<T> {
     /// Converts this article comment into one that would've been retrieved through /r/SUB/comments.json
     pub fn into_latest(self, submission: &Submission<T>) -> LatestComment<T> {
@@ -560,7 +662,77 @@ pub enum ArticleCommentOrMore<T> {
     /// The comment
     Comment(ArticleComment<T>),
     /// The marker
-    More(MoreCommentData),
+    More(MoreComments<T>),
+}
+
+impl<T> ArticleComments<T> {
+    /// Walks every comment depth-first, in the order they appear on the site.
+    /// "More comments" markers are skipped.
+    pub fn walk(&self) -> impl Iterator<Item = &ArticleCommentData> {
+        CommentWalk::from_comments(self.children.iter().filter_map(|child| match child {
+            ArticleCommentOrMore::Comment(comment) => Some(comment.raw_data()),
+            ArticleCommentOrMore::More(_) => None,
+        }))
+    }
+
+    /// Collects every comment in the tree, depth-first.
+    pub fn flatten(&self) -> Vec<&ArticleCommentData> {
+        self.walk().collect()
+    }
+
+    /// Finds the comment with the fullname anywhere in the tree.
+    pub fn find(&self, name: &ThingFullname) -> Option<&ArticleCommentData> {
+        self.walk().find(|comment| &comment.common.name == name)
+    }
+}
+
+/// A marker that more comments exist, which can be loaded with [`MoreComments::expand`].
+pub struct MoreComments<T> {
+    client: T,
+    data: MoreCommentData,
+}
+
+impl<T> MoreComments<T> {
+    /// Gets the underlying raw data.
+    pub fn raw_data(&self) -> &MoreCommentData {
+        &self.data
+    }
+
+    /// The fullname of the comment (or submission) these comments are replies to.
+    pub fn parent_id(&self) -> &ThingFullname {
+        &self.data.parent_id
+    }
+
+    /// The number of comments not loaded.
+    pub fn count(&self) -> i32 {
+        self.data.count
+    }
+
+    /// How deep the comments are beneath the post.
+    pub fn depth(&self) -> i32 {
+        self.data.depth
+    }
+
+    /// The IDs of the comments which can be loaded.
+    pub fn children(&self) -> &[String] {
+        &self.data.children
+    }
+}
+
+impl MoreComments<AuthedClient> {
+    /// Loads the comments this marker stands in for, in the order Reddit returns them.
+    ///
+    /// `link` is the fullname of the submission the comments are on. The returned comments are flat,
+    /// so use each comment's `parent_id` to place it in the tree; they may include further markers.
+    /// "Continue this thread" markers have no children, so nothing is loaded for them.
+    #[maybe_async::maybe_async]
+    pub async fn expand(
+        &self,
+        link: &ThingFullname,
+    ) -> Result<Vec<ArticleCommentOrMore<AuthedClient>>, RouxError> {
+        let children: Vec<&str> = self.data.children.iter().map(String::as_str).collect();
+        self.client.more_children(link, &children).await
+    }
 }
 
 impl<Client> super::FromClientAndData<Client, ArticleCommentOrMoreComments>
@@ -571,7 +743,22 @@ impl<Client> super::FromClientAndData<Client, ArticleCommentOrMoreComments>
             ArticleCommentOrMoreComments::Comment(data) => {
                 Self::Comment(ArticleComment::new(client, data))
             }
-            ArticleCommentOrMoreComments::More(data) => Self::More(data),
+            ArticleCommentOrMoreComments::More(data) => Self::More(MoreComments { client, data }),
+        }
+    }
+}
+
+impl<T> Export for MoreComments<T> {
+    fn export_value(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(&self.data)
+    }
+}
+
+impl<T> Export for ArticleCommentOrMore<T> {
+    fn export_value(&self) -> Result<Value, serde_json::Error> {
+        match self {
+            ArticleCommentOrMore::Comment(comment) => comment.export_value(),
+            ArticleCommentOrMore::More(more) => more.export_value(),
         }
     }
 }
@@ -0,0 +1,34 @@
+//! Helper model for approved submitters of a subreddit.
+
+use crate::api::subreddit::ContributorData;
+use crate::client::{RedditClient, User};
+
+use super::FromClientAndData;
+
+/// An approved submitter of a subreddit, as returned by
+/// [`Subreddit::contributors`](crate::client::Subreddit::contributors).
+pub struct Contributor<T> {
+    client: T,
+    data: ContributorData,
+}
+
+impl<T> std::ops::Deref for Contributor<T> {
+    type Target = ContributorData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: RedditClient + Clone> Contributor<T> {
+    /// Creates a helper for the approved user.
+    pub fn user(&self) -> User<T> {
+        self.client.user(&self.data.name)
+    }
+}
+
+impl<T> FromClientAndData<T, ContributorData> for Contributor<T> {
+    fn new(client: T, data: ContributorData) -> Self {
+        Self { client, data }
+    }
+}
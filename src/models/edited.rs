@@ -0,0 +1,44 @@
+//! Models for edited comments and submissions.
+
+use crate::{
+    api::{edited::EditedItem, ThingFullname},
+    models::{CreatedComment, FromClientAndData, Submission},
+    util::export::Export,
+};
+
+/// A comment or self post whose text has just been edited, as returned by
+/// [`AuthedClient::edit`](crate::client::AuthedClient::edit).
+pub enum Edited<T> {
+    /// A comment
+    Comment(CreatedComment<T>),
+    /// A self post
+    Submission(Submission<T>),
+}
+
+impl<T> Edited<T> {
+    /// The thing's fullname.
+    pub fn name(&self) -> &ThingFullname {
+        match self {
+            Edited::Comment(d) => d.name(),
+            Edited::Submission(d) => d.name(),
+        }
+    }
+}
+
+impl<T> FromClientAndData<T, EditedItem> for Edited<T> {
+    fn new(client: T, data: EditedItem) -> Self {
+        match data {
+            EditedItem::Comment(d) => Edited::Comment(CreatedComment::new(client, d)),
+            EditedItem::Submission(d) => Edited::Submission(Submission::new(client, d)),
+        }
+    }
+}
+
+impl<T> Export for Edited<T> {
+    fn export_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        match self {
+            Edited::Comment(comment) => comment.export_value(),
+            Edited::Submission(submission) => submission.export_value(),
+        }
+    }
+}
@@ -1,7 +1,12 @@
+use std::borrow::Cow;
+
 use crate::{
-    api::{inbox::InboxData, ThingFullname},
+    api::{
+        inbox::{InboxData, MessageType},
+        ThingFullname,
+    },
     client::AuthedClient,
-    util::RouxError,
+    util::{markdown, RouxError},
 };
 
 use super::{FromClientAndData, Listing};
@@ -41,14 +46,35 @@ impl<T> Message<T> {
     pub fn is_new(&self) -> &bool {
         &self.data.new
     }
-    /// ???
+    /// The `type` Reddit gives the message, e.g. `comment_reply`.
     pub fn r#type(&self) -> &str {
-        &self.data.r#type
+        self.data.r#type.as_str()
+    }
+    /// What the message is, e.g. a reply to a comment.
+    pub fn message_type(&self) -> MessageType {
+        self.data.r#type
+    }
+    /// Whether the message is a comment mentioning the account's username.
+    pub fn is_mention(&self) -> bool {
+        self.data.r#type == MessageType::UsernameMention
+    }
+    /// Whether the message is a reply to one of the account's comments.
+    pub fn is_comment_reply(&self) -> bool {
+        self.data.r#type == MessageType::CommentReply
+    }
+    /// Whether the message is a reply to one of the account's posts.
+    pub fn is_post_reply(&self) -> bool {
+        self.data.r#type == MessageType::PostReply
     }
-    /// Body
+    /// The markdown of the message.
     pub fn body(&self) -> &str {
         &self.data.body
     }
+    /// The markdown of the message, with Reddit's escaping of `&`, `<` and `>` undone. This is
+    /// only needed by clients built with [`Config::raw_json(false)`](crate::Config::raw_json).
+    pub fn body_unescaped(&self) -> Cow<'_, str> {
+        markdown::unescape(&self.data.body)
+    }
     /// Dest
     pub fn dest(&self) -> &str {
         &self.data.dest
@@ -107,4 +133,10 @@ impl<T> FromClientAndData<T, InboxData> for Message<T> {
     }
 }
 
+impl<T> crate::util::export::Export for Message<T> {
+    fn export_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(&self.data)
+    }
+}
+
 pub type Inbox<T> = Listing<Message<T>>;
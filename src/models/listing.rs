@@ -1,9 +1,20 @@
 use crate::api::{
+    comment::{created::CreatedCommentWithLinkInfoData, latest::LatestCommentData},
+    inbox::InboxData,
     response::{BasicListing, Listing as APIListing, OuterBasicListing},
+    saved::SavedData,
+    submission::SubmissionData,
+    subreddit::{BannedUserData, ContributorData, ModQueueItems, MutedUserData},
     ThingFullname,
 };
+use crate::client::{endpoint::EndpointBuilder, RedditClient};
+use crate::util::{FeedOption, RouxError};
 
-use super::FromClientAndData;
+use super::modqueue::QueueThing;
+use super::{
+    BannedUser, Contributor, CreatedCommentWithLinkInfo, FromClientAndData, LatestComment, Message,
+    MutedUser, Saved, Submission,
+};
 
 /// Represents a view of a list of some thing `T`.
 pub struct Listing<T> {
@@ -17,9 +28,38 @@ pub struct Listing<T> {
     pub dist: Option<i32>,
     /// A mod hash
     pub modhash: Option<String>,
+    /// Where this listing was fetched from, if it can be paged through.
+    source: Option<ListingSource>,
+}
+
+/// The endpoint and options a listing was fetched with, so the following page can be requested.
+#[derive(Clone, Debug)]
+pub(crate) struct ListingSource {
+    endpoint: EndpointBuilder,
+    options: FeedOption,
+}
+
+impl ListingSource {
+    fn url(&self) -> EndpointBuilder {
+        let mut endpoint = self.endpoint.clone();
+        self.options.clone().build_url(&mut endpoint);
+        endpoint
+    }
 }
 
 impl<TModel> Listing<TModel> {
+    /// A listing with no items.
+    pub(crate) fn empty() -> Self {
+        Self {
+            before: None,
+            after: None,
+            children: Vec::new(),
+            dist: None,
+            modhash: None,
+            source: None,
+        }
+    }
+
     pub(crate) fn new_converter<TApi, F>(listing: OuterBasicListing<TApi>, convertor: F) -> Self
     where
         F: Fn(TApi) -> TModel,
@@ -40,9 +80,46 @@ impl<TModel> Listing<TModel> {
             children,
             dist,
             modhash,
+            source: None,
         }
     }
 
+    /// Remembers where this listing was fetched from, allowing [`next_page`](Listing::next_page) to be used.
+    ///
+    /// `endpoint` should not have the `options` applied to it.
+    pub(crate) fn with_source(
+        mut self,
+        endpoint: EndpointBuilder,
+        options: Option<FeedOption>,
+    ) -> Self {
+        self.source = Some(ListingSource {
+            endpoint,
+            options: options.unwrap_or_default(),
+        });
+        self
+    }
+
+    /// Whether there is another page after this one which can be fetched.
+    pub fn has_next_page(&self) -> bool {
+        self.after.is_some() && self.source.is_some()
+    }
+
+    /// The source of the page following this one.
+    pub(crate) fn next_source(&self) -> Option<ListingSource> {
+        let source = self.source.as_ref()?;
+        let after = self.after.as_ref()?;
+
+        let mut options = source.options.clone();
+        options.before = None;
+        options.after = Some(after.full().to_owned());
+        options.count = Some(options.count.unwrap_or(0) + self.children.len() as u32);
+
+        Some(ListingSource {
+            endpoint: source.endpoint.clone(),
+            options,
+        })
+    }
+
     pub(crate) fn new<TApi, TClient>(listing: BasicListing<TApi>, client: TClient) -> Self
     where
         TClient: Clone,
@@ -72,3 +149,62 @@ impl<T> IntoIterator for Listing<T> {
         self.children.into_iter()
     }
 }
+
+macro_rules! impl_pagination {
+    ($($model:ident => $data:ty),* $(,)?) => {
+        $(impl_pagination!(@impl $model, BasicListing<$data>, new);)*
+    };
+    (@impl $model:ident, $api:ty, $new:ident) => {
+        impl<C: RedditClient + Clone> Listing<$model<C>> {
+            /// Fetches the page following this one, or `None` if this is the last page
+            /// (or the listing wasn't fetched from a pageable endpoint).
+            #[maybe_async::maybe_async]
+            pub async fn next_page(&self, client: &C) -> Result<Option<Self>, RouxError> {
+                match self.next_source() {
+                    Some(source) => Ok(Some(Self::fetch_source(client, source).await?)),
+                    None => Ok(None),
+                }
+            }
+
+            #[maybe_async::maybe_async]
+            pub(crate) async fn fetch_source(
+                client: &C,
+                source: ListingSource,
+            ) -> Result<Self, RouxError> {
+                let api: $api = client.get_json(source.url()).await?;
+                let mut listing = Listing::$new(api, client.clone());
+                listing.source = Some(source);
+                Ok(listing)
+            }
+
+            /// Streams the items in this listing, followed by the items in each page after it,
+            /// until there are no more pages or `limit` items have been yielded.
+            ///
+            /// If fetching a page fails, the error is yielded and the stream ends.
+            #[cfg(all(feature = "streams", not(feature = "blocking")))]
+            pub fn paginate(
+                self,
+                client: C,
+                limit: Option<usize>,
+            ) -> impl futures_util::Stream<Item = Result<$model<C>, RouxError>> {
+                crate::streams::paginate(self, limit, move |source| {
+                    let client = client.clone();
+                    async move { Self::fetch_source(&client, source).await }
+                })
+            }
+        }
+    };
+}
+
+impl_pagination!(
+    Submission => SubmissionData,
+    LatestComment => LatestCommentData,
+    Message => InboxData,
+    Saved => SavedData,
+    CreatedCommentWithLinkInfo => CreatedCommentWithLinkInfoData,
+);
+
+impl_pagination!(@impl BannedUser, OuterBasicListing<BannedUserData>, new_outer);
+impl_pagination!(@impl MutedUser, OuterBasicListing<MutedUserData>, new_outer);
+impl_pagination!(@impl Contributor, OuterBasicListing<ContributorData>, new_outer);
+impl_pagination!(@impl QueueThing, ModQueueItems, new_outer);
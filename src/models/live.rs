@@ -1,6 +1,10 @@
 //! Helper models for live thread related objects.
 
-use crate::{api::live::LiveThreadData, client::AuthedClient, util::RouxError};
+use crate::{
+    api::live::{LiveThreadData, LiveThreadState, LiveUpdateData},
+    client::AuthedClient,
+    util::{FeedOption, RouxError},
+};
 
 /// A live thread that can provide live-updating events.
 pub struct LiveThread<T> {
@@ -16,6 +20,18 @@ impl<T> std::ops::Deref for LiveThread<T> {
     }
 }
 
+impl<T> LiveThread<T> {
+    /// The viewer count as of when this thread was fetched.
+    pub fn viewers(&self) -> ViewerCount {
+        ViewerCount {
+            viewer_count: self.data.viewer_count,
+            fuzzed: self.data.viewer_count_fuzzed.unwrap_or(false),
+            state: self.data.state,
+            sampled_at: std::time::SystemTime::now(),
+        }
+    }
+}
+
 impl LiveThread<AuthedClient> {
     /// Helper to get the human URL to this live thread.
     pub fn url(&self) -> String {
@@ -34,6 +50,15 @@ impl LiveThread<AuthedClient> {
         self.client.update_live_thread(&self.id, text).await
     }
 
+    /// Gets the most recent updates posted to this live thread.
+    #[maybe_async::maybe_async]
+    pub async fn updates(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Vec<LiveUpdate<AuthedClient>>, RouxError> {
+        self.client.live_updates(&self.id, options).await
+    }
+
     /// Invites a contributor to this live thread.
     #[maybe_async::maybe_async]
     pub async fn invite(&self, name: &str) -> Result<(), RouxError> {
@@ -43,8 +68,162 @@ impl LiveThread<AuthedClient> {
     }
 }
 
+/// An update posted to a live thread.
+pub struct LiveUpdate<T> {
+    client: T,
+    thread: String,
+    data: LiveUpdateData,
+}
+
+impl<T> std::ops::Deref for LiveUpdate<T> {
+    type Target = LiveUpdateData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> LiveUpdate<T> {
+    pub(crate) fn new(client: T, thread: impl Into<String>, data: LiveUpdateData) -> Self {
+        Self {
+            client,
+            thread: thread.into(),
+            data,
+        }
+    }
+
+    /// The ID of the live thread this update was posted to.
+    pub fn thread_id(&self) -> &str {
+        &self.thread
+    }
+
+    /// The URL to this update.
+    pub fn permalink(&self) -> String {
+        format!(
+            "https://www.reddit.com/live/{}/updates/{}",
+            self.thread, self.data.id
+        )
+    }
+}
+
+impl LiveUpdate<AuthedClient> {
+    /// Strikes this update, marking it as incorrect without deleting it.
+    #[maybe_async::maybe_async]
+    pub async fn strike(&mut self) -> Result<(), RouxError> {
+        self.client
+            .strike_live_update(&self.thread, &self.data.name)
+            .await?;
+        self.data.stricken = true;
+        Ok(())
+    }
+
+    /// Deletes this update.
+    #[maybe_async::maybe_async]
+    pub async fn delete(self) -> Result<(), RouxError> {
+        self.client
+            .delete_live_update(&self.thread, &self.data.name)
+            .await
+    }
+}
+
+/// A sample of a live thread's audience, yielded by [`LiveThread::poll_viewers`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewerCount {
+    /// The number of viewers, if Reddit reported it.
+    pub viewer_count: Option<i32>,
+    /// Whether the count has been fuzzed.
+    pub fuzzed: bool,
+    /// The state of the thread when sampled.
+    pub state: LiveThreadState,
+    /// When the sample was taken.
+    pub sampled_at: std::time::SystemTime,
+}
+
+#[cfg(all(feature = "streams", not(feature = "blocking")))]
+impl LiveThread<AuthedClient> {
+    /// Periodically re-fetches this thread, yielding its viewer count every `interval`.
+    ///
+    /// The first sample is taken immediately. The stream ends after the thread is
+    /// sampled as [`Complete`](LiveThreadState::Complete); errors are yielded and polling continues.
+    pub fn poll_viewers(
+        &self,
+        interval: std::time::Duration,
+    ) -> impl futures_util::Stream<Item = Result<ViewerCount, RouxError>> {
+        use crate::client::req::sleep;
+
+        let client = self.client.clone();
+        let id = self.data.id.clone();
+
+        futures_util::stream::unfold(Some(true), move |state| {
+            let client = client.clone();
+            let id = id.clone();
+            async move {
+                let first = state?;
+                if !first {
+                    sleep(interval).await;
+                }
+
+                match client.about_live_thread(&id).await {
+                    Ok(thread) => {
+                        let sample = thread.viewers();
+                        let next = (sample.state == LiveThreadState::Live).then_some(false);
+                        Some((Ok(sample), next))
+                    }
+                    Err(error) => Some((Err(error), Some(false))),
+                }
+            }
+        })
+    }
+}
+
 impl<T> super::FromClientAndData<T, LiveThreadData> for LiveThread<T> {
     fn new(client: T, data: LiveThreadData) -> Self {
         Self { client, data }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::LiveUpdate;
+    use crate::api::live::LiveUpdates;
+
+    #[test]
+    fn test_live_updates() {
+        let updates: LiveUpdates = serde_json::from_str(
+            r#"{
+                "kind": "Listing",
+                "data": {
+                    "after": "LiveUpdate_b5b2f4a0-5a4b-11ee-a5d0-1a6f8bd6a1c2",
+                    "before": null,
+                    "children": [{
+                        "kind": "LiveUpdate",
+                        "data": {
+                            "body": "Hello",
+                            "name": "LiveUpdate_b5b2f4a0-5a4b-11ee-a5d0-1a6f8bd6a1c2",
+                            "author": "someone",
+                            "created": 1695800000.0,
+                            "created_utc": 1695800000.0,
+                            "body_html": "&lt;p&gt;Hello&lt;/p&gt;",
+                            "stricken": false,
+                            "id": "b5b2f4a0-5a4b-11ee-a5d0-1a6f8bd6a1c2"
+                        }
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let update = updates
+            .data
+            .children
+            .into_iter()
+            .map(|thing| LiveUpdate::new((), "18hnzysb1elcs", thing.data))
+            .next()
+            .unwrap();
+        assert_eq!(update.thread_id(), "18hnzysb1elcs");
+        assert_eq!(
+            update.permalink(),
+            "https://www.reddit.com/live/18hnzysb1elcs/updates/b5b2f4a0-5a4b-11ee-a5d0-1a6f8bd6a1c2"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Helper model for the logged in account.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::api::me::{MeData, PrefsData, UnreadCounts};
+use crate::api::subreddit::SubredditsData;
+use crate::client::{AuthedClient, RedditClient, SubRelation, User};
+use crate::models::inbox::Inbox;
+use crate::RouxError;
+
+use super::FromClientAndData;
+
+/// The logged in account, as returned by [`AuthedClient::me_model`].
+pub struct Me<T> {
+    client: T,
+    data: MeData,
+}
+
+impl<T> Me<T> {
+    /// The account's username.
+    pub fn name(&self) -> &str {
+        &self.data.name
+    }
+    /// The ID of the account in base-36 form.
+    pub fn id(&self) -> &str {
+        &self.data.id
+    }
+    /// Karma from comments.
+    pub fn comment_karma(&self) -> i32 {
+        self.data.comment_karma
+    }
+    /// Karma from submissions.
+    pub fn link_karma(&self) -> i32 {
+        self.data.link_karma
+    }
+    /// The karma shown on the profile, which also includes awarder and awardee karma.
+    ///
+    /// Falls back to the sum of comment and link karma if Reddit didn't provide it.
+    pub fn total_karma(&self) -> i32 {
+        if self.data.total_karma != 0 {
+            self.data.total_karma
+        } else {
+            self.data.comment_karma + self.data.link_karma
+        }
+    }
+    /// A timestamp of the time when the account was created, in **UTC**.
+    pub fn created_utc(&self) -> f64 {
+        self.data.created_utc
+    }
+    /// When the account was created.
+    pub fn created_at(&self) -> Option<std::time::SystemTime> {
+        crate::util::utc_timestamp(self.data.created_utc)
+    }
+    /// Whether the account moderates any subreddit.
+    pub fn is_mod(&self) -> bool {
+        self.data.is_mod
+    }
+    /// Whether the account has Reddit Premium.
+    pub fn is_gold(&self) -> bool {
+        self.data.is_gold
+    }
+    /// Whether the account is a Reddit employee.
+    pub fn is_employee(&self) -> bool {
+        self.data.is_employee
+    }
+    /// Whether the account is suspended.
+    pub fn is_suspended(&self) -> bool {
+        self.data.is_suspended
+    }
+    /// Whether the account has a verified email address.
+    pub fn has_verified_email(&self) -> bool {
+        self.data.has_verified_email
+    }
+    /// The URL of the account's avatar.
+    pub fn icon_url(&self) -> String {
+        html_escape::decode_html_entities(&self.data.icon_img).into_owned()
+    }
+    /// The feature flags of the account.
+    pub fn features(&self) -> &HashMap<String, Value> {
+        &self.data.features
+    }
+    /// Whether the feature flag is enabled.
+    /// Experiment variants, which Reddit gives as objects, count as enabled.
+    pub fn has_feature(&self, name: &str) -> bool {
+        match self.data.features.get(name) {
+            Some(Value::Bool(enabled)) => *enabled,
+            Some(Value::Object(_)) => true,
+            _ => false,
+        }
+    }
+    /// The inbox counts at the time this was fetched.
+    pub fn unread_counts(&self) -> UnreadCounts {
+        UnreadCounts::from(&self.data)
+    }
+    /// The raw data of the account.
+    pub fn raw_data(&self) -> &MeData {
+        &self.data
+    }
+}
+
+impl<T: RedditClient + Clone> Me<T> {
+    /// Creates a helper to make further requests about this account's public profile.
+    pub fn user(&self) -> User<T> {
+        User::new(&self.data.name, self.client.clone())
+    }
+}
+
+impl Me<AuthedClient> {
+    /// Get the account's received messages (includes both read and unread).
+    #[maybe_async::maybe_async]
+    pub async fn inbox(&self) -> Result<Inbox<AuthedClient>, RouxError> {
+        self.client.inbox().await
+    }
+
+    /// Get the account's unread messages.
+    #[maybe_async::maybe_async]
+    pub async fn unread(&self) -> Result<Inbox<AuthedClient>, RouxError> {
+        self.client.unread().await
+    }
+
+    /// Get the account's preferences.
+    #[maybe_async::maybe_async]
+    pub async fn prefs(&self) -> Result<PrefsData, RouxError> {
+        self.client.prefs().await
+    }
+
+    /// Get the subreddits the account has the relationship with.
+    #[maybe_async::maybe_async]
+    pub async fn subreddits(&self, relation: SubRelation) -> Result<SubredditsData, RouxError> {
+        self.client.get_my_subreddits(relation).await
+    }
+}
+
+impl<T> FromClientAndData<T, MeData> for Me<T> {
+    fn new(client: T, data: MeData) -> Self {
+        Self { client, data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Me;
+    use crate::api::me::MeData;
+    use crate::models::FromClientAndData;
+
+    #[test]
+    fn test_karma_and_features() {
+        let data: MeData = serde_json::from_value(json!({
+            "id": "abc12", "name": "someone", "is_employee": false, "verified": true,
+            "over_18": false, "has_verified_email": true, "is_suspended": false,
+            "has_mail": false, "inbox_count": 0, "created": 0.0, "created_utc": 0.0,
+            "in_beta": false, "comment_karma": 10, "link_karma": 5, "is_mod": false,
+            "is_gold": false, "icon_img": "https://example.com/a.png?a=1&amp;b=2",
+            "features": {
+                "chat": true,
+                "mweb_xpromo": false,
+                "some_experiment": { "owner": "growth", "variant": "treatment_1" }
+            }
+        }))
+        .unwrap();
+        let me = Me::new((), data);
+
+        assert_eq!(me.total_karma(), 15);
+        assert!(me.has_feature("chat"));
+        assert!(!me.has_feature("mweb_xpromo"));
+        assert!(me.has_feature("some_experiment"));
+        assert!(!me.has_feature("missing"));
+        assert_eq!(me.icon_url(), "https://example.com/a.png?a=1&b=2");
+    }
+}
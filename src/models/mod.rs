@@ -5,11 +5,17 @@ pub(crate) mod listing;
 pub use listing::Listing;
 
 pub(crate) mod submission;
-pub use submission::{Submission, SubmissionLinkInfo, SubmissionStickySlot};
+pub use submission::{
+    MediaKind, MediaLink, Submission, SubmissionKind, SubmissionLinkInfo, SubmissionSnapshot,
+    SubmissionStatus, SubmissionStickySlot, SubmissionWithComments,
+};
 
 pub(crate) mod saved;
 pub use saved::Saved;
 
+pub(crate) mod edited;
+pub use edited::Edited;
+
 pub(crate) mod comment;
 pub use comment::*;
 
@@ -19,8 +25,26 @@ pub use inbox::Message;
 mod distinguish;
 pub use distinguish::Distinguish;
 
+pub(crate) mod subreddit;
+pub use subreddit::SubredditAbout;
+
+pub(crate) mod thing;
+pub use thing::Thing;
+
+pub(crate) mod banned;
+pub use banned::BannedUser;
+
+pub(crate) mod muted;
+pub use muted::MutedUser;
+
+pub(crate) mod contributor;
+pub use contributor::Contributor;
+
 pub mod live;
+pub mod me;
+pub mod modmail;
 pub mod modqueue;
+pub mod multi;
 
 pub(crate) trait FromClientAndData<Client, Data> {
     fn new(client: Client, data: Data) -> Self;
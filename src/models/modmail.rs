@@ -0,0 +1,239 @@
+//! Helper models for modmail conversations.
+
+use std::collections::HashMap;
+
+use crate::{
+    api::modmail::{ModmailConversationData, ModmailMessage},
+    client::{AuthedClient, ModmailMuteDuration},
+    util::RouxError,
+};
+
+/// The [`state`](ModmailConversationData::state) of a conversation which has been replied to.
+const STATE_IN_PROGRESS: i32 = 1;
+/// The [`state`](ModmailConversationData::state) of an archived conversation.
+const STATE_ARCHIVED: i32 = 2;
+
+/// A modmail conversation with the messages that were returned with it.
+///
+/// Conversations from [`AuthedClient::modmail_conversations`] only include their most recent
+/// message; use [`ModmailConversation::fetch`] to get all of them.
+pub struct ModmailConversation<T> {
+    client: T,
+    data: ModmailConversationData,
+    messages: Vec<ModmailMessage>,
+}
+
+impl<T> std::ops::Deref for ModmailConversation<T> {
+    type Target = ModmailConversationData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> ModmailConversation<T> {
+    /// Creates the conversation, taking the messages it refers to from `messages`.
+    pub(crate) fn new(
+        client: T,
+        data: ModmailConversationData,
+        messages: &mut HashMap<String, ModmailMessage>,
+    ) -> Self {
+        let messages = data
+            .obj_ids
+            .iter()
+            .filter(|obj| obj.key == "messages")
+            .filter_map(|obj| messages.remove(&obj.id))
+            .collect();
+
+        Self {
+            client,
+            data,
+            messages,
+        }
+    }
+
+    /// The messages of this conversation which were returned with it, oldest first.
+    pub fn messages(&self) -> &[ModmailMessage] {
+        &self.messages
+    }
+
+    /// The most recent message returned with this conversation.
+    pub fn last_message(&self) -> Option<&ModmailMessage> {
+        self.messages.last()
+    }
+
+    /// The name of the subreddit this conversation belongs to.
+    pub fn subreddit(&self) -> &str {
+        &self.data.owner.display_name
+    }
+
+    /// Whether this conversation has been archived.
+    pub fn is_archived(&self) -> bool {
+        self.data.state == STATE_ARCHIVED
+    }
+
+    /// Whether this conversation has unread messages.
+    pub fn is_unread(&self) -> bool {
+        self.data.last_unread.is_some()
+    }
+
+    /// Helper to get the human URL to this conversation.
+    pub fn url(&self) -> String {
+        format!("https://mod.reddit.com/mail/all/{}", self.data.id)
+    }
+
+    /// Consumes this, returning the underlying data and messages.
+    pub fn into_inner(self) -> (ModmailConversationData, Vec<ModmailMessage>) {
+        (self.data, self.messages)
+    }
+}
+
+impl ModmailConversation<AuthedClient> {
+    /// Fetches this conversation again, with all of its messages.
+    #[maybe_async::maybe_async]
+    pub async fn fetch(&self) -> Result<Self, RouxError> {
+        self.client.modmail_conversation(&self.data.id).await
+    }
+
+    /// Replies to this conversation, updating it with the new message.
+    ///
+    /// Internal replies are only visible to moderators; hidden replies are sent as the subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn reply(
+        &mut self,
+        body: &str,
+        is_internal: bool,
+        is_author_hidden: bool,
+    ) -> Result<(), RouxError> {
+        *self = self
+            .client
+            .reply_modmail(&self.data.id, body, is_internal, is_author_hidden)
+            .await?;
+        Ok(())
+    }
+
+    /// Replies with a note only visible to moderators.
+    #[maybe_async::maybe_async]
+    pub async fn add_mod_note(&mut self, body: &str) -> Result<(), RouxError> {
+        self.reply(body, true, false).await
+    }
+
+    /// Archives this conversation.
+    #[maybe_async::maybe_async]
+    pub async fn archive(&mut self) -> Result<(), RouxError> {
+        self.client.archive_modmail(&self.data.id).await?;
+        self.data.state = STATE_ARCHIVED;
+        Ok(())
+    }
+
+    /// Unarchives this conversation.
+    #[maybe_async::maybe_async]
+    pub async fn unarchive(&mut self) -> Result<(), RouxError> {
+        self.client.unarchive_modmail(&self.data.id).await?;
+        self.data.state = STATE_IN_PROGRESS;
+        Ok(())
+    }
+
+    /// Highlights this conversation.
+    #[maybe_async::maybe_async]
+    pub async fn highlight(&mut self) -> Result<(), RouxError> {
+        self.client.highlight_modmail(&self.data.id).await?;
+        self.data.is_highlighted = true;
+        Ok(())
+    }
+
+    /// Removes the highlight from this conversation.
+    #[maybe_async::maybe_async]
+    pub async fn unhighlight(&mut self) -> Result<(), RouxError> {
+        self.client.unhighlight_modmail(&self.data.id).await?;
+        self.data.is_highlighted = false;
+        Ok(())
+    }
+
+    /// Mutes the user this conversation is with.
+    #[maybe_async::maybe_async]
+    pub async fn mute(&self, duration: ModmailMuteDuration) -> Result<(), RouxError> {
+        self.client.mute_modmail_user(&self.data.id, duration).await
+    }
+
+    /// Unmutes the user this conversation is with.
+    #[maybe_async::maybe_async]
+    pub async fn unmute(&self) -> Result<(), RouxError> {
+        self.client.unmute_modmail_user(&self.data.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModmailConversation;
+    use crate::api::modmail::ModmailConversations;
+
+    #[test]
+    fn test_conversation_messages() {
+        let json = r#"{
+            "conversations": {
+                "abc12": {
+                    "id": "abc12",
+                    "subject": "Why was I banned?",
+                    "state": 1,
+                    "isAuto": false,
+                    "isInternal": false,
+                    "isHighlighted": false,
+                    "isRepliable": true,
+                    "numMessages": 2,
+                    "lastUpdated": "2024-01-02T00:00:00.000000+00:00",
+                    "lastUserUpdate": "2024-01-01T00:00:00.000000+00:00",
+                    "lastModUpdate": "2024-01-02T00:00:00.000000+00:00",
+                    "lastUnread": null,
+                    "owner": { "id": "t5_2s7lj", "displayName": "rust", "type": "subreddit" },
+                    "participant": { "name": "someone", "isParticipant": true, "id": 1 },
+                    "authors": [
+                        { "name": "someone", "isOp": true, "isParticipant": true, "id": 1 },
+                        { "name": "a_mod", "isMod": true, "isHidden": true, "id": 2 }
+                    ],
+                    "objIds": [
+                        { "id": "m1", "key": "messages" },
+                        { "id": "a1", "key": "modActions" },
+                        { "id": "m2", "key": "messages" }
+                    ]
+                }
+            },
+            "conversationIds": ["abc12"],
+            "messages": {
+                "m2": {
+                    "id": "m2",
+                    "body": "<p>Spam.</p>",
+                    "bodyMarkdown": "Spam.",
+                    "author": { "name": "a_mod", "isMod": true, "isHidden": true },
+                    "isInternal": false,
+                    "date": "2024-01-02T00:00:00.000000+00:00"
+                },
+                "m1": {
+                    "id": "m1",
+                    "body": "<p>Why?</p>",
+                    "bodyMarkdown": "Why?",
+                    "author": { "name": "someone", "isOp": true },
+                    "isInternal": false,
+                    "date": "2024-01-01T00:00:00.000000+00:00"
+                }
+            }
+        }"#;
+
+        let mut response: ModmailConversations = serde_json::from_str(json).unwrap();
+        let data = response.conversations.remove("abc12").unwrap();
+        let conversation = ModmailConversation::new((), data, &mut response.messages);
+
+        assert_eq!(conversation.subreddit(), "rust");
+        assert_eq!(conversation.participant.name, "someone");
+        assert!(!conversation.is_unread());
+        assert!(!conversation.is_archived());
+
+        let bodies: Vec<_> = conversation
+            .messages()
+            .iter()
+            .map(|m| m.body_markdown.as_str())
+            .collect();
+        assert_eq!(bodies, ["Why?", "Spam."]);
+        assert!(conversation.last_message().unwrap().author.is_hidden);
+    }
+}
@@ -3,12 +3,14 @@
 use crate::{
     api::{subreddit::ModQueueItem, ThingFullname},
     models::{FromClientAndData, LatestComment, Listing, Submission},
+    util::export::Export,
 };
 
-/// Returned by `/about/modqueue`, a list of things that need moderator review.
+/// Returned by `/about/modqueue` and the other moderation queues, a list of submissions and comments.
 pub type Modqueue<T> = Listing<QueueThing<T>>;
 
-/// A thing which needs moderator review
+/// A thing which needs moderator review, or any other submission or comment in a listing which
+/// mixes both, such as gilded content.
 pub enum QueueThing<T> {
     /// A submission
     Submission(Submission<T>),
@@ -42,3 +44,12 @@ impl<T> FromClientAndData<T, ModQueueItem> for QueueThing<T> {
         }
     }
 }
+
+impl<T> Export for QueueThing<T> {
+    fn export_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        match self {
+            QueueThing::Submission(submission) => submission.export_value(),
+            QueueThing::Comment(comment) => comment.export_value(),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Models for multireddits.
+
+use crate::api::multi::MultiData;
+use crate::client::endpoint::EndpointBuilder;
+use crate::client::RedditClient;
+use crate::models::submission::Submissions;
+use crate::models::{FromClientAndData, Listing};
+use crate::util::{FeedOption, RouxError};
+
+/// A multireddit (custom feed), which combines the submissions of several subreddits.
+///
+/// This dereferences to the raw [`MultiData`].
+pub struct Multireddit<T> {
+    client: T,
+    data: MultiData,
+}
+
+impl<T> std::ops::Deref for Multireddit<T> {
+    type Target = MultiData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> Multireddit<T> {
+    /// The names of the subreddits combined in the multireddit.
+    pub fn subreddit_names(&self) -> impl Iterator<Item = &str> {
+        self.data.subreddits.iter().map(|sub| sub.name.as_str())
+    }
+
+    /// When the multireddit was created.
+    pub fn created_at(&self) -> Option<std::time::SystemTime> {
+        crate::util::utc_timestamp(self.data.created_utc)
+    }
+}
+
+impl<T: RedditClient + Clone> Multireddit<T> {
+    #[maybe_async::maybe_async]
+    async fn get_feed(
+        &self,
+        sort: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<T>, RouxError> {
+        let path = self.data.path.trim_matches('/');
+        let source = EndpointBuilder::new(format!("{path}/{sort}"));
+        let mut endpoint = source.clone();
+
+        if let Some(options) = options.clone() {
+            options.build_url(&mut endpoint);
+        }
+
+        let api: crate::api::APISubmissions = self.client.get_json(endpoint).await?;
+        Ok(Listing::new(api, self.client.clone()).with_source(source, options))
+    }
+
+    /// Get hot posts.
+    #[maybe_async::maybe_async]
+    pub async fn hot(&self, options: Option<FeedOption>) -> Result<Submissions<T>, RouxError> {
+        self.get_feed("hot", options).await
+    }
+
+    /// Get rising posts.
+    #[maybe_async::maybe_async]
+    pub async fn rising(&self, options: Option<FeedOption>) -> Result<Submissions<T>, RouxError> {
+        self.get_feed("rising", options).await
+    }
+
+    /// Get top posts.
+    #[maybe_async::maybe_async]
+    pub async fn top(&self, options: Option<FeedOption>) -> Result<Submissions<T>, RouxError> {
+        self.get_feed("top", options).await
+    }
+
+    /// Get controversial posts.
+    #[maybe_async::maybe_async]
+    pub async fn controversial(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<T>, RouxError> {
+        self.get_feed("controversial", options).await
+    }
+
+    /// Get latest posts.
+    #[maybe_async::maybe_async]
+    pub async fn latest(&self, options: Option<FeedOption>) -> Result<Submissions<T>, RouxError> {
+        self.get_feed("new", options).await
+    }
+}
+
+impl<T> FromClientAndData<T, MultiData> for Multireddit<T> {
+    fn new(client: T, data: MultiData) -> Self {
+        Self { client, data }
+    }
+}
@@ -0,0 +1,34 @@
+//! Helper model for users muted in a subreddit.
+
+use crate::api::subreddit::MutedUserData;
+use crate::client::{RedditClient, User};
+
+use super::FromClientAndData;
+
+/// A user muted in a subreddit's modmail, as returned by
+/// [`Subreddit::muted`](crate::client::Subreddit::muted).
+pub struct MutedUser<T> {
+    client: T,
+    data: MutedUserData,
+}
+
+impl<T> std::ops::Deref for MutedUser<T> {
+    type Target = MutedUserData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: RedditClient + Clone> MutedUser<T> {
+    /// Creates a helper for the muted user.
+    pub fn user(&self) -> User<T> {
+        self.client.user(&self.data.name)
+    }
+}
+
+impl<T> FromClientAndData<T, MutedUserData> for MutedUser<T> {
+    fn new(client: T, data: MutedUserData) -> Self {
+        Self { client, data }
+    }
+}
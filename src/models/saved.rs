@@ -1,4 +1,5 @@
 use crate::api::saved::SavedData;
+use crate::util::export::Export;
 
 use super::{FromClientAndData, LatestComment, Submission};
 
@@ -18,3 +19,12 @@ impl<T> FromClientAndData<T, SavedData> for Saved<T> {
         }
     }
 }
+
+impl<T> Export for Saved<T> {
+    fn export_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        match self {
+            Saved::Submission(submission) => submission.export_value(),
+            Saved::Comment(comment) => comment.export_value(),
+        }
+    }
+}
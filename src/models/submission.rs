@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use serde::Serialize;
@@ -5,14 +6,20 @@ use serde_json::Value;
 
 use crate::{
     api::{
+        comment::PartialArticleCommentsResponse,
+        edited::EditedItem,
         submission::{
-            SubmissionData, SubmissionDataGalleryData, SubmissionDataMediaMetadata,
-            SubmissionDataPreview, SubmissionModerationData,
+            DiscussionType, DuplicatesResponse, RemovedByCategory, SubmissionData,
+            SubmissionDataGalleryData, SubmissionDataMediaMetadata, SubmissionDataPreview,
+            SubmissionModerationData,
         },
         Distinguished, FlairId, ThingFullname,
     },
     builders::form::FormBuilder,
-    client::{RedditClient, RemoveReason, SelectFlairData},
+    client::{
+        endpoint::EndpointBuilder, RedditClient, RemoveReason, SelectFlairData, VoteDirection,
+    },
+    util::{markdown, FeedOption},
     RouxError,
 };
 
@@ -20,6 +27,31 @@ use super::{comment::ArticleComments, CreatedComment, Distinguish, FromClientAnd
 
 pub(crate) type Submissions<T> = Listing<Submission<T>>;
 
+/// A submission with its comments, from [`RedditClient::submission_with_comments`].
+pub struct SubmissionWithComments<T> {
+    /// The submission.
+    pub submission: Submission<T>,
+    /// The comments, or an empty listing if they could not be parsed.
+    pub comments: ArticleComments<T>,
+    /// Why the comments could not be parsed, if they could not be.
+    pub comments_error: Option<RouxError>,
+}
+
+impl<T: Clone> FromClientAndData<T, PartialArticleCommentsResponse> for SubmissionWithComments<T> {
+    fn new(client: T, data: PartialArticleCommentsResponse) -> Self {
+        let (comments, comments_error) = match data.comments {
+            Ok(comments) => (Listing::new_outer(comments, client.clone()), None),
+            Err(error) => (Listing::empty(), Some(RouxError::parse(error))),
+        };
+
+        Self {
+            submission: Submission::new(client, data.submission),
+            comments,
+            comments_error,
+        }
+    }
+}
+
 /// A Submission in a subreddit.
 pub struct Submission<T> {
     client: T,
@@ -43,14 +75,23 @@ impl<T> Submission<T> {
     }
     /// The self text in **Markdown** format, if this is a self post. Unlike `selftext_html`, this
     /// is an **empty string** if this is a link post.
-    pub fn selftext(&self) -> &String {
+    pub fn selftext(&self) -> &str {
         &self.data.selftext
     }
+    /// The self text, with Reddit's escaping of `&`, `<` and `>` undone. This is only needed by
+    /// clients built with [`Config::raw_json(false)`](crate::Config::raw_json).
+    pub fn selftext_unescaped(&self) -> Cow<'_, str> {
+        markdown::unescape(&self.data.selftext)
+    }
     /// This is `Some(true)` if the logged-in user has upvoted this submission, `Some(false)` if
     /// the user has downvoted this submission or `None` if the user has not voted.
     pub fn likes(&self) -> Option<bool> {
         self.data.likes.clone()
     }
+    /// How the logged-in user has voted on this submission.
+    pub fn my_vote(&self) -> VoteDirection {
+        VoteDirection::from_likes(self.data.likes)
+    }
     /// If a specific sort method is suggested, this is set to the string name of it, otherwise
     /// it is `None`.
     /// # Possible values
@@ -119,6 +160,13 @@ impl<T> Submission<T> {
     pub fn preview(&self) -> &Option<SubmissionDataPreview> {
         &self.data.preview
     }
+    /// This is `true` if the submission has a preview which is shown.
+    pub fn preview_enabled(&self) -> bool {
+        self.data
+            .preview
+            .as_ref()
+            .is_some_and(|preview| preview.enabled)
+    }
     /// The number of comment replies to this submission.
     pub fn num_comments(&self) -> u64 {
         self.data.num_comments
@@ -190,6 +238,26 @@ impl<T> Submission<T> {
     pub fn is_video(&self) -> bool {
         self.data.is_video
     }
+    /// How the comments are shown, if not as usual.
+    pub fn discussion_type(&self) -> Option<&DiscussionType> {
+        self.data.discussion_type.as_ref()
+    }
+    /// When the event starts, if this is an event post.
+    pub fn event_start(&self) -> Option<std::time::SystemTime> {
+        crate::util::utc_timestamp(self.data.event_start?)
+    }
+    /// When the event ends, if this is an event post.
+    pub fn event_end(&self) -> Option<std::time::SystemTime> {
+        crate::util::utc_timestamp(self.data.event_end?)
+    }
+    /// This is `true` if this is an event post and the event is happening now.
+    pub fn event_is_live(&self) -> bool {
+        self.data.event_is_live
+    }
+    /// What kind of post this is, for processing feeds of mixed posts.
+    pub fn kind(&self) -> SubmissionKind {
+        SubmissionKind::of(&self.data)
+    }
     // TODO: skipped from_id
     /// The permanent, long link for this submission.
     pub fn permalink(&self) -> &str {
@@ -200,6 +268,15 @@ impl<T> Submission<T> {
     pub fn locked(&self) -> bool {
         self.data.locked
     }
+    /// Whether the submission can still be voted on, i.e. neither it nor its subreddit is archived.
+    pub fn can_vote(&self) -> bool {
+        !self.data.archived && self.data.subreddit_type.as_deref() != Some("archived")
+    }
+    /// Whether comments can still be made on the submission: it must be votable and not locked,
+    /// although moderators of the subreddit can comment on locked submissions.
+    pub fn can_comment(&self) -> bool {
+        self.can_vote() && (!self.data.locked || self.data.moderation.is_some())
+    }
     /// The full 'Thing ID', consisting of a 'kind' and a base-36 identifier. The valid kinds are:
     /// - t1_ - Comment
     /// - t2_ - Account
@@ -253,13 +330,98 @@ impl<T> Submission<T> {
     pub fn media_metadata(&self) -> &Option<HashMap<String, SubmissionDataMediaMetadata>> {
         &self.data.media_metadata
     }
+    /// The media attached to this submission: a Reddit-hosted video, each image of a gallery, or a
+    /// linked image. Empty if there is no media.
+    pub fn media_links(&self) -> Vec<MediaLink> {
+        let video = self
+            .data
+            .media
+            .as_ref()
+            .and_then(|m| m.reddit_video.as_ref());
+        if let Some(video) = video {
+            return vec![MediaLink {
+                kind: MediaKind::Video,
+                url: video.fallback_url.clone(),
+                caption: None,
+            }];
+        }
 
+        if let (Some(gallery), Some(metadata)) =
+            (&self.data.gallery_data, &self.data.media_metadata)
+        {
+            return gallery_links(gallery, metadata);
+        }
+
+        self.data
+            .url
+            .as_deref()
+            .and_then(linked_image)
+            .into_iter()
+            .collect()
+    }
+    /// The media attached to this submission as markdown links, one paragraph per item, for
+    /// mirroring or cross-posting. `None` if there is no media.
+    pub fn media_markdown(&self) -> Option<String> {
+        let links = self.media_links();
+        if links.is_empty() {
+            return None;
+        }
+
+        Some(
+            links
+                .iter()
+                .map(MediaLink::to_markdown)
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+        )
+    }
     /// Moderation related data for this post.
     ///
     /// This is present only if you are a moderator and can moderate this post.
     pub fn moderation(&self) -> Option<&SubmissionModerationData> {
         self.data.moderation.as_ref()
     }
+
+    /// Whether the submission is still visible, or was removed or deleted.
+    ///
+    /// Reddit only says why a post is gone to moderators, so for everyone else this relies on
+    /// the `[removed]`/`[deleted]` placeholders, which link posts do not have.
+    pub fn status(&self) -> SubmissionStatus {
+        SubmissionStatus::of(&self.data)
+    }
+
+    /// Whether the submission was removed by moderators, AutoModerator or Reddit.
+    pub fn is_removed(&self) -> bool {
+        matches!(self.status(), SubmissionStatus::Removed(_))
+    }
+
+    /// Whether the author deleted the submission.
+    pub fn is_deleted(&self) -> bool {
+        self.status() == SubmissionStatus::Deleted
+    }
+
+    /// The submission's score, comment count and upvote ratio as of when it was fetched.
+    pub fn snapshot(&self) -> SubmissionSnapshot {
+        SubmissionSnapshot {
+            score: self.data.score,
+            num_comments: self.data.num_comments,
+            upvote_ratio: self.data.upvote_ratio,
+            sampled_at: std::time::SystemTime::now(),
+        }
+    }
+}
+
+/// A sample of how a submission is performing, yielded by [`Submission::watch`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionSnapshot {
+    /// The score, see [`Submission::score`].
+    pub score: f64,
+    /// The number of comments.
+    pub num_comments: u64,
+    /// The ratio of upvotes to total votes.
+    pub upvote_ratio: f64,
+    /// When the sample was taken.
+    pub sampled_at: std::time::SystemTime,
 }
 
 impl<T: RedditClient + Clone> Submission<T> {
@@ -274,6 +436,62 @@ impl<T: RedditClient + Clone> Submission<T> {
             .article_comments(&self.data.subreddit, self.name(), depth, limit)
             .await
     }
+
+    /// Fetches other submissions of the same link, including crossposts of this submission.
+    ///
+    /// The listing cannot be paged with [`Listing::next_page`]; request following pages with
+    /// [`FeedOption::after`] instead.
+    #[maybe_async::maybe_async]
+    pub async fn duplicates(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions<T>, RouxError> {
+        let mut endpoint = EndpointBuilder::new(format!("duplicates/{}", self.data.id));
+
+        if let Some(options) = options {
+            options.build_url(&mut endpoint);
+        }
+
+        let response: DuplicatesResponse = self.client.get_json(endpoint).await?;
+        Ok(Listing::new(response.duplicates, self.client.clone()))
+    }
+}
+
+#[cfg(all(feature = "streams", not(feature = "blocking")))]
+impl<T: RedditClient + Clone> Submission<T> {
+    /// Periodically re-fetches this submission, yielding a snapshot of it every `interval`.
+    ///
+    /// The first snapshot is taken immediately. Requests go through the client, so ratelimits
+    /// are waited out rather than yielded. Other errors are yielded and watching continues. The
+    /// stream ends if the submission can no longer be fetched, e.g. because it was deleted.
+    pub fn watch(
+        &self,
+        interval: std::time::Duration,
+    ) -> impl futures_util::Stream<Item = Result<SubmissionSnapshot, RouxError>> {
+        use crate::client::req::sleep;
+
+        let client = self.client.clone();
+        let name = self.data.name.clone();
+
+        futures_util::stream::unfold(Some(true), move |state| {
+            let client = client.clone();
+            let name = name.clone();
+            async move {
+                let first = state?;
+                if !first {
+                    sleep(interval).await;
+                }
+
+                match client.get_submissions(&[&name]).await {
+                    Ok(submissions) => {
+                        let submission = submissions.children.into_iter().next()?;
+                        Some((Ok(submission.snapshot()), Some(false)))
+                    }
+                    Err(error) => Some((Err(error), Some(false))),
+                }
+            }
+        })
+    }
 }
 
 impl Submission<crate::client::AuthedClient> {
@@ -297,11 +515,65 @@ impl Submission<crate::client::AuthedClient> {
         self.client.comment(text, &self.data.name).await
     }
 
-    /// Sets the [`Submission::selftext`]
+    /// Sets the [`Submission::selftext`], updating this submission to match Reddit's copy
+    /// (e.g. `selftext_html` and `edited`).
     #[maybe_async::maybe_async]
     pub async fn edit(&mut self, text: &str) -> Result<(), RouxError> {
-        self.client.edit(text, self.name()).await?;
-        self.data.selftext = text.to_owned();
+        match self.client.edit_raw(text, self.name()).await? {
+            EditedItem::Submission(data) => self.data = data,
+            EditedItem::Comment(_) => {
+                return Err(RouxError::unexpected_response(
+                    "api/editusertext returned a comment for a submission",
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Votes on this submission, updating [`Submission::likes`], [`Submission::ups`] and
+    /// [`Submission::score`] to match.
+    #[maybe_async::maybe_async]
+    pub async fn vote(&mut self, dir: VoteDirection) -> Result<(), RouxError> {
+        self.client.vote(self.name(), dir).await?;
+
+        let (ups, score) = self.my_vote().deltas_to(dir);
+        self.data.ups += f64::from(ups);
+        self.data.score += f64::from(score);
+        self.data.likes = dir.likes();
+        Ok(())
+    }
+
+    /// Upvotes this submission.
+    #[maybe_async::maybe_async]
+    pub async fn upvote(&mut self) -> Result<(), RouxError> {
+        self.vote(VoteDirection::Up).await
+    }
+
+    /// Downvotes this submission.
+    #[maybe_async::maybe_async]
+    pub async fn downvote(&mut self) -> Result<(), RouxError> {
+        self.vote(VoteDirection::Down).await
+    }
+
+    /// Removes the vote on this submission.
+    #[maybe_async::maybe_async]
+    pub async fn unvote(&mut self) -> Result<(), RouxError> {
+        self.vote(VoteDirection::None).await
+    }
+
+    /// Saves this submission, optionally into a category, updating [`Submission::saved`].
+    #[maybe_async::maybe_async]
+    pub async fn save(&mut self, category: Option<&str>) -> Result<(), RouxError> {
+        self.client.save(self.name(), category).await?;
+        self.data.saved = true;
+        Ok(())
+    }
+
+    /// Unsaves this submission, updating [`Submission::saved`].
+    #[maybe_async::maybe_async]
+    pub async fn unsave(&mut self) -> Result<(), RouxError> {
+        self.client.unsave(self.name()).await?;
+        self.data.saved = false;
         Ok(())
     }
 
@@ -311,6 +583,38 @@ impl Submission<crate::client::AuthedClient> {
         self.client.remove(self.name(), spam).await
     }
 
+    /// Approves this submission, requires moderator permission in the subreddit.
+    ///
+    /// Updates [`Submission::moderation`] to match, apart from `approved_by`.
+    #[maybe_async::maybe_async]
+    pub async fn approve(&mut self) -> Result<(), RouxError> {
+        self.client.approve(self.name()).await?;
+        if let Some(moderation) = &mut self.data.moderation {
+            moderation.mark_approved(crate::util::utc_now());
+        }
+        Ok(())
+    }
+
+    /// Ignores further reports on this submission, updating [`Submission::moderation`] to match.
+    #[maybe_async::maybe_async]
+    pub async fn ignore_reports(&mut self) -> Result<(), RouxError> {
+        self.client.ignore_reports(self.name()).await?;
+        if let Some(moderation) = &mut self.data.moderation {
+            moderation.ignore_reports = true;
+        }
+        Ok(())
+    }
+
+    /// Stops ignoring reports on this submission, updating [`Submission::moderation`] to match.
+    #[maybe_async::maybe_async]
+    pub async fn unignore_reports(&mut self) -> Result<(), RouxError> {
+        self.client.unignore_reports(self.name()).await?;
+        if let Some(moderation) = &mut self.data.moderation {
+            moderation.ignore_reports = false;
+        }
+        Ok(())
+    }
+
     /// Removes this submission with the provided reason.
     #[maybe_async::maybe_async]
     pub async fn remove_with_reason(
@@ -334,6 +638,36 @@ impl Submission<crate::client::AuthedClient> {
         self.client.unlock(self.name()).await
     }
 
+    /// Follows or unfollows this submission, see [`AuthedClient::follow_post`](crate::client::AuthedClient::follow_post).
+    #[maybe_async::maybe_async]
+    pub async fn follow(&self, follow: bool) -> Result<(), RouxError> {
+        self.client.follow_post(self.name(), follow).await
+    }
+
+    /// Marks or unmarks this submission as NSFW, updating [`Submission::over_18`] to match.
+    #[maybe_async::maybe_async]
+    pub async fn set_nsfw(&mut self, nsfw: bool) -> Result<(), RouxError> {
+        if nsfw {
+            self.client.mark_nsfw(self.name()).await?;
+        } else {
+            self.client.unmark_nsfw(self.name()).await?;
+        }
+        self.data.over_18 = nsfw;
+        Ok(())
+    }
+
+    /// Marks or unmarks this submission as a spoiler, updating [`Submission::spoiler`] to match.
+    #[maybe_async::maybe_async]
+    pub async fn set_spoiler(&mut self, spoiler: bool) -> Result<(), RouxError> {
+        if spoiler {
+            self.client.spoiler(self.name()).await?;
+        } else {
+            self.client.unspoiler(self.name()).await?;
+        }
+        self.data.spoiler = spoiler;
+        Ok(())
+    }
+
     /// Distinguishes this submission.
     #[maybe_async::maybe_async]
     pub async fn distinguish(&self, kind: Distinguish) -> Result<(), RouxError> {
@@ -382,6 +716,184 @@ impl<T> FromClientAndData<T, SubmissionData> for Submission<T> {
     }
 }
 
+impl<T> crate::util::export::Export for Submission<T> {
+    fn export_value(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(&self.data)
+    }
+}
+
+/// Whether a submission's content is still there, see [`Submission::status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionStatus {
+    /// The submission is visible.
+    Visible,
+    /// The submission is visible, but its author deleted their account.
+    AuthorDeleted,
+    /// The submission was removed, by whom if known.
+    Removed(Option<RemovedByCategory>),
+    /// The author deleted the submission.
+    Deleted,
+}
+
+impl SubmissionStatus {
+    fn of(data: &SubmissionData) -> Self {
+        if let Some(moderation) = &data.moderation {
+            match &moderation.removed_by_category {
+                Some(RemovedByCategory::Deleted) => return SubmissionStatus::Deleted,
+                Some(category) => return SubmissionStatus::Removed(Some(category.clone())),
+                None if moderation.removed || moderation.spam => {
+                    return SubmissionStatus::Removed(None)
+                }
+                None => {}
+            }
+        }
+
+        match (data.selftext.as_str(), data.author.as_str()) {
+            ("[removed]", _) => SubmissionStatus::Removed(None),
+            ("[deleted]", _) => SubmissionStatus::Deleted,
+            (_, "[deleted]") => SubmissionStatus::AuthorDeleted,
+            _ => SubmissionStatus::Visible,
+        }
+    }
+}
+
+/// What kind of post a submission is, see [`Submission::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionKind {
+    /// A text post
+    Text,
+    /// A link post
+    Link,
+    /// A gallery of images
+    Gallery,
+    /// A video hosted by Reddit
+    Video,
+    /// A poll
+    Poll,
+    /// A prediction, which users bet on
+    Prediction,
+    /// A live chat post, where comments are shown as a chat
+    LiveChat,
+    /// A Reddit Public Access Network (RPAN) broadcast
+    Broadcast,
+}
+
+impl SubmissionKind {
+    fn of(data: &SubmissionData) -> Self {
+        if data.rpan_video.is_some() {
+            SubmissionKind::Broadcast
+        } else if data.discussion_type == Some(DiscussionType::Chat) {
+            SubmissionKind::LiveChat
+        } else if let Some(poll) = &data.poll_data {
+            if poll.is_prediction {
+                SubmissionKind::Prediction
+            } else {
+                SubmissionKind::Poll
+            }
+        } else if data.is_gallery {
+            SubmissionKind::Gallery
+        } else if data.is_video {
+            SubmissionKind::Video
+        } else if data.is_self {
+            SubmissionKind::Text
+        } else {
+            SubmissionKind::Link
+        }
+    }
+}
+
+/// The kind of a piece of media attached to a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaKind {
+    /// A still image
+    Image,
+    /// A gif
+    AnimatedImage,
+    /// A video
+    Video,
+}
+
+/// A piece of media attached to a submission, see [`Submission::media_links`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaLink {
+    /// What kind of media this is.
+    pub kind: MediaKind,
+    /// A direct link to the media.
+    pub url: String,
+    /// The caption, for gallery items which have one.
+    pub caption: Option<String>,
+}
+
+impl MediaLink {
+    /// Formats this as a markdown link, labelled with the caption if there is one.
+    pub fn to_markdown(&self) -> String {
+        let label = match (&self.caption, self.kind) {
+            (Some(caption), _) => caption
+                .replace('\\', "\\\\")
+                .replace('[', "\\[")
+                .replace(']', "\\]"),
+            (None, MediaKind::Image) => String::from("Image"),
+            (None, MediaKind::AnimatedImage) => String::from("GIF"),
+            (None, MediaKind::Video) => String::from("Video"),
+        };
+        let url = self
+            .url
+            .replace(' ', "%20")
+            .replace('(', "%28")
+            .replace(')', "%29");
+
+        format!("[{label}]({url})")
+    }
+}
+
+fn gallery_links(
+    gallery: &SubmissionDataGalleryData,
+    metadata: &HashMap<String, SubmissionDataMediaMetadata>,
+) -> Vec<MediaLink> {
+    gallery
+        .items
+        .iter()
+        .filter_map(|item| {
+            let (kind, url) = match metadata.get(&item.media_id)? {
+                SubmissionDataMediaMetadata::Image { s, .. } => (MediaKind::Image, &s.u),
+                SubmissionDataMediaMetadata::AnimatedImage { s, .. } => {
+                    (MediaKind::AnimatedImage, &s.gif)
+                }
+                SubmissionDataMediaMetadata::RedditVideo { hls_url, .. } => {
+                    (MediaKind::Video, hls_url)
+                }
+                SubmissionDataMediaMetadata::Unknown => return None,
+            };
+
+            Some(MediaLink {
+                kind,
+                url: html_escape::decode_html_entities(url).into_owned(),
+                caption: item.caption.clone().filter(|c| !c.is_empty()),
+            })
+        })
+        .collect()
+}
+
+fn linked_image(url: &str) -> Option<MediaLink> {
+    let path = url.split(['?', '#']).next()?.to_ascii_lowercase();
+    let kind = if path.ends_with(".gif") {
+        MediaKind::AnimatedImage
+    } else if [".png", ".jpg", ".jpeg", ".webp"]
+        .iter()
+        .any(|ext| path.ends_with(ext))
+    {
+        MediaKind::Image
+    } else {
+        return None;
+    };
+
+    Some(MediaLink {
+        kind,
+        url: url.to_owned(),
+        caption: None,
+    })
+}
+
 /// An extracted representation of a submission link.
 ///
 /// This may include a title, and if so may further include a comment ID.
@@ -495,7 +1007,122 @@ impl<'a> SubmissionLinkInfo<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::SubmissionLinkInfo;
+    use super::{
+        gallery_links, linked_image, MediaKind, Submission, SubmissionKind, SubmissionLinkInfo,
+        SubmissionStatus,
+    };
+    use crate::api::submission::{
+        test_submission, RemovedByCategory, SubmissionData, SubmissionDataGalleryData,
+    };
+    use crate::models::FromClientAndData;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn submission(extra: &str) -> SubmissionData {
+        let extra = serde_json::from_str(&format!("{{{extra}}}")).unwrap();
+        serde_json::from_value(test_submission(extra)).unwrap()
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut data = submission(r#""is_self": true, "num_comments": 7"#);
+        data.score = 42.0;
+        data.upvote_ratio = 0.9;
+        let snapshot = Submission::new((), data).snapshot();
+
+        assert_eq!(snapshot.score, 42.0);
+        assert_eq!(snapshot.num_comments, 7);
+        assert_eq!(snapshot.upvote_ratio, 0.9);
+    }
+
+    #[test]
+    fn test_event() {
+        let event = Submission::new(
+            (),
+            submission(
+                r#""is_self": true, "event_start": 1714586400.0, "event_end": 1714593600.0, "event_is_live": true"#,
+            ),
+        );
+        assert_eq!(
+            event.event_start(),
+            Some(UNIX_EPOCH + Duration::from_secs(1714586400))
+        );
+        assert_eq!(
+            event.event_end(),
+            Some(UNIX_EPOCH + Duration::from_secs(1714593600))
+        );
+        assert!(event.event_is_live());
+
+        let post = Submission::new((), submission(r#""is_self": true"#));
+        assert_eq!(post.event_start(), None);
+        assert!(!post.event_is_live());
+    }
+
+    #[test]
+    fn test_status() {
+        let status = |extra| SubmissionStatus::of(&submission(extra));
+
+        assert_eq!(status(r#""is_self": true"#), SubmissionStatus::Visible);
+
+        let mut data = submission(r#""is_self": true"#);
+        data.selftext = String::from("[removed]");
+        assert_eq!(SubmissionStatus::of(&data), SubmissionStatus::Removed(None));
+        data.author = String::from("[deleted]");
+        assert_eq!(SubmissionStatus::of(&data), SubmissionStatus::Removed(None));
+        data.selftext = String::from("[deleted]");
+        assert_eq!(SubmissionStatus::of(&data), SubmissionStatus::Deleted);
+        data.selftext = String::from("Still here");
+        assert_eq!(SubmissionStatus::of(&data), SubmissionStatus::AuthorDeleted);
+
+        let moderated = submission(
+            r#""is_self": false, "can_mod_post": true, "approved": false, "approved_at_utc": null,
+            "approved_by": null, "ban_note": null, "banned_at_utc": 0, "banned_by": "AutoModerator",
+            "ignore_reports": false, "mod_note": null, "mod_reason_by": null,
+            "mod_reason_title": null, "mod_reports": [], "num_reports": 0, "removal_reason": null,
+            "removed": false, "removed_by": null, "removed_by_category": "automod_filtered",
+            "spam": false, "user_reports": []"#,
+        );
+        let submission = Submission::new((), moderated);
+        assert_eq!(
+            submission.status(),
+            SubmissionStatus::Removed(Some(RemovedByCategory::AutoModerator))
+        );
+        assert!(submission.is_removed());
+        assert!(!submission.is_deleted());
+    }
+
+    #[test]
+    fn test_submission_kind() {
+        let kind = |extra| SubmissionKind::of(&submission(extra));
+
+        assert_eq!(kind(r#""is_self": true"#), SubmissionKind::Text);
+        assert_eq!(kind(r#""is_self": false"#), SubmissionKind::Link);
+        assert_eq!(
+            kind(r#""is_self": false, "is_gallery": true"#),
+            SubmissionKind::Gallery
+        );
+        assert_eq!(
+            kind(r#""is_self": true, "discussion_type": "CHAT""#),
+            SubmissionKind::LiveChat
+        );
+        assert_eq!(
+            kind(r#""is_self": true, "discussion_type": "SOMETHING_NEW""#),
+            SubmissionKind::Text
+        );
+        assert_eq!(
+            kind(
+                r#""is_self": true, "poll_data": {"options": [{"id": "1", "text": "Yes"}], "total_vote_count": 3}"#
+            ),
+            SubmissionKind::Poll
+        );
+        assert_eq!(
+            kind(r#""is_self": true, "poll_data": {"is_prediction": true}"#),
+            SubmissionKind::Prediction
+        );
+        assert_eq!(
+            kind(r#""is_self": false, "is_video": true, "rpan_video": {"hls_url": null}"#),
+            SubmissionKind::Broadcast
+        );
+    }
 
     #[test]
     pub fn extracts_submission_info() {
@@ -547,4 +1174,43 @@ mod tests {
 
         assert!(SubmissionLinkInfo::parse("https://www.reddit.com/r/sub123/comments").is_err());
     }
+
+    #[test]
+    fn test_gallery_markdown() {
+        let gallery: SubmissionDataGalleryData = serde_json::from_str(
+            r#"{"items": [
+                {"caption": "First [one]", "id": 1, "media_id": "abc"},
+                {"id": 2, "media_id": "def"},
+                {"id": 3, "media_id": "missing"}
+            ]}"#,
+        )
+        .unwrap();
+        let metadata = serde_json::from_str(
+            r#"{
+                "abc": {"e": "Image", "id": "abc", "m": "image/png",
+                        "s": {"u": "https://preview.redd.it/abc.png?width=1&amp;s=x", "x": 1, "y": 1}},
+                "def": {"e": "AnimatedImage", "id": "def", "m": "image/gif",
+                        "s": {"gif": "https://i.redd.it/def.gif", "mp4": "https://i.redd.it/def.mp4", "x": 1, "y": 1}}
+            }"#,
+        )
+        .unwrap();
+
+        let links = gallery_links(&gallery, &metadata);
+        assert_eq!(links.len(), 2);
+        assert_eq!(
+            links[0].to_markdown(),
+            "[First \\[one\\]](https://preview.redd.it/abc.png?width=1&s=x)"
+        );
+        assert_eq!(links[1].kind, MediaKind::AnimatedImage);
+        assert_eq!(links[1].to_markdown(), "[GIF](https://i.redd.it/def.gif)");
+    }
+
+    #[test]
+    fn test_linked_image() {
+        assert_eq!(
+            linked_image("https://i.redd.it/abc.JPG?x=1").map(|l| l.kind),
+            Some(MediaKind::Image)
+        );
+        assert_eq!(linked_image("https://example.com/article"), None);
+    }
 }
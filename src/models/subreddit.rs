@@ -0,0 +1,223 @@
+//! Helper model for a subreddit's about data.
+
+use crate::api::subreddit::{AccountsActive, CommentSort, SubmissionType, SubredditData};
+use crate::builders::flair::FlairTextConstraints;
+use crate::client::{RedditClient, Subreddit};
+
+use super::FromClientAndData;
+
+/// Information about a subreddit, as returned by [`Subreddit::about`].
+///
+/// This dereferences to the raw [`SubredditData`], with accessors for the commonly needed fields
+/// which smooth over Reddit's inconsistencies (e.g. empty strings meaning "not set").
+pub struct SubredditAbout<T> {
+    client: T,
+    data: SubredditData,
+}
+
+impl<T> std::ops::Deref for SubredditAbout<T> {
+    type Target = SubredditData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|v| !v.is_empty())
+}
+
+impl<T> SubredditAbout<T> {
+    /// The types of submission allowed, or [`SubmissionType::Unknown`] if Reddit didn't say.
+    pub fn submission_type(&self) -> SubmissionType {
+        self.data.submission_type.unwrap_or(SubmissionType::Unknown)
+    }
+
+    /// Whether link posts can be submitted.
+    pub fn allows_links(&self) -> bool {
+        matches!(
+            self.submission_type(),
+            SubmissionType::Any | SubmissionType::Link
+        )
+    }
+
+    /// Whether self (text) posts can be submitted.
+    pub fn allows_self_posts(&self) -> bool {
+        matches!(
+            self.submission_type(),
+            SubmissionType::Any | SubmissionType::SelfPost
+        )
+    }
+
+    /// Whether images can be uploaded.
+    pub fn allows_images(&self) -> bool {
+        self.data.allow_images.unwrap_or(false)
+    }
+
+    /// Whether videos can be uploaded.
+    pub fn allows_videos(&self) -> bool {
+        self.data.allow_videos.unwrap_or(false)
+    }
+
+    /// Whether gallery posts can be submitted.
+    pub fn allows_galleries(&self) -> bool {
+        self.data.allow_galleries.unwrap_or(false)
+    }
+
+    /// Whether poll posts can be submitted.
+    pub fn allows_polls(&self) -> bool {
+        self.data.allow_polls.unwrap_or(false)
+    }
+
+    /// Whether emojis can be used, e.g. in flair.
+    pub fn emojis_enabled(&self) -> bool {
+        self.data.emojis_enabled.unwrap_or(false)
+    }
+
+    /// The width and height custom emojis are displayed at, if the subreddit has set a custom size.
+    pub fn emoji_custom_size(&self) -> Option<(u64, u64)> {
+        match self.data.emojis_custom_size.as_deref()? {
+            [width, height] => Some((*width, *height)),
+            _ => None,
+        }
+    }
+
+    /// The rules flair text must follow in this subreddit.
+    pub fn flair_text_constraints(&self) -> FlairTextConstraints {
+        FlairTextConstraints {
+            emojis_enabled: self.emojis_enabled(),
+            ..FlairTextConstraints::default()
+        }
+    }
+
+    /// The order comments are sorted in by default, if the subreddit has set one.
+    pub fn suggested_comment_sort(&self) -> Option<CommentSort> {
+        non_empty(&self.data.suggested_comment_sort).map(|sort| CommentSort::from(sort.to_owned()))
+    }
+
+    /// Whether link flair is enabled.
+    pub fn link_flair_enabled(&self) -> bool {
+        self.data.link_flair_enabled.unwrap_or(false)
+    }
+
+    /// Whether user flair is enabled.
+    pub fn user_flair_enabled(&self) -> bool {
+        self.data.user_flair_enabled_in_sr.unwrap_or(false)
+    }
+
+    /// The (approximate) number of users active in the past 15 minutes, if Reddit provided it.
+    ///
+    /// Reddit occasionally returns an empty array for one of the duplicate fields, so both are checked.
+    pub fn active_user_count(&self) -> Option<u64> {
+        [&self.data.active_user_count, &self.data.accounts_active]
+            .into_iter()
+            .flatten()
+            .find_map(|count| match count {
+                AccountsActive::Number(count) => Some(*count),
+                AccountsActive::Vector(_) => None,
+            })
+    }
+
+    /// The number of subscribers.
+    pub fn subscribers(&self) -> u64 {
+        self.data.subscribers.unwrap_or(0)
+    }
+
+    /// The URL of the subreddit's icon, preferring the redesign's community icon.
+    pub fn icon_url(&self) -> Option<String> {
+        non_empty(&self.data.community_icon)
+            .or_else(|| non_empty(&self.data.icon_img))
+            .map(|url| html_escape::decode_html_entities(url).into_owned())
+    }
+
+    /// The URL of the subreddit's banner, preferring the desktop banner.
+    pub fn banner_url(&self) -> Option<String> {
+        non_empty(&self.data.banner_background_image)
+            .or_else(|| non_empty(&self.data.banner_img))
+            .or_else(|| non_empty(&self.data.mobile_banner_image))
+            .map(|url| html_escape::decode_html_entities(url).into_owned())
+    }
+
+    /// Consumes this, returning the underlying data.
+    pub fn into_inner(self) -> SubredditData {
+        self.data
+    }
+}
+
+impl<T: RedditClient + Clone> SubredditAbout<T> {
+    /// Creates a helper to make further requests to this subreddit.
+    pub fn subreddit(&self) -> Subreddit<T> {
+        Subreddit::new(self.data.display_name.as_str(), self.client.clone())
+    }
+}
+
+impl<T> FromClientAndData<T, SubredditData> for SubredditAbout<T> {
+    fn new(client: T, data: SubredditData) -> Self {
+        Self { client, data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::SubredditAbout;
+    use crate::api::subreddit::{SubmissionType, SubredditData};
+    use crate::models::FromClientAndData;
+
+    fn parse(extra: serde_json::Value) -> SubredditAbout<()> {
+        let mut value = json!({
+            "display_name": "rust",
+            "id": "2s7lj",
+            "name": "t5_2s7lj",
+        });
+        value
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+
+        let data: SubredditData = serde_json::from_value(value).unwrap();
+        SubredditAbout::new((), data)
+    }
+
+    #[test]
+    fn test_submission_type_fallback() {
+        let about = parse(json!({ "submission_type": "" }));
+        assert_eq!(about.submission_type(), SubmissionType::Unknown);
+        assert!(!about.allows_links());
+
+        let about = parse(json!({ "submission_type": "self" }));
+        assert!(about.allows_self_posts());
+        assert!(!about.allows_links());
+    }
+
+    #[test]
+    fn test_urls_and_active_users() {
+        let about = parse(json!({
+            "community_icon": "",
+            "icon_img": "https://example.com/icon.png?a=1&amp;b=2",
+            "banner_background_image": "",
+            "banner_img": "",
+            "active_user_count": [],
+            "accounts_active": 42,
+        }));
+
+        assert_eq!(
+            about.icon_url().as_deref(),
+            Some("https://example.com/icon.png?a=1&b=2")
+        );
+        assert_eq!(about.banner_url(), None);
+        assert_eq!(about.active_user_count(), Some(42));
+    }
+
+    #[test]
+    fn test_emoji_settings() {
+        let about = parse(json!({ "emojis_enabled": true, "emojis_custom_size": [16, 20] }));
+        assert_eq!(about.emoji_custom_size(), Some((16, 20)));
+        assert!(about.flair_text_constraints().emojis_enabled);
+
+        let about = parse(json!({ "emojis_custom_size": null }));
+        assert_eq!(about.emoji_custom_size(), None);
+        assert!(!about.flair_text_constraints().emojis_enabled);
+    }
+}
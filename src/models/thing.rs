@@ -0,0 +1,62 @@
+//! Models for things of different kinds.
+
+use crate::{
+    api::{info::InfoItem, ThingFullname},
+    models::{FromClientAndData, InfoComment, Submission, SubredditAbout},
+};
+
+/// A comment, submission or subreddit, as returned by
+/// [`RedditClient::get_things`](crate::client::RedditClient::get_things).
+pub enum Thing<T> {
+    /// A comment
+    Comment(InfoComment<T>),
+    /// A submission
+    Submission(Submission<T>),
+    /// A subreddit
+    Subreddit(SubredditAbout<T>),
+}
+
+impl<T> Thing<T> {
+    /// The thing's fullname.
+    pub fn name(&self) -> &ThingFullname {
+        match self {
+            Thing::Comment(c) => c.name(),
+            Thing::Submission(s) => s.name(),
+            Thing::Subreddit(s) => &s.name,
+        }
+    }
+
+    /// The comment, if this is one.
+    pub fn as_comment(&self) -> Option<&InfoComment<T>> {
+        match self {
+            Thing::Comment(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// The submission, if this is one.
+    pub fn as_submission(&self) -> Option<&Submission<T>> {
+        match self {
+            Thing::Submission(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The subreddit, if this is one.
+    pub fn as_subreddit(&self) -> Option<&SubredditAbout<T>> {
+        match self {
+            Thing::Subreddit(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl<T> FromClientAndData<T, InfoItem> for Thing<T> {
+    fn new(client: T, data: InfoItem) -> Self {
+        match data {
+            InfoItem::Comment(d) => Self::Comment(InfoComment::new(client, d)),
+            InfoItem::Submission(d) => Self::Submission(Submission::new(client, d)),
+            InfoItem::Subreddit(d) => Self::Subreddit(SubredditAbout::new(client, d)),
+        }
+    }
+}
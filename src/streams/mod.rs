@@ -0,0 +1,491 @@
+//! # Streams
+//! Reddit has no push API, so bots typically poll a listing such as `/r/sub/new` and keep track
+//! of which items they have already handled. The streams in this module do that polling for you,
+//! yielding each item once, oldest first.
+//!
+//! Requires the `streams` feature, and is not available with `blocking`.
+//!
+//! # Basic Usage
+//! ```no_run
+//! use futures_util::StreamExt;
+//! use roux::client::{RedditClient, UnauthedClient};
+//! use roux::streams::StreamOptions;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let client = UnauthedClient::new().unwrap();
+//! let subreddit = client.subreddit("rust");
+//!
+//! let stream = subreddit.stream_new(StreamOptions::new());
+//! futures_util::pin_mut!(stream);
+//!
+//! while let Some(submission) = stream.next().await {
+//!     match submission {
+//!         Ok(submission) => println!("{}", submission.title()),
+//!         Err(error) => eprintln!("{error}"),
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::time::Duration;
+
+use futures_util::stream::{self, Stream, StreamExt};
+
+use crate::api::ThingFullname;
+use crate::client::req::sleep;
+use crate::client::{AuthedClient, RedditClient, Subreddit};
+use crate::models::listing::ListingSource;
+use crate::models::modqueue::QueueThing;
+use crate::models::{LatestComment, Listing, Message, Submission};
+use crate::util::{FeedOption, RouxError};
+
+/// Options controlling how often a stream polls Reddit.
+#[derive(Clone, Debug)]
+pub struct StreamOptions {
+    /// How long to wait between polls while new items keep arriving.
+    pub poll_interval: Duration,
+    /// The longest to wait between polls. When a poll finds nothing new or fails,
+    /// the wait is doubled up to this value.
+    pub max_poll_interval: Duration,
+    /// The number of items to request with each poll, at most 100.
+    pub limit: u32,
+    /// Whether to skip the items which already exist when the stream starts.
+    pub skip_existing: bool,
+}
+
+impl StreamOptions {
+    /// Create a new `StreamOptions` instance.
+    pub fn new() -> StreamOptions {
+        StreamOptions {
+            poll_interval: Duration::from_secs(5),
+            max_poll_interval: Duration::from_secs(60),
+            limit: 100,
+            skip_existing: false,
+        }
+    }
+
+    /// Set the poll interval.
+    pub fn poll_interval(mut self, interval: Duration) -> StreamOptions {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set the maximum poll interval used when backing off.
+    pub fn max_poll_interval(mut self, interval: Duration) -> StreamOptions {
+        self.max_poll_interval = interval;
+        self
+    }
+
+    /// Set the number of items requested with each poll.
+    pub fn limit(mut self, limit: u32) -> StreamOptions {
+        self.limit = limit.min(100);
+        self
+    }
+
+    /// Set whether to skip items which already exist when the stream starts.
+    pub fn skip_existing(mut self, skip: bool) -> StreamOptions {
+        self.skip_existing = skip;
+        self
+    }
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Something that can be yielded by a stream, identified by its fullname.
+pub(crate) trait StreamItem {
+    fn fullname(&self) -> &ThingFullname;
+}
+
+impl<T> StreamItem for Submission<T> {
+    fn fullname(&self) -> &ThingFullname {
+        self.name()
+    }
+}
+
+impl<T> StreamItem for LatestComment<T> {
+    fn fullname(&self) -> &ThingFullname {
+        self.name()
+    }
+}
+
+impl<T> StreamItem for QueueThing<T> {
+    fn fullname(&self) -> &ThingFullname {
+        self.name()
+    }
+}
+
+impl<T> StreamItem for Message<T> {
+    fn fullname(&self) -> &ThingFullname {
+        self.name()
+    }
+}
+
+/// Remembers the most recently seen fullnames, forgetting the oldest once full.
+struct Seen {
+    order: VecDeque<ThingFullname>,
+    set: HashSet<ThingFullname>,
+    capacity: usize,
+}
+
+impl Seen {
+    fn new(capacity: usize) -> Self {
+        Self {
+            order: VecDeque::with_capacity(capacity),
+            set: HashSet::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns whether the fullname had not been seen before.
+    fn insert(&mut self, name: &ThingFullname) -> bool {
+        if !self.set.insert(name.clone()) {
+            return false;
+        }
+
+        self.order.push_back(name.clone());
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.set.remove(&oldest);
+            }
+        }
+
+        true
+    }
+}
+
+struct PollState<T, F> {
+    fetch: F,
+    options: StreamOptions,
+    seen: Seen,
+    pending: VecDeque<T>,
+    delay: Duration,
+    first: bool,
+}
+
+impl<T, F> PollState<T, F> {
+    fn back_off(&mut self) {
+        self.delay = (self.delay * 2).min(self.options.max_poll_interval);
+    }
+}
+
+/// Creates a stream by repeatedly calling `fetch` with the number of items to request.
+///
+/// `fetch` should return the newest items first, as Reddit's listings do.
+/// Each item is yielded once, oldest first. Errors are yielded without ending the stream,
+/// and cause the next poll to back off.
+pub(crate) fn poll<T, F, Fut>(
+    options: StreamOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<T, RouxError>>
+where
+    T: StreamItem,
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, RouxError>>,
+{
+    let state = PollState {
+        fetch,
+        seen: Seen::new(options.limit as usize * 10),
+        pending: VecDeque::new(),
+        delay: options.poll_interval,
+        first: true,
+        options,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.pending.pop_front() {
+                return Some((Ok(item), state));
+            }
+
+            if !state.first {
+                sleep(state.delay).await;
+            }
+
+            let result = (state.fetch)(state.options.limit).await;
+            let first = std::mem::replace(&mut state.first, false);
+
+            let items = match result {
+                Ok(items) => items,
+                Err(error) => {
+                    state.back_off();
+                    return Some((Err(error), state));
+                }
+            };
+
+            let seen = &mut state.seen;
+            let new: Vec<_> = items
+                .into_iter()
+                .rev()
+                .filter(|item| seen.insert(item.fullname()))
+                .collect();
+
+            if new.is_empty() {
+                state.back_off();
+            } else {
+                state.delay = state.options.poll_interval;
+            }
+
+            if !(first && state.options.skip_existing) {
+                state.pending.extend(new);
+            }
+        }
+    })
+}
+
+/// Creates a stream of the items in `first`, followed by those in each following page fetched by `fetch`.
+pub(crate) fn paginate<T, F, Fut>(
+    first: Listing<T>,
+    limit: Option<usize>,
+    fetch: F,
+) -> impl Stream<Item = Result<T, RouxError>>
+where
+    F: FnMut(ListingSource) -> Fut,
+    Fut: Future<Output = Result<Listing<T>, RouxError>>,
+{
+    let next = first.next_source();
+    let items: VecDeque<T> = first.children.into();
+    let remaining = limit.unwrap_or(usize::MAX);
+
+    stream::unfold(
+        (items, next, remaining, fetch),
+        |(mut items, mut next, remaining, mut fetch)| async move {
+            loop {
+                if remaining == 0 {
+                    return None;
+                }
+
+                if let Some(item) = items.pop_front() {
+                    return Some((Ok(item), (items, next, remaining - 1, fetch)));
+                }
+
+                match fetch(next.take()?).await {
+                    Ok(page) => {
+                        next = page.next_source();
+                        items.extend(page.children);
+                    }
+                    Err(error) => return Some((Err(error), (items, None, remaining, fetch))),
+                }
+            }
+        },
+    )
+}
+
+impl<T: RedditClient + Clone> Subreddit<T> {
+    /// Streams new submissions to this subreddit, oldest first.
+    ///
+    /// The stream never ends; errors are yielded as they occur and polling continues after backing off.
+    pub fn stream_new(
+        &self,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<Submission<T>, RouxError>> {
+        let subreddit = self.clone();
+
+        poll(options, move |limit| {
+            let subreddit = subreddit.clone();
+            async move {
+                let listing = subreddit
+                    .latest(Some(FeedOption::new().limit(limit)))
+                    .await?;
+                Ok(listing.children)
+            }
+        })
+    }
+
+    /// Streams new comments made in this subreddit, oldest first.
+    ///
+    /// The stream never ends; errors are yielded as they occur and polling continues after backing off.
+    pub fn stream_comments(
+        &self,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<LatestComment<T>, RouxError>> {
+        let subreddit = self.clone();
+
+        poll(options, move |limit| {
+            let subreddit = subreddit.clone();
+            async move {
+                let listing = subreddit.latest_comments(None, Some(limit), None).await?;
+                Ok(listing.children)
+            }
+        })
+    }
+
+    /// Streams items newly added to this subreddit's modqueue (reported or filtered submissions and comments),
+    /// oldest first. Requires the logged in user to moderate this subreddit.
+    ///
+    /// Each item is only yielded once, even if it remains in the queue across polls.
+    /// The stream never ends; errors are yielded as they occur and polling continues after backing off.
+    pub fn stream_modqueue(
+        &self,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<QueueThing<T>, RouxError>> {
+        let subreddit = self.clone();
+
+        poll(options, move |limit| {
+            let subreddit = subreddit.clone();
+            async move {
+                let listing = subreddit
+                    .modqueue(Some(FeedOption::new().limit(limit)))
+                    .await?;
+                Ok(listing.children)
+            }
+        })
+    }
+}
+
+impl AuthedClient {
+    /// Streams unread messages in the inbox as they arrive, oldest first.
+    ///
+    /// If `mark_read` is set, each message is marked as read once the next item is requested
+    /// from the stream, i.e. after the consumer has finished processing it.
+    /// The stream never ends; errors are yielded as they occur and polling continues after backing off.
+    pub fn stream_unread(
+        &self,
+        options: StreamOptions,
+        mark_read: bool,
+    ) -> impl Stream<Item = Result<Message<AuthedClient>, RouxError>> {
+        let client = self.clone();
+        let messages = Box::pin(poll(options, move |_| {
+            let client = client.clone();
+            async move { Ok(client.unread().await?.children) }
+        }));
+
+        let client = self.clone();
+        stream::unfold(
+            (messages, None::<ThingFullname>),
+            move |(mut messages, processed)| {
+                let client = client.clone();
+                async move {
+                    if let Some(name) = processed {
+                        if let Err(error) = client.mark_read(&name).await {
+                            return Some((Err(error), (messages, None)));
+                        }
+                    }
+
+                    let message = messages.next().await?;
+                    let processed = match &message {
+                        Ok(message) if mark_read => Some(message.name().clone()),
+                        _ => None,
+                    };
+
+                    Some((message, (messages, processed)))
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+
+    use super::{paginate, poll, StreamItem, StreamOptions};
+    use crate::api::response::OuterBasicListing;
+    use crate::api::ThingFullname;
+    use crate::client::endpoint::EndpointBuilder;
+    use crate::models::Listing;
+    use crate::util::RouxError;
+
+    #[derive(Debug, PartialEq)]
+    struct Item(ThingFullname);
+
+    impl StreamItem for Item {
+        fn fullname(&self) -> &ThingFullname {
+            &self.0
+        }
+    }
+
+    fn item(id: &str) -> Item {
+        Item(ThingFullname::from_submission_id(id))
+    }
+
+    /// Each call returns the next page of items, newest first.
+    fn pages(
+        pages: Vec<Vec<&'static str>>,
+    ) -> impl FnMut(u32) -> std::future::Ready<Result<Vec<Item>, RouxError>> {
+        let pages = Arc::new(Mutex::new(pages.into_iter()));
+        move |_| {
+            let page = pages.lock().unwrap().next().unwrap_or_default();
+            std::future::ready(Ok(page.into_iter().map(item).collect()))
+        }
+    }
+
+    fn options() -> StreamOptions {
+        StreamOptions::new()
+            .poll_interval(Duration::ZERO)
+            .max_poll_interval(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn test_poll_dedupes_and_orders() {
+        let fetch = pages(vec![vec!["b", "a"], vec!["c", "b", "a"], vec!["d", "c"]]);
+
+        let items: Vec<_> = poll(options(), fetch)
+            .take(4)
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(items, vec![item("a"), item("b"), item("c"), item("d")]);
+    }
+
+    #[tokio::test]
+    async fn test_poll_skip_existing() {
+        let fetch = pages(vec![vec!["b", "a"], vec!["c", "b", "a"]]);
+
+        let items: Vec<_> = poll(options().skip_existing(true), fetch)
+            .take(1)
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(items, vec![item("c")]);
+    }
+
+    fn page(ids: &[&str], after: Option<&str>) -> Listing<Item> {
+        let api: OuterBasicListing<String> = serde_json::from_value(serde_json::json!({
+            "kind": "Listing",
+            "data": {
+                "after": after.map(|id| format!("t3_{id}")),
+                "before": null,
+                "children": ids,
+            }
+        }))
+        .unwrap();
+
+        Listing::new_converter(api, |id| item(&id)).with_source(EndpointBuilder::new("new"), None)
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        let pages = Arc::new(Mutex::new(
+            vec![page(&["c", "d"], Some("d")), page(&["e"], None)].into_iter(),
+        ));
+        let fetch = move |_| std::future::ready(Ok(pages.lock().unwrap().next().unwrap()));
+
+        let items: Vec<_> = paginate(page(&["a", "b"], Some("b")), None, fetch)
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+        assert_eq!(
+            items,
+            vec![item("a"), item("b"), item("c"), item("d"), item("e")]
+        );
+
+        let limited: Vec<_> = paginate(page(&["a", "b"], Some("b")), Some(1), |_| async {
+            unreachable!("only the first page is needed")
+        })
+        .collect()
+        .await;
+        assert_eq!(limited.len(), 1);
+    }
+}
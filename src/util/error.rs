@@ -4,9 +4,9 @@ use std::time::Duration;
 
 use serde_json;
 
-use crate::api::response::ApiError;
+use crate::api::response::{ApiError, ApiErrorCode};
 use crate::client;
-use crate::client::ParseJsonError;
+use crate::client::{ParseJsonError, Scope};
 
 /// Error type that occurs when an API request fails for some reason.
 pub enum RouxErrorKind {
@@ -17,16 +17,27 @@ pub enum RouxErrorKind {
     Network(reqwest::Error),
     /// A network error, with the response.
     FullNetwork(client::req::Response, reqwest::Error),
-    /// Occurs if the request triggered a ratelimit
+    /// Occurs if the request was still ratelimited after retrying
+    /// [`Config::max_ratelimit_retries`](crate::Config::max_ratelimit_retries) times.
     Ratelimited {
         /// The duration to retry the request at, according to the response `Retry-After` header, or none
         /// if that header did not exist on the response.
         retry_after: Option<Duration>,
+        /// The duration until the ratelimit window resets, according to the response
+        /// `X-Ratelimit-Reset` header, or none if that header did not exist on the response.
+        reset: Option<Duration>,
     },
     /// One or more errors returned from Reddit's API.
     RedditError(Vec<ApiError>),
     /// Occurs if serde could not Deserialize the response.
     Parse(serde_json::Error),
+    /// Occurs if the response was valid but did not hold what the request should return, e.g. an
+    /// edit which returned no edited item.
+    UnexpectedResponse(String),
+    /// Occurs if a file to upload is not an image or video type that Reddit accepts.
+    UnsupportedMediaType(String),
+    /// Occurs if writing to or reading from an IO resource failed, e.g. when exporting.
+    Io(std::io::Error),
     /// The response could not be deserialized, at the provided location.
     #[cfg(feature = "json-error-path")]
     ParseWithPath(serde_path_to_error::Error<serde_json::Error>),
@@ -36,6 +47,15 @@ pub enum RouxErrorKind {
     CredentialsNotSet,
     /// Occurs if endpoint requires OAuth
     OAuthClientRequired,
+    /// The access token was not granted the scopes needed for the request.
+    InsufficientScope {
+        /// The declared scopes (see [`Config::scopes`](crate::Config::scopes)) that were not granted,
+        /// or the scopes the endpoint needs (see [`endpoints`](crate::endpoints())) if the request
+        /// was stopped before it was sent.
+        ///
+        /// This may be empty if Reddit rejected the request for a scope that was not declared.
+        missing: Vec<Scope>,
+    },
 }
 
 /// An error type with a backtrace, if that feature is enabled.
@@ -62,6 +82,14 @@ impl RouxError {
         Self::new(RouxErrorKind::Auth(s))
     }
 
+    pub(crate) fn insufficient_scope(missing: Vec<Scope>) -> Self {
+        Self::new(RouxErrorKind::InsufficientScope { missing })
+    }
+
+    pub(crate) fn ratelimited(retry_after: Option<Duration>, reset: Option<Duration>) -> Self {
+        Self::new(RouxErrorKind::Ratelimited { retry_after, reset })
+    }
+
     pub(crate) fn status(response: crate::client::req::Response) -> Self {
         Self::new(RouxErrorKind::Status(response))
     }
@@ -85,6 +113,31 @@ impl RouxError {
         Self::new(RouxErrorKind::Parse(error))
     }
 
+    pub(crate) fn unexpected_response(description: &str) -> Self {
+        Self::new(RouxErrorKind::UnexpectedResponse(description.to_owned()))
+    }
+
+    /// How long to wait before retrying, if this error was caused by a ratelimit.
+    ///
+    /// This covers both HTTP ratelimiting ([`RouxErrorKind::Ratelimited`]) and action-specific
+    /// ratelimits reported by Reddit, such as commenting too often.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match &self.kind {
+            RouxErrorKind::Ratelimited { retry_after, reset } => retry_after.or(*reset),
+            RouxErrorKind::RedditError(errors) => errors.iter().find_map(ApiError::retry_after),
+            _ => None,
+        }
+    }
+
+    /// The code of the first error reported by Reddit, if this is a
+    /// [`RouxErrorKind::RedditError`].
+    pub fn api_error_code(&self) -> Option<ApiErrorCode> {
+        match &self.kind {
+            RouxErrorKind::RedditError(errors) => errors.first().map(ApiError::code),
+            _ => None,
+        }
+    }
+
     #[cfg(feature = "json-error-path")]
     pub(crate) fn parse_with_path(error: serde_path_to_error::Error<serde_json::Error>) -> Self {
         Self::new(RouxErrorKind::ParseWithPath(error))
@@ -109,6 +162,12 @@ impl From<serde_json::Error> for RouxError {
     }
 }
 
+impl From<std::io::Error> for RouxError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(RouxErrorKind::Io(e))
+    }
+}
+
 impl From<ParseJsonError> for RouxError {
     fn from(value: ParseJsonError) -> Self {
         match value {
@@ -126,6 +185,13 @@ impl fmt::Display for RouxError {
             RouxErrorKind::Status(err) => write!(f, "Status error: {}", err.status()),
             RouxErrorKind::Network(err) => err.fmt(f),
             RouxErrorKind::Parse(err) => err.fmt(f),
+            RouxErrorKind::UnexpectedResponse(description) => {
+                write!(f, "Unexpected response: {description}")
+            }
+            RouxErrorKind::Io(err) => write!(f, "IO error: {err}"),
+            RouxErrorKind::UnsupportedMediaType(name) => {
+                write!(f, "Unsupported media type for {name:?}")
+            }
             RouxErrorKind::Auth(err) => write!(f, "Auth error: {}", err),
             RouxErrorKind::CredentialsNotSet => write!(
                 f,
@@ -135,8 +201,14 @@ impl fmt::Display for RouxError {
                 write!(f, "Endpoint requires authentication with OAuth")
             }
             RouxErrorKind::FullNetwork(_, err) => err.fmt(f),
-            RouxErrorKind::Ratelimited { retry_after } => {
-                write!(f, "Ratelimited until {retry_after:?}")
+            RouxErrorKind::InsufficientScope { missing } => {
+                write!(f, "Insufficient scope, missing: {missing:?}")
+            }
+            RouxErrorKind::Ratelimited { retry_after, reset } => {
+                write!(
+                    f,
+                    "Ratelimited, retry after {retry_after:?} (resets in {reset:?})"
+                )
             }
             RouxErrorKind::RedditError(errors) => write!(f, "API errors: {errors:?}"),
             #[cfg(feature = "json-error-path")]
@@ -163,8 +235,12 @@ impl error::Error for RouxError {
             RouxErrorKind::Auth(_) => None,
             RouxErrorKind::Network(err) => Some(err),
             RouxErrorKind::Parse(err) => Some(err),
+            RouxErrorKind::UnexpectedResponse(_) => None,
+            RouxErrorKind::Io(err) => Some(err),
+            RouxErrorKind::UnsupportedMediaType(_) => None,
             RouxErrorKind::CredentialsNotSet => None,
             RouxErrorKind::OAuthClientRequired => None,
+            RouxErrorKind::InsufficientScope { .. } => None,
             RouxErrorKind::FullNetwork(_, err) => Some(err),
             RouxErrorKind::Ratelimited { .. } => None,
             RouxErrorKind::RedditError { .. } => None,
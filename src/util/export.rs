@@ -0,0 +1,290 @@
+//! # Export
+//! Writes models to NDJSON or CSV, serializing the underlying API data, so feeds can be archived
+//! without writing serialization code for every model.
+//!
+//! ```no_run
+//! use roux::util::export::Exporter;
+//! # use roux::client::{RedditClient, UnauthedClient};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), roux::util::RouxError> {
+//! # let client = UnauthedClient::new()?;
+//! let hot = client.subreddit("rust").hot(None).await?;
+//!
+//! let file = std::fs::File::create("rust.csv")?;
+//! let mut exporter = Exporter::csv(file).with_fields(["name", "author", "title", "score"]);
+//! exporter.write_listing(&hot)?;
+//! exporter.flush()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use serde_json::{Map, Value};
+
+use crate::models::Listing;
+use crate::util::RouxError;
+
+/// A model whose underlying API data can be exported.
+pub trait Export {
+    /// Serializes the underlying API data into a JSON value.
+    fn export_value(&self) -> Result<Value, serde_json::Error>;
+}
+
+/// The format records are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One JSON object per line.
+    Ndjson,
+    /// Comma separated values, with a header row.
+    Csv,
+}
+
+/// Which fields of each record are written.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Schema {
+    /// Every field of the API data.
+    ///
+    /// For CSV, the columns are the top-level fields of the first record, in alphabetical order.
+    #[default]
+    Full,
+    /// Only the listed fields, in order.
+    ///
+    /// Nested fields are selected with dots, e.g. `preview.enabled`.
+    /// Fields missing from a record are written as `null` (NDJSON) or an empty cell (CSV).
+    Fields(Vec<String>),
+}
+
+/// Writes models to a writer in the chosen format.
+///
+/// The writer is not buffered; wrap files in a [`std::io::BufWriter`] when writing many records.
+pub struct Exporter<W> {
+    writer: W,
+    format: ExportFormat,
+    schema: Schema,
+    columns: Option<Vec<String>>,
+}
+
+impl<W: Write> Exporter<W> {
+    /// Creates an exporter which writes to `writer` in the format.
+    pub fn new(writer: W, format: ExportFormat) -> Self {
+        Self {
+            writer,
+            format,
+            schema: Schema::Full,
+            columns: None,
+        }
+    }
+
+    /// Creates an exporter which writes NDJSON.
+    pub fn ndjson(writer: W) -> Self {
+        Self::new(writer, ExportFormat::Ndjson)
+    }
+
+    /// Creates an exporter which writes CSV.
+    pub fn csv(writer: W) -> Self {
+        Self::new(writer, ExportFormat::Csv)
+    }
+
+    /// Sets which fields are written.
+    pub fn schema(mut self, schema: Schema) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Only writes the listed fields, see [`Schema::Fields`].
+    pub fn with_fields<I, S>(self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.schema(Schema::Fields(fields.into_iter().map(Into::into).collect()))
+    }
+
+    /// Writes a single record.
+    pub fn write<E: Export>(&mut self, item: &E) -> Result<(), RouxError> {
+        let value = item.export_value()?;
+
+        match self.format {
+            ExportFormat::Ndjson => {
+                let record = match &self.schema {
+                    Schema::Full => value,
+                    Schema::Fields(fields) => Value::Object(
+                        fields
+                            .iter()
+                            .map(|field| (field.clone(), select(&value, field).clone()))
+                            .collect(),
+                    ),
+                };
+
+                serde_json::to_writer(&mut self.writer, &record)?;
+                self.writer.write_all(b"\n")?;
+            }
+            ExportFormat::Csv => {
+                if self.columns.is_none() {
+                    let columns = match &self.schema {
+                        Schema::Full => {
+                            let mut keys: Vec<_> = value
+                                .as_object()
+                                .map(Map::keys)
+                                .into_iter()
+                                .flatten()
+                                .cloned()
+                                .collect();
+                            keys.sort();
+                            keys
+                        }
+                        Schema::Fields(fields) => fields.clone(),
+                    };
+
+                    write_csv_row(&mut self.writer, columns.iter().map(String::as_str))?;
+                    self.columns = Some(columns);
+                }
+
+                let columns = self.columns.as_deref().unwrap_or_default();
+                let cells: Vec<_> = columns
+                    .iter()
+                    .map(|column| csv_cell(select(&value, column)))
+                    .collect();
+                write_csv_row(&mut self.writer, cells.iter().map(String::as_str))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes every record, returning how many were written.
+    pub fn write_all<'a, E, I>(&mut self, items: I) -> Result<usize, RouxError>
+    where
+        E: Export + 'a,
+        I: IntoIterator<Item = &'a E>,
+    {
+        let mut count = 0;
+        for item in items {
+            self.write(item)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Writes every record in the listing, returning how many were written.
+    pub fn write_listing<E: Export>(&mut self, listing: &Listing<E>) -> Result<usize, RouxError> {
+        self.write_all(&listing.children)
+    }
+
+    /// Writes records from the stream until it ends, returning how many were written.
+    ///
+    /// Streams from [`crate::streams`] never end on their own, so limit them first
+    /// (e.g. with [`StreamExt::take`](futures_util::StreamExt::take)).
+    /// The first error from the stream or the writer is returned.
+    #[cfg(all(feature = "streams", not(feature = "blocking")))]
+    pub async fn write_stream<E, S>(&mut self, stream: S) -> Result<usize, RouxError>
+    where
+        E: Export,
+        S: futures_util::Stream<Item = Result<E, RouxError>>,
+    {
+        use futures_util::StreamExt;
+
+        futures_util::pin_mut!(stream);
+
+        let mut count = 0;
+        while let Some(item) = stream.next().await {
+            self.write(&item?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<(), RouxError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Consumes the exporter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+fn select<'a>(value: &'a Value, path: &str) -> &'a Value {
+    path.split('.')
+        .try_fold(value, |value, key| value.get(key))
+        .unwrap_or(&Value::Null)
+}
+
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn write_csv_row<'a>(
+    writer: &mut impl Write,
+    cells: impl Iterator<Item = &'a str>,
+) -> std::io::Result<()> {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+
+        if cell.contains([',', '"', '\n', '\r']) {
+            write!(writer, "\"{}\"", cell.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(cell.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::{Export, Exporter};
+
+    struct Record(Value);
+
+    impl Export for Record {
+        fn export_value(&self) -> Result<Value, serde_json::Error> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record(
+                json!({ "name": "t3_a", "title": "Hello, \"world\"", "preview": { "enabled": true } }),
+            ),
+            Record(json!({ "name": "t3_b", "title": "Plain", "score": 3 })),
+        ]
+    }
+
+    #[test]
+    fn test_ndjson_fields() {
+        let mut exporter = Exporter::ndjson(Vec::new()).with_fields(["name", "preview.enabled"]);
+        assert_eq!(exporter.write_all(&records()).unwrap(), 2);
+
+        let output = String::from_utf8(exporter.into_inner()).unwrap();
+        assert_eq!(
+            output,
+            "{\"name\":\"t3_a\",\"preview.enabled\":true}\n{\"name\":\"t3_b\",\"preview.enabled\":null}\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_full_schema() {
+        let mut exporter = Exporter::csv(Vec::new());
+        exporter.write_all(&records()).unwrap();
+
+        let output = String::from_utf8(exporter.into_inner()).unwrap();
+        assert_eq!(
+            output,
+            "name,preview,title\r\n\
+             t3_a,\"{\"\"enabled\"\":true}\",\"Hello, \"\"world\"\"\"\r\n\
+             t3_b,,Plain\r\n"
+        );
+    }
+}
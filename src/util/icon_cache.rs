@@ -0,0 +1,276 @@
+//! # Icon cache
+//! Downloads and caches award and trophy icons, for bots which draw them into images such as
+//! score cards or leaderboards.
+//!
+//! Icons are kept in memory, and optionally in a directory so they survive restarts. Once an icon
+//! is cached, fetching it again only revalidates it with its `ETag`.
+//!
+//! ```no_run
+//! use roux::util::icon_cache::IconCache;
+//! # use roux::client::UnauthedClient;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), roux::util::RouxError> {
+//! # let client = UnauthedClient::new()?;
+//! let cache = IconCache::in_dir("icons");
+//! let icon = cache
+//!     .get(&client, "https://www.redditstatic.com/awards2/gold_70.png")
+//!     .await?;
+//! println!("{} bytes at {:?}", icon.bytes.len(), icon.path);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
+
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{Method, StatusCode};
+
+use crate::client::req::fs;
+use crate::client::{ParseJsonError, RedditClient};
+use crate::util::RouxError;
+
+/// An icon which has been downloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedIcon {
+    /// The URL the icon was downloaded from.
+    pub url: String,
+    /// The contents of the icon.
+    pub bytes: Vec<u8>,
+    /// The icon's `ETag`, used to check whether it has changed.
+    pub etag: Option<String>,
+    /// Where the icon is saved, if the cache has a directory.
+    pub path: Option<PathBuf>,
+}
+
+/// Downloads icons, keeping them so they are only downloaded again if they change.
+pub struct IconCache {
+    dir: Option<PathBuf>,
+    icons: Mutex<HashMap<String, CachedIcon>>,
+}
+
+enum Fetched {
+    NotModified,
+    Modified(Vec<u8>, Option<String>),
+}
+
+impl IconCache {
+    /// Creates a cache which only keeps icons in memory.
+    pub fn in_memory() -> Self {
+        Self {
+            dir: None,
+            icons: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Creates a cache which also saves icons to the directory, creating it if needed.
+    pub fn in_dir(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: Some(dir.into()),
+            icons: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Gets the icon at the URL, downloading it if it is not cached or has changed.
+    ///
+    /// URLs are resolved first, see [`resolve_icon_url`].
+    #[maybe_async::maybe_async]
+    pub async fn get<C: RedditClient>(
+        &self,
+        client: &C,
+        url: &str,
+    ) -> Result<CachedIcon, RouxError> {
+        let url = resolve_icon_url(url);
+        let cached = self.cached(&url).await;
+        let etag = cached.as_ref().and_then(|icon| icon.etag.clone());
+
+        let fetched = client
+            .execute_with_retries(
+                &|| {
+                    let request = client.make_external_req(Method::GET, &url);
+                    match &etag {
+                        Some(etag) => request.header(IF_NONE_MATCH, etag.as_str()),
+                        None => request,
+                    }
+                },
+                &|response| async move {
+                    if response.status() == StatusCode::NOT_MODIFIED {
+                        return Ok(Fetched::NotModified);
+                    }
+                    let etag = response
+                        .headers()
+                        .get(ETAG)
+                        .and_then(|etag| etag.to_str().ok())
+                        .map(str::to_owned);
+                    let bytes = response.bytes().await.map_err(ParseJsonError::Reqwest)?;
+                    Ok(Fetched::Modified(bytes.to_vec(), etag))
+                },
+            )
+            .await?;
+
+        let icon = match (fetched, cached) {
+            (Fetched::NotModified, Some(icon)) => return Ok(icon),
+            (Fetched::NotModified, None) => {
+                return Err(RouxError::unexpected_response(
+                    "icon was not modified, but is not cached",
+                ))
+            }
+            (Fetched::Modified(bytes, etag), _) => CachedIcon {
+                path: self.file_path(&url),
+                url: url.clone(),
+                bytes,
+                etag,
+            },
+        };
+
+        if let Some(path) = &icon.path {
+            if let Some(dir) = &self.dir {
+                fs::create_dir_all(dir).await?;
+            }
+            fs::write(path, &icon.bytes).await?;
+            fs::write(
+                path.with_extension("etag"),
+                icon.etag.as_deref().unwrap_or(""),
+            )
+            .await?;
+        }
+
+        self.icons
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(url, icon.clone());
+        Ok(icon)
+    }
+
+    /// The cached icon, from memory or else from the directory.
+    #[maybe_async::maybe_async]
+    async fn cached(&self, url: &str) -> Option<CachedIcon> {
+        let icon = self
+            .icons
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(url)
+            .cloned();
+        if icon.is_some() {
+            return icon;
+        }
+
+        let path = self.file_path(url)?;
+        let bytes = fs::read(&path).await.ok()?;
+        let etag = fs::read_to_string(path.with_extension("etag"))
+            .await
+            .ok()
+            .filter(|etag| !etag.is_empty());
+        let icon = CachedIcon {
+            url: url.to_owned(),
+            bytes,
+            etag,
+            path: Some(path),
+        };
+        self.icons
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(url.to_owned(), icon.clone());
+        Some(icon)
+    }
+
+    /// Where the icon at the URL is saved, named after a hash of the URL.
+    fn file_path(&self, url: &str) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+
+        let extension = url
+            .split(['?', '#'])
+            .next()
+            .and_then(|path| path.rsplit('/').next())
+            .and_then(|name| name.rsplit_once('.'))
+            .map(|(_, extension)| extension)
+            .filter(|extension| extension.chars().all(|c| c.is_ascii_alphanumeric()))
+            .unwrap_or("img");
+
+        Some(dir.join(format!("{:016x}.{extension}", fnv1a(url))))
+    }
+}
+
+/// Hashes text with 64-bit FNV-1a, which unlike `DefaultHasher` won't change between Rust
+/// releases, so icons saved by older builds are still found.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+impl Default for IconCache {
+    fn default() -> Self {
+        Self::in_memory()
+    }
+}
+
+/// Turns an icon URL as Reddit gives it into one which can be downloaded.
+///
+/// Without [`raw_json`](crate::Config::raw_json), Reddit HTML-escapes icon URLs (e.g. `&amp;`
+/// in award `resized_icons`). Some are also given without a scheme (e.g.
+/// `//www.redditstatic.com/...`).
+pub fn resolve_icon_url(url: &str) -> String {
+    let url = html_escape::decode_html_entities(url.trim());
+    match url.strip_prefix("//") {
+        Some(rest) => format!("https://{rest}"),
+        None => url.into_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fnv1a, resolve_icon_url, IconCache};
+
+    #[test]
+    fn test_resolve_icon_url() {
+        assert_eq!(
+            resolve_icon_url("https://i.redd.it/award.png?width=16&amp;height=16"),
+            "https://i.redd.it/award.png?width=16&height=16"
+        );
+        assert_eq!(
+            resolve_icon_url("//www.redditstatic.com/awards2/gold_70.png"),
+            "https://www.redditstatic.com/awards2/gold_70.png"
+        );
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a("foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_file_path() {
+        let cache = IconCache::in_dir("icons");
+        let path = cache
+            .file_path("https://i.redd.it/award.png?width=16")
+            .unwrap();
+        assert_eq!(path.extension().unwrap(), "png");
+        assert_eq!(
+            path,
+            cache
+                .file_path("https://i.redd.it/award.png?width=16")
+                .unwrap()
+        );
+        assert_ne!(
+            path,
+            cache.file_path("https://i.redd.it/other.png").unwrap()
+        );
+
+        assert_eq!(
+            IconCache::in_memory().file_path("https://i.redd.it/a.png"),
+            None
+        );
+        assert_eq!(
+            cache
+                .file_path("https://i.redd.it/award")
+                .unwrap()
+                .extension()
+                .unwrap(),
+            "img"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! # Markdown
+//! Unless `raw_json=1` is sent, Reddit HTML-escapes `&`, `<` and `>` in the markdown of comments,
+//! posts and messages, so a comment containing `a & b` is returned as `a &amp; b`.
+//!
+//! roux sends `raw_json=1` by default, so text is returned as written. Clients which disable it
+//! with [`Config::raw_json`](crate::Config::raw_json) can use the `_unescaped` accessors of
+//! comments, submissions and messages, or [`unescape`](crate::util::markdown::unescape) for
+//! other text.
+//!
+//! [`lint`](crate::util::markdown::lint) finds common mistakes which make markdown render
+//! differently than intended.
+
+use std::borrow::Cow;
+
+/// Reverses Reddit's escaping of markdown, turning `&amp;`, `&lt;` and `&gt;` back into
+/// `&`, `<` and `>`.
+///
+/// Only these entities are decoded, so markdown which itself contains an entity such as `&nbsp;`
+/// (returned as `&amp;nbsp;`) is unescaped to exactly what was written.
+pub fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let (decoded, length) = if rest.starts_with("&amp;") {
+            ('&', 5)
+        } else if rest.starts_with("&lt;") {
+            ('<', 4)
+        } else if rest.starts_with("&gt;") {
+            ('>', 4)
+        } else {
+            ('&', 1)
+        };
+        unescaped.push(decoded);
+        rest = &rest[length..];
+    }
+    unescaped.push_str(rest);
+
+    Cow::Owned(unescaped)
+}
+
+/// Escapes markdown the way Reddit does, the reverse of [`unescape`].
+pub fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// A mistake in markdown found by [`lint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkdownIssue {
+    /// A code block is opened with ```` ``` ```` but never closed, so the rest of the text is
+    /// shown as code.
+    UnclosedCodeFence {
+        /// The line the code block starts on, from 1.
+        line: usize,
+    },
+    /// A link's URL is missing its closing `)`.
+    UnclosedLink {
+        /// The line of the link, from 1.
+        line: usize,
+    },
+    /// A link is written as `[text] (url)`, which Reddit does not render as a link.
+    SpacedLink {
+        /// The line of the link, from 1.
+        line: usize,
+    },
+}
+
+impl std::fmt::Display for MarkdownIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MarkdownIssue::UnclosedCodeFence { line } => {
+                write!(f, "the code block on line {line} is never closed")
+            }
+            MarkdownIssue::UnclosedLink { line } => {
+                write!(f, "the link on line {line} is missing its closing `)`")
+            }
+            MarkdownIssue::SpacedLink { line } => write!(
+                f,
+                "the link on line {line} has a space between `]` and `(`, so is not rendered"
+            ),
+        }
+    }
+}
+
+/// Finds common mistakes in markdown, such as unclosed code blocks and broken links.
+///
+/// Text inside code blocks is not checked.
+pub fn lint(text: &str) -> Vec<MarkdownIssue> {
+    let mut issues = Vec::new();
+    let mut open_fence = None;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim_start().starts_with("```") {
+            open_fence = match open_fence {
+                Some(_) => None,
+                None => Some(line_number),
+            };
+            continue;
+        }
+        if open_fence.is_some() {
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("](") {
+            rest = &rest[start + 2..];
+            match rest.find(')') {
+                Some(end) => rest = &rest[end + 1..],
+                None => {
+                    issues.push(MarkdownIssue::UnclosedLink { line: line_number });
+                    break;
+                }
+            }
+        }
+
+        if line.contains("] (") {
+            issues.push(MarkdownIssue::SpacedLink { line: line_number });
+        }
+    }
+
+    if let Some(line) = open_fence {
+        issues.push(MarkdownIssue::UnclosedCodeFence { line });
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, lint, unescape, MarkdownIssue};
+
+    #[test]
+    fn test_lint() {
+        assert_eq!(lint("[roux](https://docs.rs/roux) is **fine**"), []);
+        assert_eq!(lint("```\n[a](b\n```\nafter"), []);
+        assert_eq!(
+            lint("see [the docs](https://docs.rs/roux\nand [this] (https://example.com)\n```\n[a](b\n"),
+            [
+                MarkdownIssue::UnclosedLink { line: 1 },
+                MarkdownIssue::SpacedLink { line: 2 },
+                MarkdownIssue::UnclosedCodeFence { line: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape("a &amp; b &lt;3 &gt; c"), "a & b <3 > c");
+        assert_eq!(
+            unescape("&amp;nbsp; &quot; & done&"),
+            "&nbsp; &quot; & done&"
+        );
+        assert_eq!(unescape("plain"), "plain");
+    }
+
+    #[test]
+    fn test_escape_round_trip() {
+        for text in ["a & b", "<3 &amp; >", "&nbsp;", "plain"] {
+            assert_eq!(unescape(&escape(text)), text);
+        }
+        assert_eq!(escape("a & <b>"), "a &amp; &lt;b&gt;");
+    }
+}
@@ -1,11 +1,16 @@
 /// Error responses.
 pub mod error;
+/// Writing listings and streams to NDJSON or CSV.
+pub mod export;
+/// Caching award and trophy icons.
+pub mod icon_cache;
+/// Escaping and unescaping markdown as Reddit does without `raw_json`, and checking it for mistakes.
+pub mod markdown;
 pub mod option;
 
 pub(crate) mod defaults;
 pub(crate) mod ser_enumstr;
 pub(crate) mod ser_map;
-pub(crate) mod serde;
 /// Url building.
 pub(crate) mod url;
 
@@ -15,8 +20,22 @@ pub use error::RouxError;
 /// Options
 pub use option::FeedOption;
 pub use option::TimePeriod;
+pub use option::{SearchOptions, SearchSort};
 pub use submission_stream::*;
 
+/// Converts a UTC timestamp in seconds, as Reddit gives them, to a [`SystemTime`](std::time::SystemTime).
+pub(crate) fn utc_timestamp(seconds: f64) -> Option<std::time::SystemTime> {
+    let since_epoch = std::time::Duration::try_from_secs_f64(seconds).ok()?;
+    std::time::UNIX_EPOCH.checked_add(since_epoch)
+}
+
+/// The current time as a UTC timestamp in seconds, as Reddit gives them.
+pub(crate) fn utc_now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |since_epoch| since_epoch.as_secs_f64())
+}
+
 macro_rules! maybe_async_handler {
     ($vis:vis fn $fn_name:ident (&$self:ident, $builder:ident, $handler:ident) $err:ty $body:block) => {
         #[cfg(feature = "blocking")]
@@ -18,7 +18,7 @@ pub struct FeedOption {
     pub limit: Option<u32>,
     /// The number of items already seen in this listing.
     pub count: Option<u32>,
-    /// What time period to request (only works on some requests, like top)
+    /// What time period to request (only works on some requests, like top and controversial)
     pub period: Option<TimePeriod>,
 }
 
@@ -100,7 +100,7 @@ impl Default for FeedOption {
     }
 }
 
-/// Allows you to request a certain time period. This only works in certain situations, like when asking for top of a subreddit
+/// Allows you to request a certain time period. This only works in certain situations, like when asking for top or controversial posts of a subreddit
 #[derive(Copy, Clone, Debug)]
 pub enum TimePeriod {
     /// Posts from very recently
@@ -131,11 +131,81 @@ impl TimePeriod {
     }
 }
 
+/// How search results are sorted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchSort {
+    /// The best matches first, the default
+    Relevance,
+    /// Popular submissions first
+    Hot,
+    /// The highest scoring submissions first
+    Top,
+    /// The newest submissions first
+    New,
+    /// The submissions with the most comments first
+    Comments,
+}
+
+impl SearchSort {
+    /// Gets the request string for the sort
+    pub fn as_str(&self) -> &str {
+        match self {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Hot => "hot",
+            SearchSort::Top => "top",
+            SearchSort::New => "new",
+            SearchSort::Comments => "comments",
+        }
+    }
+}
+
+/// Options for searching submissions
+#[derive(Clone, Debug, Default)]
+pub struct SearchOptions {
+    /// How the results are sorted.
+    pub sort: Option<SearchSort>,
+    /// Paging, and the time period the results are from.
+    pub feed: FeedOption,
+}
+
+impl SearchOptions {
+    /// Create a new `SearchOptions` instance.
+    pub fn new() -> SearchOptions {
+        SearchOptions::default()
+    }
+
+    /// Set how the results are sorted.
+    pub fn sort(mut self, sort: SearchSort) -> SearchOptions {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set the paging and time period options.
+    pub fn feed(mut self, feed: FeedOption) -> SearchOptions {
+        self.feed = feed;
+        self
+    }
+
+    /// Build the search endpoint for the query, returning it along with the paging options.
+    pub(crate) fn build_search(
+        self,
+        endpoint: EndpointBuilder,
+        query: &str,
+    ) -> (EndpointBuilder, FeedOption) {
+        let mut endpoint = endpoint.query("q", query).query("type", "link");
+        if let Some(sort) = self.sort {
+            endpoint.with_query("sort", sort.as_str());
+        }
+
+        (endpoint, self.feed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::client::endpoint::EndpointBuilder;
 
-    use super::FeedOption;
+    use super::{FeedOption, SearchOptions, SearchSort, TimePeriod};
 
     #[test]
     fn test_build_url_after() {
@@ -161,6 +231,19 @@ mod tests {
         assert_eq!(url.build(""), format!("/.json?before={}&", before))
     }
 
+    #[test]
+    fn test_build_url_hostile_after() {
+        let options = FeedOption::new().after("t3_a&before=t3_b+c").limit(5);
+
+        let mut url = EndpointBuilder::new("");
+        options.build_url(&mut url);
+
+        assert_eq!(
+            url.build(""),
+            "/.json?after=t3_a%26before%3Dt3_b%2Bc&limit=5&"
+        )
+    }
+
     #[test]
     fn test_build_url_count() {
         let count = 100u32;
@@ -171,4 +254,19 @@ mod tests {
 
         assert_eq!(url.build(""), format!("/.json?count={}&", count))
     }
+
+    #[test]
+    fn test_build_search() {
+        let options = SearchOptions::new()
+            .sort(SearchSort::Top)
+            .feed(FeedOption::new().period(TimePeriod::ThisWeek));
+
+        let (mut url, feed) = options.build_search(EndpointBuilder::new("search"), "rust 2024");
+        feed.build_url(&mut url);
+
+        assert_eq!(
+            url.build(""),
+            "/search/.json?q=rust+2024&type=link&sort=top&t=week&"
+        )
+    }
 }
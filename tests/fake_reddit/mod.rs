@@ -0,0 +1,358 @@
+//! A fake Reddit server, so the client can be tested without real credentials.
+//!
+//! It implements just enough of the API for the tests in `tests.rs`, with some canned
+//! misbehaviour:
+//! - `/r/ratelimited/...` responds `429 Too Many Requests` a number of times before succeeding.
+//! - `/r/malformed/...` responds with a listing that cannot be parsed.
+//! - `/r/stickied/...` lists a stickied post first.
+//! - [`FakeReddit::expire_token`] makes the current access token be rejected with `401`.
+//! - `/static/icon.png` is an image with an `ETag`, which responds `304 Not Modified` when
+//!   revalidated.
+//! - `/message/unread` has a username mention and a private message.
+//! - `POST /api/comment` returns the new comment.
+//! - `POST /api/editusertext` returns the edited comment or submission, except for `t1_missing`,
+//!   which succeeds without returning the edited item. Editing the text to `wrong kind` returns
+//!   an item of the other kind.
+//! - `POST /api/submit_gallery_post` returns the URL of a new post.
+//! - `/api/info` returns one submission per request, whatever is looked up.
+//! - Any other `POST /api/...` succeeds with no errors, so form bodies can be checked with
+//!   [`FakeReddit::body`].
+//!
+//! Like Reddit, text echoed back is HTML-escaped unless the request has `raw_json=1`.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[derive(Default)]
+struct State {
+    logins: usize,
+    token: Option<String>,
+    ratelimited: usize,
+    requests: Vec<String>,
+    bodies: Vec<(String, String)>,
+}
+
+/// A running fake Reddit server. It stops when the test process exits.
+pub struct FakeReddit {
+    url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl FakeReddit {
+    /// Starts a server on a free local port.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let server_state = state.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let state = server_state.clone();
+                thread::spawn(move || handle(stream, &state));
+            }
+        });
+
+        Self { url, state }
+    }
+
+    /// The URL to pass to [`Config::base_url`](roux::Config::base_url).
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The number of times a client has logged in.
+    pub fn logins(&self) -> usize {
+        self.state.lock().unwrap().logins
+    }
+
+    /// The requests received, as `METHOD /path`, without the query.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// The body of the last request received as `METHOD /path`, if there was one.
+    pub fn body(&self, request: &str) -> Option<String> {
+        let state = self.state.lock().unwrap();
+        state
+            .bodies
+            .iter()
+            .rev()
+            .find(|(received, _)| received == request)
+            .map(|(_, body)| body.clone())
+    }
+
+    /// Rejects the current access token, as if it had expired.
+    pub fn expire_token(&self) {
+        self.state.lock().unwrap().token = None;
+    }
+
+    /// Responds `429 Too Many Requests` to the next `times` requests to `/r/ratelimited`.
+    pub fn ratelimit(&self, times: usize) {
+        self.state.lock().unwrap().ratelimited = times;
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_owned();
+    let target = parts.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_owned());
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path: path.to_owned(),
+        query: query.to_owned(),
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn handle(mut stream: TcpStream, state: &Mutex<State>) {
+    let Some(request) = read_request(&stream) else {
+        return;
+    };
+    let (status, headers, body) = respond(&request, state);
+
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        body.len()
+    );
+    for (name, value) in headers {
+        response.push_str(&format!("{name}: {value}\r\n"));
+    }
+    response.push_str("\r\n");
+    response.push_str(&body);
+
+    let _ = stream.write_all(response.as_bytes());
+}
+
+type Response = (&'static str, Vec<(&'static str, &'static str)>, String);
+
+fn respond(request: &Request, state: &Mutex<State>) -> Response {
+    let mut state = state.lock().unwrap();
+    let received = format!("{} {}", request.method, request.path);
+    state.requests.push(received.clone());
+    state.bodies.push((received, request.body.clone()));
+
+    let segments: Vec<&str> = request
+        .path
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".json")
+        .collect();
+
+    if request.method == "POST" && segments == ["api", "v1", "access_token"] {
+        state.logins += 1;
+        let token = format!("token-{}", state.logins);
+        state.token = Some(token.clone());
+        return ok(format!(
+            r#"{{"access_token": "{token}", "token_type": "bearer", "expires_in": 86400, "scope": "*"}}"#
+        ));
+    }
+
+    if request.method == "GET" && segments == ["static", "icon.png"] {
+        return match request.headers.get("if-none-match") {
+            Some(etag) if etag == ICON_ETAG => ("304 Not Modified", Vec::new(), String::new()),
+            _ => (
+                "200 OK",
+                vec![("ETag", ICON_ETAG)],
+                "not really a png".to_owned(),
+            ),
+        };
+    }
+
+    let authorized = match (&state.token, request.headers.get("authorization")) {
+        (Some(token), Some(header)) => header == &format!("Bearer {token}"),
+        _ => false,
+    };
+    if !authorized {
+        return (
+            "401 Unauthorized",
+            Vec::new(),
+            r#"{"message": "Unauthorized", "error": 401}"#.to_owned(),
+        );
+    }
+
+    match segments.as_slice() {
+        ["api", "v1", "me"] => ok(ME.to_owned()),
+        ["api", "submit_gallery_post"] => ok(r#"{"json": {"errors": [], "data": {
+            "url": "https://www.reddit.com/r/rust/comments/post0/cats/"
+        }}}"#
+            .to_owned()),
+        ["api", "comment"] => {
+            let form = form(request);
+            let mut data: serde_json::Value = serde_json::from_str(COMMENT).unwrap();
+            data["id"] = "reply1".into();
+            data["name"] = "t1_reply1".into();
+            data["parent_id"] = form["parent"].as_str().into();
+            data["body"] = echo(request, &form["text"]).into();
+            data["rte_mode"] = "markdown".into();
+            ok(things(&[format!(r#"{{"kind": "t1", "data": {data}}}"#)]))
+        }
+        ["api", "editusertext"] => {
+            let form = form(request);
+            let text = echo(request, &form["text"]);
+            let edited = match form["thing_id"].split_once('_') {
+                Some(("t1", "missing")) | None => None,
+                Some((kind, id)) if (kind == "t1") != (form["text"] == "wrong kind") => {
+                    let mut data: serde_json::Value = serde_json::from_str(COMMENT).unwrap();
+                    data["id"] = id.into();
+                    data["name"] = format!("t1_{id}").into();
+                    data["body"] = text.into();
+                    data["edited"] = 1700000000.0.into();
+                    data["rte_mode"] = "markdown".into();
+                    Some(format!(r#"{{"kind": "t1", "data": {data}}}"#))
+                }
+                Some((_, id)) => {
+                    let mut data: serde_json::Value = serde_json::from_str(SUBMISSION).unwrap();
+                    data["id"] = id.into();
+                    data["name"] = format!("t3_{id}").into();
+                    data["selftext"] = text.into();
+                    data["edited"] = 1700000000.0.into();
+                    Some(format!(r#"{{"kind": "t3", "data": {data}}}"#))
+                }
+            };
+            ok(things(edited.as_slice()))
+        }
+        ["api", ..] if request.method == "POST" => ok(r#"{"json": {"errors": []}}"#.to_owned()),
+        ["r", "ratelimited", _] if state.ratelimited > 0 => {
+            state.ratelimited -= 1;
+            (
+                "429 Too Many Requests",
+                vec![("Retry-After", "0")],
+                r#"{"message": "Too Many Requests", "error": 429}"#.to_owned(),
+            )
+        }
+        ["api", "info"] => ok(listing("rust", 1)),
+        ["message", "unread"] => ok(UNREAD.to_owned()),
+        ["api", "multi", "mine"] => ok(MULTIS.to_owned()),
+        ["user", _, "m", multi, _] => ok(listing(multi, 2)),
+        ["r", "malformed", _] => ok(r#"{"kind": "Listing", "data": {"children": 5}}"#.to_owned()),
+        ["r", subreddit, _] => ok(listing(subreddit, 3)),
+        _ => (
+            "404 Not Found",
+            Vec::new(),
+            r#"{"message": "Not Found", "error": 404}"#.to_owned(),
+        ),
+    }
+}
+
+fn ok(body: String) -> Response {
+    ("200 OK", Vec::new(), body)
+}
+
+fn form(request: &Request) -> HashMap<String, String> {
+    serde_urlencoded::from_str(&request.body).unwrap()
+}
+
+/// Echoes `text` back the way Reddit does, escaped unless `raw_json=1` was sent.
+fn echo(request: &Request, text: &str) -> String {
+    if request.query.split('&').any(|pair| pair == "raw_json=1") {
+        return text.to_owned();
+    }
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn things(things: &[String]) -> String {
+    format!(
+        r#"{{"json": {{"errors": [], "data": {{"things": [{}]}}}}}}"#,
+        things.join(",")
+    )
+}
+
+fn listing(subreddit: &str, count: usize) -> String {
+    let children: Vec<String> = (0..count)
+        .map(|i| {
+            let mut data: serde_json::Value = serde_json::from_str(SUBMISSION).unwrap();
+            data["subreddit"] = subreddit.into();
+            data["id"] = format!("post{i}").into();
+            data["name"] = format!("t3_post{i}").into();
+            data["title"] = format!("Post {i}").into();
+            data["permalink"] = format!("/r/{subreddit}/comments/post{i}/title/").into();
+            data["stickied"] = (subreddit == "stickied" && i == 0).into();
+            format!(r#"{{"kind": "t3", "data": {data}}}"#)
+        })
+        .collect();
+
+    format!(
+        r#"{{"kind": "Listing", "data": {{"after": null, "before": null, "dist": {count}, "children": [{}]}}}}"#,
+        children.join(",")
+    )
+}
+
+const SUBMISSION: &str = include_str!("../fixtures/submission.json");
+
+const COMMENT: &str = include_str!("../fixtures/comment.json");
+
+const UNREAD: &str = r#"{"kind": "Listing", "data": {"after": null, "before": null, "children": [
+    {"kind": "t1", "data": {
+        "id": "mention1", "name": "t1_mention1", "subject": "username mention",
+        "was_comment": true, "author": "someone", "parent_id": "t3_post0",
+        "subreddit_name_prefixed": "r/rust", "new": true, "type": "username_mention",
+        "body": "hey /u/roux_tester", "dest": "roux_tester", "body_html": "", "created": 0,
+        "created_utc": 0, "context": "/r/rust/comments/post0/title/mention1/?context=3",
+        "first_message_name": null
+    }},
+    {"kind": "t4", "data": {
+        "id": "pm1", "name": "t4_pm1", "subject": "hello", "was_comment": false,
+        "author": "someone", "parent_id": null, "subreddit_name_prefixed": null, "new": true,
+        "type": "unknown", "body": "hi", "dest": "roux_tester", "body_html": "", "created": 0,
+        "created_utc": 0, "context": "", "first_message_name": null
+    }}
+]}}"#;
+
+const MULTIS: &str = r#"[{"kind": "LabeledMulti", "data": {
+    "name": "langs", "display_name": "Languages", "path": "/user/roux_tester/m/langs/",
+    "owner": "roux_tester", "subreddits": [{"name": "rust"}, {"name": "golang"}],
+    "description_md": "", "visibility": "private", "over_18": false, "num_subscribers": 0,
+    "icon_url": null, "copied_from": null, "created_utc": 1700000000.0
+}}]"#;
+
+const ICON_ETAG: &str = "\"icon-v1\"";
+
+const ME: &str = r#"{
+    "id": "abc", "name": "roux_tester", "icon_img": "",
+    "is_employee": false, "verified": true, "over_18": false, "has_verified_email": true,
+    "is_suspended": false, "has_mail": false, "has_mod_mail": false, "in_beta": false,
+    "is_mod": false, "is_gold": false,
+    "inbox_count": 0, "created": 0, "created_utc": 0,
+    "comment_karma": 1, "link_karma": 2, "total_karma": 3,
+    "features": {}
+}"#;
@@ -4,6 +4,9 @@ extern crate roux;
 #[cfg(all(not(feature = "blocking"), test))]
 extern crate tokio;
 
+#[cfg(not(feature = "blocking"))]
+mod fake_reddit;
+
 #[cfg(test)]
 mod tests {
     use std::env;
@@ -117,3 +120,491 @@ mod tests {
         assert!(new_client.moderators().is_ok());
     }
 }
+
+/// Tests against [`fake_reddit`], which need no credentials.
+#[cfg(not(feature = "blocking"))]
+mod fake {
+    use roux::api::ThingFullname;
+    use roux::builders::submission::{GalleryItem, SubmissionSubmitBuilder};
+    use roux::client::{AuthedClient, OAuthClient, RedditClient, RemoveReason};
+    use roux::models::SubmissionStickySlot;
+    use roux::util::error::RouxErrorKind;
+    use roux::util::icon_cache::IconCache;
+    use roux::util::{SearchOptions, SearchSort};
+    use roux::Config;
+
+    use crate::fake_reddit::FakeReddit;
+
+    async fn login(server: &FakeReddit) -> AuthedClient {
+        let config = Config::new("test:roux:v0 (by /u/roux_tester)", "id", "secret")
+            .username("roux_tester")
+            .password("hunter2")
+            .base_url(server.url());
+
+        OAuthClient::new(config).unwrap().login().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_login_and_me() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let me = client.me().await.unwrap();
+        assert_eq!(me.name, "roux_tester");
+        assert_eq!(server.logins(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_login_without_credentials() {
+        let server = FakeReddit::start();
+        let config = Config::new("test:roux:v0 (by /u/roux_tester)", "id", "secret")
+            .username("roux_tester")
+            .password("")
+            .base_url(server.url());
+
+        let client = OAuthClient::new(config).unwrap();
+        assert!(!client.is_authenticatable());
+
+        let Err(error) = client.login().await else {
+            panic!("logged in without a password");
+        };
+        assert!(matches!(error.kind, RouxErrorKind::CredentialsNotSet));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_post_bodies_request_json() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let post = ThingFullname::from_submission_id("abc123");
+
+        client
+            .sticky(&post, true, SubmissionStickySlot::Top)
+            .await
+            .unwrap();
+        assert_eq!(
+            server.body("POST /api/set_subreddit_sticky/.json").unwrap(),
+            "api_type=json&id=t3_abc123&state=true&num=1"
+        );
+
+        client
+            .add_removal_reason(&post, RemoveReason::ModNote("spam"))
+            .await
+            .unwrap();
+        assert_eq!(
+            server
+                .body("POST /api/v1/modactions/removal_reasons/.json")
+                .unwrap(),
+            "api_type=json&json=%7B%22item_ids%22%3A%5B%22t3_abc123%22%5D%2C%22mod_note%22%3A%22spam%22%7D"
+        );
+    }
+
+    #[cfg(feature = "bots")]
+    #[tokio::test]
+    async fn test_mention_bot() {
+        use futures_util::StreamExt;
+        use roux::bots::MentionBot;
+
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let stream = MentionBot::new(client, |mention| {
+            assert!(mention.is_mention());
+            None
+        })
+        .stream();
+        futures_util::pin_mut!(stream);
+
+        let handled = stream.next().await.unwrap().unwrap();
+        assert_eq!(handled.mention.name().full(), "t1_mention1");
+        assert!(handled.reply.is_none());
+        assert_eq!(
+            server.body("POST /api/read_message/.json").unwrap(),
+            "api_type=json&id=t1_mention1"
+        );
+    }
+
+    #[cfg(feature = "bots")]
+    #[tokio::test]
+    async fn test_moderation_bot() {
+        use futures_util::StreamExt;
+        use roux::bots::{ModerationAction, ModerationBot, ModerationSource};
+        use roux::models::modqueue::QueueThing;
+
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let rule = |item: &QueueThing<_>, source| {
+            assert_eq!(source, ModerationSource::Submissions);
+            vec![
+                ModerationAction::Report("check this".to_owned()),
+                ModerationAction::Remove {
+                    spam: item.name().full() == "t3_post2",
+                },
+            ]
+        };
+        let stream = ModerationBot::new(client.subreddit("rust"))
+            .comments(false)
+            .modqueue(false)
+            .rule(rule)
+            .stream();
+        futures_util::pin_mut!(stream);
+
+        let moderated = stream.next().await.unwrap().unwrap();
+        assert_eq!(moderated.item.name().full(), "t3_post2");
+        assert_eq!(moderated.actions.len(), 2);
+        assert!(moderated
+            .actions
+            .iter()
+            .all(|applied| matches!(applied.result, Some(Ok(())))));
+        assert_eq!(
+            server.body("POST /api/report/.json").unwrap(),
+            "api_type=json&id=t3_post2&reason=check+this"
+        );
+        assert_eq!(
+            server.body("POST /api/remove/.json").unwrap(),
+            "api_type=json&spam=true&id=t3_post2"
+        );
+
+        let stream = ModerationBot::new(client.subreddit("dryrun"))
+            .comments(false)
+            .modqueue(false)
+            .rule(rule)
+            .dry_run(true)
+            .stream();
+        futures_util::pin_mut!(stream);
+
+        let moderated = stream.next().await.unwrap().unwrap();
+        assert!(moderated
+            .actions
+            .iter()
+            .all(|applied| applied.result.is_none()));
+        assert_eq!(
+            server
+                .requests()
+                .iter()
+                .filter(|request| request.starts_with("POST /api/remove"))
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subreddit_listing() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let hot = client.subreddit("rust").hot(None).await.unwrap();
+        assert_eq!(hot.children.len(), 3);
+        assert_eq!(hot.children[0].subreddit(), "rust");
+    }
+
+    #[tokio::test]
+    async fn test_subreddit_sticky() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let subreddit = client.subreddit("stickied");
+
+        let top = subreddit.sticky(SubmissionStickySlot::Top).await.unwrap();
+        assert_eq!(top.unwrap().id(), "post0");
+        let bottom = subreddit
+            .sticky(SubmissionStickySlot::Bottom)
+            .await
+            .unwrap();
+        assert!(bottom.is_none());
+        let fullname = subreddit
+            .sticky_fullname(SubmissionStickySlot::Top)
+            .await
+            .unwrap();
+        assert_eq!(fullname.unwrap().full(), "t3_post0");
+
+        let none = client
+            .subreddit("rust")
+            .sticky(SubmissionStickySlot::Top)
+            .await
+            .unwrap();
+        assert!(none.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subreddit_gilded() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let gilded = client.subreddit("rust").gilded(None).await.unwrap();
+        assert_eq!(gilded.children.len(), 3);
+        assert_eq!(gilded.children[0].name().full(), "t3_post0");
+    }
+
+    #[tokio::test]
+    async fn test_my_multireddits() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let multis = client.my_multireddits().await.unwrap();
+        assert_eq!(multis.len(), 1);
+        assert_eq!(
+            multis[0].subreddit_names().collect::<Vec<_>>(),
+            ["rust", "golang"]
+        );
+
+        let hot = multis[0].hot(None).await.unwrap();
+        assert_eq!(hot.children.len(), 2);
+        assert!(server
+            .requests()
+            .contains(&"GET /user/roux_tester/m/langs/hot/.json".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn test_subreddit_search() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let options = SearchOptions::new().sort(SearchSort::New);
+        let results = client
+            .subreddit("rust")
+            .search("async", Some(options))
+            .await
+            .unwrap();
+        assert_eq!(results.children.len(), 3);
+        assert!(server
+            .requests()
+            .contains(&"GET /r/rust/search/.json".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn test_ratelimit_retry() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        server.ratelimit(2);
+        let hot = client.subreddit("ratelimited").hot(None).await.unwrap();
+        assert_eq!(hot.children.len(), 3);
+
+        let attempts = server
+            .requests()
+            .iter()
+            .filter(|request| request.starts_with("GET /r/ratelimited/hot"))
+            .count();
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_relogin_after_token_expiry() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        server.expire_token();
+        assert!(client.me().await.is_ok());
+        assert_eq!(server.logins(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_icon_cache_revalidates() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let cache = IconCache::in_memory();
+        let url = format!("{}/static/icon.png", server.url());
+
+        let first = cache.get(&client, &url).await.unwrap();
+        let second = cache.get(&client, &url).await.unwrap();
+        assert_eq!(first.bytes, b"not really a png");
+        assert_eq!(first, second);
+
+        let fetches = server
+            .requests()
+            .iter()
+            .filter(|request| request.as_str() == "GET /static/icon.png")
+            .count();
+        assert_eq!(fetches, 2);
+    }
+
+    #[tokio::test]
+    async fn test_icon_cache_dir() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let dir = std::env::temp_dir().join(format!("roux-icons-{}", std::process::id()));
+        let url = format!("{}/static/icon.png", server.url());
+
+        let saved = IconCache::in_dir(&dir).get(&client, &url).await.unwrap();
+        let path = saved.path.clone().unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"not really a png");
+
+        let loaded = IconCache::in_dir(&dir).get(&client, &url).await.unwrap();
+        assert_eq!(loaded, saved);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_listing() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+
+        let Err(error) = client.subreddit("malformed").hot(None).await else {
+            panic!("the malformed listing was parsed");
+        };
+        let is_parse_error = match &error.kind {
+            RouxErrorKind::Parse(_) => true,
+            RouxErrorKind::Network(error) => error.is_decode(),
+            #[cfg(feature = "json-error-path")]
+            RouxErrorKind::ParseWithPath(_) => true,
+            _ => false,
+        };
+        assert!(is_parse_error, "{error:?}");
+    }
+
+    #[tokio::test]
+    async fn test_submit_gallery() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let items = vec![
+            GalleryItem::new("abc").with_caption("A cat").unwrap(),
+            GalleryItem::new("def"),
+        ];
+
+        let submitted = client
+            .subreddit("rust")
+            .submit_gallery(&SubmissionSubmitBuilder::gallery("Cats", items))
+            .await
+            .unwrap();
+        assert_eq!(
+            submitted.url,
+            "https://www.reddit.com/r/rust/comments/post0/cats/"
+        );
+
+        let body = server.body("POST /api/submit_gallery_post/.json").unwrap();
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["sr"], "rust");
+        assert_eq!(body["items"][0]["caption"], "A cat");
+        assert_eq!(body["items"][1]["media_id"], "def");
+    }
+
+    #[tokio::test]
+    async fn test_get_things_in_chunks() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let fullnames: Vec<ThingFullname> = (0..150)
+            .map(|i| ThingFullname::from_submission_id(&format!("post{i}")))
+            .collect();
+        let fullnames: Vec<&ThingFullname> = fullnames.iter().collect();
+
+        let things = client.get_things(&fullnames[..100]).await.unwrap();
+        assert_eq!(things.len(), 1);
+        let things = client.get_things(&fullnames).await.unwrap();
+        assert_eq!(things.len(), 2);
+
+        let lookups = server
+            .requests()
+            .iter()
+            .filter(|request| request.as_str() == "GET /api/info/.json")
+            .count();
+        assert_eq!(lookups, 3);
+    }
+
+    #[tokio::test]
+    async fn test_edit_without_item() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let comment = ThingFullname::from_comment_id("missing");
+
+        let Err(error) = client.edit("new text", &comment).await else {
+            panic!("edited without an edited item");
+        };
+        assert!(
+            matches!(error.kind, RouxErrorKind::UnexpectedResponse(_)),
+            "{error:?}"
+        );
+        assert_eq!(
+            server.body("POST /api/editusertext/.json").unwrap(),
+            "api_type=json&text=new+text&thing_id=t1_missing"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_keeps_raw_text() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let post = ThingFullname::from_submission_id("abc123");
+
+        let mut comment = client.comment("1 < 2", &post).await.unwrap();
+        assert_eq!(comment.body(), "1 < 2");
+
+        comment.edit("a & <b>").await.unwrap();
+        assert_eq!(comment.body(), "a & <b>");
+        assert_eq!(
+            server.body("POST /api/editusertext/.json").unwrap(),
+            "api_type=json&text=a+%26+%3Cb%3E&thing_id=t1_reply1"
+        );
+
+        let mut submission = client
+            .subreddit("rust")
+            .hot(None)
+            .await
+            .unwrap()
+            .children
+            .remove(0);
+        submission.edit("fish & chips").await.unwrap();
+        assert_eq!(submission.selftext(), "fish & chips");
+    }
+
+    #[tokio::test]
+    async fn test_edit_wrong_kind() {
+        let server = FakeReddit::start();
+        let client = login(&server).await;
+        let post = ThingFullname::from_submission_id("abc123");
+
+        let mut comment = client.comment("original", &post).await.unwrap();
+        let Err(error) = comment.edit("wrong kind").await else {
+            panic!("edited a comment into a submission");
+        };
+        assert!(
+            matches!(error.kind, RouxErrorKind::UnexpectedResponse(_)),
+            "{error:?}"
+        );
+        assert_eq!(comment.body(), "original");
+
+        let mut submission = client
+            .subreddit("rust")
+            .hot(None)
+            .await
+            .unwrap()
+            .children
+            .remove(0);
+        let selftext = submission.selftext().to_owned();
+        let Err(error) = submission.edit("wrong kind").await else {
+            panic!("edited a submission into a comment");
+        };
+        assert!(
+            matches!(error.kind, RouxErrorKind::UnexpectedResponse(_)),
+            "{error:?}"
+        );
+        assert_eq!(submission.selftext(), selftext);
+    }
+
+    #[tokio::test]
+    async fn test_unescape_without_raw_json() {
+        let server = FakeReddit::start();
+        let config = Config::new("test:roux:v0 (by /u/roux_tester)", "id", "secret")
+            .username("roux_tester")
+            .password("hunter2")
+            .base_url(server.url())
+            .raw_json(false);
+        let client = OAuthClient::new(config).unwrap().login().await.unwrap();
+        let post = ThingFullname::from_submission_id("abc123");
+
+        let comment = client.comment("a & <b>", &post).await.unwrap();
+        assert_eq!(comment.body(), "a &amp; &lt;b&gt;");
+        assert_eq!(comment.body_unescaped(), "a & <b>");
+
+        let mut submission = client
+            .subreddit("rust")
+            .hot(None)
+            .await
+            .unwrap()
+            .children
+            .remove(0);
+        submission.edit("fish & chips").await.unwrap();
+        assert_eq!(submission.selftext(), "fish &amp; chips");
+        assert_eq!(submission.selftext_unescaped(), "fish & chips");
+    }
+}